@@ -1,6 +1,8 @@
 #[cfg(feature = "clonable-lock")]
 mod fxlock;
 mod fxproxy;
+mod timeout;
+mod try_lock;
 
 #[cfg(all(feature = "async-tokio", feature = "async-lock", not(docsrs)))]
 compile_error!(
@@ -10,6 +12,7 @@ compile_error!(
 #[cfg(not(any(feature = "async-tokio", feature = "async-lock")))]
 compile_error!("Either `async-tokio` or `async-lock` feature must be enabled. Please, choose one of them.");
 
+pub use crate::lock_guards::FXDerefReadGuard;
 #[cfg(feature = "clonable-lock")]
 pub use fxlock::FXRwLock;
 #[doc(hidden)]
@@ -20,6 +23,24 @@ pub use fxproxy::FXProxy;
 pub use fxproxy::FXProxyReadGuard;
 pub use fxproxy::FXProxyWriteGuard;
 pub use fxproxy::FXWriter;
+pub use timeout::set_sleep_hook;
+#[doc(hidden)]
+pub use timeout::timeout;
+pub use timeout::FXSleepFn;
+#[doc(hidden)]
+pub use try_lock::try_read;
+#[doc(hidden)]
+pub use try_lock::try_read_for;
+#[doc(hidden)]
+pub use try_lock::try_write;
+#[doc(hidden)]
+pub use try_lock::try_write_for;
+#[cfg(feature = "async-tokio")]
+#[cfg_attr(feature = "async-tokio", doc(hidden))]
+pub use tokio::sync::Mutex;
+#[cfg(feature = "async-tokio")]
+#[cfg_attr(feature = "async-tokio", doc(hidden))]
+pub use tokio::sync::MutexGuard;
 #[cfg(feature = "async-tokio")]
 #[cfg_attr(feature = "async-tokio", doc(hidden))]
 pub use tokio::sync::OnceCell;
@@ -33,6 +54,12 @@ pub use tokio::sync::RwLockReadGuard;
 #[cfg_attr(feature = "async-tokio", doc(hidden))]
 pub use tokio::sync::RwLockWriteGuard;
 
+#[cfg(all(feature = "async-lock", not(docsrs)))]
+#[cfg_attr(feature = "async-lock", doc(hidden))]
+pub use async_lock::Mutex;
+#[cfg(all(feature = "async-lock", not(docsrs)))]
+#[cfg_attr(feature = "async-lock", doc(hidden))]
+pub use async_lock::MutexGuard;
 #[cfg(all(feature = "async-lock", not(docsrs)))]
 #[cfg_attr(feature = "async-lock", doc(hidden))]
 pub use async_lock::OnceCell;
@@ -44,6 +71,9 @@ pub use async_lock::RwLock;
 pub use async_lock::RwLockReadGuard;
 #[cfg(all(feature = "async-lock", not(docsrs)))]
 #[cfg_attr(feature = "async-lock", doc(hidden))]
+pub use async_lock::RwLockUpgradableReadGuard;
+#[cfg(all(feature = "async-lock", not(docsrs)))]
+#[cfg_attr(feature = "async-lock", doc(hidden))]
 pub use async_lock::RwLockWriteGuard;
 
 #[inline(always)]
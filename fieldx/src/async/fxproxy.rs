@@ -10,7 +10,12 @@ use std::future::Future;
 use std::pin::Pin;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
+use super::try_lock::try_read_within;
+use super::try_lock::try_write_within;
 use super::RwLock;
 use super::RwLockReadGuard;
 use super::RwLockWriteGuard;
@@ -19,6 +24,8 @@ pub type FXProxyReadGuard<'a, T> = crate::lock_guards::FXProxyReadGuard<RwLockRe
 pub type FXProxyWriteGuard<'a, T> = crate::lock_guards::FXProxyWriteGuard<RwLockWriteGuard<'a, Option<T>>, T>;
 
 type FXCallback<S, T> = Box<dyn Fn(&S) -> Pin<Box<dyn Future<Output = T> + Send + '_>> + Send + Sync>;
+// Shared to let the builder wrappers be cloned along with their containers.
+type FXSharedCallback<S, T> = Arc<dyn Fn(&S) -> Pin<Box<dyn Future<Output = T> + Send + '_>> + Send + Sync>;
 
 #[cfg(feature = "async-tokio")]
 type ReadOrInitGuard<'a, T> = tokio::sync::RwLockWriteGuard<'a, T>;
@@ -33,12 +40,22 @@ pub trait FXBuilderWrapperAsync: FXBuilderWrapper {
 
 #[doc(hidden)]
 pub struct FXBuilderInfallible<S, T> {
-    builder: FXCallback<S, T>,
+    builder: FXSharedCallback<S, T>,
 }
 
 impl<S, T> FXBuilderInfallible<S, T> {
     pub fn new(builder: FXCallback<S, T>) -> Self {
-        Self { builder }
+        Self {
+            builder: Arc::from(builder),
+        }
+    }
+}
+
+impl<S, T> Clone for FXBuilderInfallible<S, T> {
+    fn clone(&self) -> Self {
+        Self {
+            builder: self.builder.clone(),
+        }
     }
 }
 
@@ -61,12 +78,22 @@ where
 
 #[doc(hidden)]
 pub struct FXBuilderFallible<S, T, E> {
-    builder: FXCallback<S, Result<T, E>>,
+    builder: FXSharedCallback<S, Result<T, E>>,
 }
 
 impl<S, T, E> FXBuilderFallible<S, T, E> {
     pub fn new(builder: FXCallback<S, Result<T, E>>) -> Self {
-        Self { builder }
+        Self {
+            builder: Arc::from(builder),
+        }
+    }
+}
+
+impl<S, T, E> Clone for FXBuilderFallible<S, T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            builder: self.builder.clone(),
+        }
     }
 }
 
@@ -166,6 +193,24 @@ where
         let _ = self.read_or_init(owner).await;
     }
 
+    async fn init_with_lock(
+        &self,
+        wguard: &mut RwLockWriteGuard<'_, Option<B::Value>>,
+        owner: &B::Owner,
+    ) -> Result<(), B::Error> {
+        // Still uninitialized? Means no other task took care of it yet.
+        if wguard.is_none() {
+            match *self.builder.read().await {
+                Some(ref builder_cb) => {
+                    **wguard = Some((*builder_cb).invoke(owner).await?);
+                    self.is_set_raw().store(true, Ordering::SeqCst);
+                }
+                None => panic!("Builder is not set"),
+            }
+        }
+        Ok(())
+    }
+
     async fn read_or_init<'a>(&'a self, owner: &B::Owner) -> Result<ReadOrInitGuard<'a, Option<B::Value>>, B::Error> {
         #[cfg(feature = "async-tokio")]
         let mut guard = self.value.write().await;
@@ -175,20 +220,19 @@ where
         if (*guard).is_none() {
             #[cfg(feature = "async-lock")]
             let mut guard = ReadOrInitGuard::upgrade(guard).await;
-            // No value has been set yet
-            match *self.builder.read().await {
-                Some(ref builder_cb) => {
-                    *guard = Some((*builder_cb).invoke(owner).await?);
-                    self.is_set_raw().store(true, Ordering::SeqCst);
-                }
-                None => panic!("Builder is not set"),
-            }
+            self.init_with_lock(&mut guard, owner).await?;
             #[cfg(feature = "async-lock")]
             return Ok(RwLockWriteGuard::downgrade_to_upgradable(guard));
         }
         Ok(guard)
     }
 
+    /// Lock the container for reading without initializing it.
+    #[doc(hidden)]
+    pub async fn read_raw(&self) -> RwLockReadGuard<'_, Option<B::Value>> {
+        self.value.read().await
+    }
+
     /// Lazy-initialize the field if necessary and return lock read guard for the inner value.
     ///
     /// Panics if fallible field builder returns an error.
@@ -230,11 +274,58 @@ where
         ));
     }
 
+    /// Lazy-initialize the field if necessary and return lock read guard for the inner value, waiting for the lock no
+    /// longer than `timeout`. Returns `None` if the time is out. The time the builder takes is not counted.
+    ///
+    /// Panics if fallible field builder returns an error.
+    pub async fn read_within<'a>(
+        &'a self,
+        owner: &B::Owner,
+        timeout: Duration,
+    ) -> Option<FXProxyReadGuard<'a, B::Value>> {
+        self.try_read_within(owner, timeout).await.unwrap()
+    }
+
+    /// Lazy-initialize the field if necessary and return lock read guard for the inner value, waiting for the lock no
+    /// longer than `timeout`. Returns `Ok(None)` if the time is out. The time the builder takes is not counted.
+    ///
+    /// Return the same error, as fallible field builder if it errors out.
+    pub async fn try_read_within<'a>(
+        &'a self,
+        owner: &B::Owner,
+        timeout: Duration,
+    ) -> Result<Option<FXProxyReadGuard<'a, B::Value>>, B::Error> {
+        let start = Instant::now();
+        let Some(guard) = try_read_within(&self.value, timeout).await
+        else {
+            return Ok(None);
+        };
+        if guard.is_some() {
+            return Ok(Some(FXProxyReadGuard::new(guard)));
+        }
+        drop(guard);
+
+        let Some(mut wguard) = try_write_within(&self.value, timeout.saturating_sub(start.elapsed())).await
+        else {
+            return Ok(None);
+        };
+        self.init_with_lock(&mut wguard, owner).await?;
+        Ok(Some(FXProxyReadGuard::new(RwLockWriteGuard::downgrade(wguard))))
+    }
+
     /// Provides write-lock to directly store the value. Never calls the lazy builder.
     pub async fn write<'a>(&'a self) -> FXWriter<'a, B> {
         FXWriter::<'a, B>::new(self.value.write().await, self)
     }
 
+    /// Same as [`write`](Self::write) but waits for the lock no longer than `timeout`. Returns `None` if the time is
+    /// out.
+    pub async fn write_within<'a>(&'a self, timeout: Duration) -> Option<FXWriter<'a, B>> {
+        try_write_within(&self.value, timeout)
+            .await
+            .map(|wguard| FXWriter::<'a, B>::new(wguard, self))
+    }
+
     fn clear_with_lock(&self, wguard: &mut RwLockWriteGuard<Option<B::Value>>) -> Option<B::Value> {
         self.is_set_raw().store(false, Ordering::SeqCst);
         wguard.take()
@@ -0,0 +1,197 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Condvar;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::sync::PoisonError;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Function producing a future that completes after the given duration.
+pub type FXSleepFn = fn(Duration) -> Pin<Box<dyn Future<Output = ()> + Send>>;
+
+static SLEEP_HOOK: OnceLock<FXSleepFn> = OnceLock::new();
+
+/// Install the sleep hook used by the timed lock helpers like `try_read_<field>_for`.
+///
+/// FieldX doesn't depend on any particular async runtime, thus it can't make use of a runtime timer on its own. Unless
+/// a hook is installed, timeouts are counted down by a single helper thread shared by all pending timed waits. This
+/// works everywhere but is less precise and efficient than a runtime timer. Applications are expected to install a hook
+/// backed by their runtime of choice, e.g.:
+///
+/// ```
+/// fieldx::r#async::set_sleep_hook(|d| Box::pin(tokio::time::sleep(d))).unwrap();
+/// ```
+///
+/// The hook can only be installed once. If one is already set the new one is returned back as the error.
+pub fn set_sleep_hook(hook: FXSleepFn) -> Result<(), FXSleepFn> {
+    SLEEP_HOOK.set(hook)
+}
+
+#[derive(Default)]
+struct ThreadSleepState {
+    done:  bool,
+    waker: Option<Waker>,
+}
+
+impl ThreadSleepState {
+    fn wake(state: &Mutex<Self>) {
+        let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.done = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+struct TimerEntry {
+    deadline: Instant,
+    state:    Arc<Mutex<ThreadSleepState>>,
+}
+
+impl PartialEq for TimerEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.deadline == other.deadline
+    }
+}
+
+impl Eq for TimerEntry {}
+
+impl PartialOrd for TimerEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TimerEntry {
+    // Reversed to turn `BinaryHeap` into a min-heap with the nearest deadline on top.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.deadline.cmp(&self.deadline)
+    }
+}
+
+// The timer thread shared by all fallback sleep futures. It is started on first use and keeps running for the rest of
+// the process life. Entries of sleeps that were dropped before their deadline simply expire without waking anybody.
+struct Timer {
+    queue:   Mutex<BinaryHeap<TimerEntry>>,
+    condvar: Condvar,
+}
+
+impl Timer {
+    fn get() -> &'static Self {
+        static TIMER: OnceLock<Timer> = OnceLock::new();
+        TIMER.get_or_init(|| {
+            thread::Builder::new()
+                .name("fieldx-timer".to_string())
+                .spawn(|| Self::get().run())
+                .expect("failed to spawn the FieldX timer thread");
+            Self {
+                queue:   Mutex::new(BinaryHeap::new()),
+                condvar: Condvar::new(),
+            }
+        })
+    }
+
+    fn schedule(&self, deadline: Instant, state: Arc<Mutex<ThreadSleepState>>) {
+        let mut queue = self.queue.lock().unwrap_or_else(PoisonError::into_inner);
+        queue.push(TimerEntry { deadline, state });
+        self.condvar.notify_one();
+    }
+
+    fn run(&self) {
+        let mut expired = Vec::new();
+        let mut queue = self.queue.lock().unwrap_or_else(PoisonError::into_inner);
+        loop {
+            let now = Instant::now();
+            while queue.peek().is_some_and(|entry| entry.deadline <= now) {
+                expired.extend(queue.pop());
+            }
+
+            if !expired.is_empty() {
+                // Wakers may run arbitrary code, don't hold the queue while calling them.
+                drop(queue);
+                for entry in expired.drain(..) {
+                    ThreadSleepState::wake(&entry.state);
+                }
+                queue = self.queue.lock().unwrap_or_else(PoisonError::into_inner);
+                continue;
+            }
+
+            queue = match queue.peek() {
+                Some(entry) => {
+                    let pause = entry.deadline - now;
+                    self.condvar
+                        .wait_timeout(queue, pause)
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .0
+                }
+                None => self.condvar.wait(queue).unwrap_or_else(PoisonError::into_inner),
+            };
+        }
+    }
+}
+
+// Fallback sleep future for when no hook is installed.
+struct ThreadSleep {
+    duration: Duration,
+    state:    Option<Arc<Mutex<ThreadSleepState>>>,
+}
+
+impl Future for ThreadSleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let duration = self.duration;
+        let state = self.state.get_or_insert_with(|| {
+            let state = Arc::new(Mutex::new(ThreadSleepState::default()));
+            Timer::get().schedule(Instant::now() + duration, state.clone());
+            state
+        });
+
+        let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+        if state.done {
+            Poll::Ready(())
+        }
+        else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+fn sleep(duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+    match SLEEP_HOOK.get() {
+        Some(hook) => hook(duration),
+        None => Box::pin(ThreadSleep {
+            duration,
+            state: None,
+        }),
+    }
+}
+
+/// Await for `fut` no longer than `duration`. Returns `None` if the time is out.
+#[doc(hidden)]
+pub async fn timeout<F: Future>(duration: Duration, fut: F) -> Option<F::Output> {
+    let mut fut = std::pin::pin!(fut);
+    let mut sleep = sleep(duration);
+
+    std::future::poll_fn(|cx| {
+        if let Poll::Ready(output) = fut.as_mut().poll(cx) {
+            Poll::Ready(Some(output))
+        }
+        else if sleep.as_mut().poll(cx).is_ready() {
+            Poll::Ready(None)
+        }
+        else {
+            Poll::Pending
+        }
+    })
+    .await
+}
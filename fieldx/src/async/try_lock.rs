@@ -0,0 +1,56 @@
+// Backend-agnostic non-blocking and timed locking used by the `try_read_<field>` family of helpers.
+use super::timeout::timeout;
+use super::RwLock;
+use super::RwLockReadGuard;
+use super::RwLockWriteGuard;
+use std::time::Duration;
+
+#[cfg(feature = "async-tokio")]
+pub fn try_read<T: ?Sized>(lock: &RwLock<T>) -> Option<RwLockReadGuard<'_, T>> {
+    lock.try_read().ok()
+}
+
+#[cfg(feature = "async-tokio")]
+pub fn try_write<T: ?Sized>(lock: &RwLock<T>) -> Option<RwLockWriteGuard<'_, T>> {
+    lock.try_write().ok()
+}
+
+#[cfg(all(feature = "async-lock", not(docsrs)))]
+pub fn try_read<T: ?Sized>(lock: &RwLock<T>) -> Option<RwLockReadGuard<'_, T>> {
+    lock.try_read()
+}
+
+#[cfg(all(feature = "async-lock", not(docsrs)))]
+pub fn try_write<T: ?Sized>(lock: &RwLock<T>) -> Option<RwLockWriteGuard<'_, T>> {
+    lock.try_write()
+}
+
+pub async fn try_read_for<T: ?Sized>(lock: &RwLock<T>, duration: Duration) -> Option<RwLockReadGuard<'_, T>> {
+    timeout(duration, lock.read()).await
+}
+
+pub async fn try_write_for<T: ?Sized>(lock: &RwLock<T>, duration: Duration) -> Option<RwLockWriteGuard<'_, T>> {
+    timeout(duration, lock.write()).await
+}
+
+// Zero timeout means no waiting at all. Unlike the timed variants, it doesn't need a timer.
+pub(crate) async fn try_read_within<T: ?Sized>(lock: &RwLock<T>, duration: Duration) -> Option<RwLockReadGuard<'_, T>> {
+    if duration.is_zero() {
+        try_read(lock)
+    }
+    else {
+        try_read_for(lock, duration).await
+    }
+}
+
+pub(crate) async fn try_write_within<T: ?Sized>(
+    lock: &RwLock<T>,
+    duration: Duration,
+) -> Option<RwLockWriteGuard<'_, T>> {
+    if duration.is_zero() {
+        try_write(lock)
+    }
+    else {
+        try_write_for(lock, duration).await
+    }
+}
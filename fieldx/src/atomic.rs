@@ -0,0 +1,109 @@
+//! Support for `atomic` fields.
+use std::fmt;
+use std::fmt::Debug;
+use std::ops::Deref;
+use std::ops::DerefMut;
+use std::sync::atomic::Ordering;
+
+/// Standard library atomic types that can be wrapped into [`FXAtomic`].
+pub trait FXAtomicType {
+    /// The primitive type of the atomic.
+    type Value: Copy;
+
+    #[doc(hidden)]
+    fn new(value: Self::Value) -> Self;
+    #[doc(hidden)]
+    fn load(&self, order: Ordering) -> Self::Value;
+    #[doc(hidden)]
+    fn into_inner(self) -> Self::Value;
+}
+
+macro_rules! impl_atomic_type {
+    ($($width:literal: $($atomic:ident => $value:ty),+;)+) => {
+        $($(
+            #[cfg(target_has_atomic = $width)]
+            impl FXAtomicType for ::std::sync::atomic::$atomic {
+                type Value = $value;
+
+                #[inline(always)]
+                fn new(value: $value) -> Self {
+                    Self::new(value)
+                }
+
+                #[inline(always)]
+                fn load(&self, order: Ordering) -> $value {
+                    Self::load(self, order)
+                }
+
+                #[inline(always)]
+                fn into_inner(self) -> $value {
+                    Self::into_inner(self)
+                }
+            }
+        )+)+
+    };
+}
+
+impl_atomic_type! {
+    "8": AtomicBool => bool, AtomicI8 => i8, AtomicU8 => u8;
+    "16": AtomicI16 => i16, AtomicU16 => u16;
+    "32": AtomicI32 => i32, AtomicU32 => u32;
+    "64": AtomicI64 => i64, AtomicU64 => u64;
+    "ptr": AtomicIsize => isize, AtomicUsize => usize;
+}
+
+/// Atomic field container
+///
+/// This is a wrapper around a standard library atomic type. It provides a way to clone an atomic field by loading its
+/// current value into a new atomic. No additional functionality is provided.
+pub struct FXAtomic<A>(A);
+
+impl<A: FXAtomicType> FXAtomic<A> {
+    #[doc(hidden)]
+    pub fn new(value: A::Value) -> Self {
+        Self(A::new(value))
+    }
+
+    /// Consumes the container and returns the wrapped value.
+    pub fn into_inner(self) -> A::Value {
+        self.0.into_inner()
+    }
+}
+
+impl<A> Deref for FXAtomic<A> {
+    type Target = A;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<A> DerefMut for FXAtomic<A> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<A> AsRef<A> for FXAtomic<A> {
+    fn as_ref(&self) -> &A {
+        &self.0
+    }
+}
+
+impl<A: FXAtomicType> Clone for FXAtomic<A> {
+    fn clone(&self) -> Self {
+        Self::new(self.0.load(Ordering::SeqCst))
+    }
+}
+
+impl<A: Debug> Debug for FXAtomic<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<A: Default> Default for FXAtomic<A> {
+    fn default() -> Self {
+        Self(A::default())
+    }
+}
@@ -0,0 +1,183 @@
+//! Change tracking support for structs declared with `track_changes`.
+//!
+//! Every tracked field has a version counter which is bumped by its setter, writer, and clearer, and a version
+//! recorded by the last `mark_clean()` call. A field is considered dirty when the two differ.
+//!
+//! Change tracking state is metadata, not a part of the struct value. Hence the trackers are always equal to each other
+//! and don't contribute to hashes; this way they don't affect `PartialEq` or `Hash` implementations derived by the user
+//! struct.
+use std::cell::Cell;
+use std::fmt;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+/// Change tracker of plain structs.
+pub struct FXChanges<const N: usize> {
+    versions: [Cell<u64>; N],
+    clean:    [Cell<u64>; N],
+}
+
+impl<const N: usize> FXChanges<N> {
+    pub fn new() -> Self {
+        Self {
+            versions: [(); N].map(|_| Cell::new(0)),
+            clean:    [(); N].map(|_| Cell::new(0)),
+        }
+    }
+
+    /// Record a change of the field with index `idx`.
+    #[inline]
+    pub fn bump(&self, idx: usize) {
+        self.versions[idx].set(self.versions[idx].get().wrapping_add(1));
+    }
+
+    /// Current version of the field with index `idx`.
+    #[inline]
+    pub fn version(&self, idx: usize) -> u64 {
+        self.versions[idx].get()
+    }
+
+    /// Tell if the field with index `idx` has changed since the last `mark_clean()`.
+    #[inline]
+    pub fn is_dirty(&self, idx: usize) -> bool {
+        self.versions[idx].get() != self.clean[idx].get()
+    }
+
+    /// Filter the names of the fields that have changed since the last `mark_clean()`. `names` must be in the order of
+    /// field indices.
+    pub fn dirty_fields(&self, names: &[&'static str; N]) -> Vec<&'static str> {
+        (0..N).filter(|idx| self.is_dirty(*idx)).map(|idx| names[idx]).collect()
+    }
+
+    /// Consider all fields unchanged as of their current versions.
+    pub fn mark_clean(&self) {
+        for (version, clean) in self.versions.iter().zip(self.clean.iter()) {
+            clean.set(version.get());
+        }
+    }
+}
+
+impl<const N: usize> Default for FXChanges<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Clone for FXChanges<N> {
+    fn clone(&self) -> Self {
+        Self {
+            versions: self.versions.clone(),
+            clean:    self.clean.clone(),
+        }
+    }
+}
+
+impl<const N: usize> fmt::Debug for FXChanges<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FXChanges")
+            .field("versions", &self.versions.iter().map(Cell::get).collect::<Vec<_>>())
+            .field("dirty", &(0..N).filter(|idx| self.is_dirty(*idx)).collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<const N: usize> PartialEq for FXChanges<N> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<const N: usize> Eq for FXChanges<N> {}
+
+impl<const N: usize> Hash for FXChanges<N> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// Change tracker of sync and async structs. The counters are atomic.
+pub struct FXSyncChanges<const N: usize> {
+    versions: [AtomicU64; N],
+    clean:    [AtomicU64; N],
+}
+
+impl<const N: usize> FXSyncChanges<N> {
+    pub fn new() -> Self {
+        Self {
+            versions: [(); N].map(|_| AtomicU64::new(0)),
+            clean:    [(); N].map(|_| AtomicU64::new(0)),
+        }
+    }
+
+    /// Record a change of the field with index `idx`.
+    #[inline]
+    pub fn bump(&self, idx: usize) {
+        self.versions[idx].fetch_add(1, Ordering::AcqRel);
+    }
+
+    /// Current version of the field with index `idx`.
+    #[inline]
+    pub fn version(&self, idx: usize) -> u64 {
+        self.versions[idx].load(Ordering::Acquire)
+    }
+
+    /// Tell if the field with index `idx` has changed since the last `mark_clean()`.
+    #[inline]
+    pub fn is_dirty(&self, idx: usize) -> bool {
+        self.versions[idx].load(Ordering::Acquire) != self.clean[idx].load(Ordering::Acquire)
+    }
+
+    /// Filter the names of the fields that have changed since the last `mark_clean()`. `names` must be in the order of
+    /// field indices.
+    pub fn dirty_fields(&self, names: &[&'static str; N]) -> Vec<&'static str> {
+        (0..N).filter(|idx| self.is_dirty(*idx)).map(|idx| names[idx]).collect()
+    }
+
+    /// Consider all fields unchanged as of their current versions. Changes made concurrently with this call may or may
+    /// not be marked clean, but none of the later ones are lost. Hence, values of the fields read after this call are
+    /// either up to date, or the fields are reported dirty.
+    pub fn mark_clean(&self) {
+        for (version, clean) in self.versions.iter().zip(self.clean.iter()) {
+            clean.store(version.load(Ordering::Acquire), Ordering::Release);
+        }
+    }
+}
+
+impl<const N: usize> Default for FXSyncChanges<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Clone for FXSyncChanges<N> {
+    fn clone(&self) -> Self {
+        Self {
+            versions: self.versions.each_ref().map(|v| AtomicU64::new(v.load(Ordering::Acquire))),
+            clean:    self.clean.each_ref().map(|v| AtomicU64::new(v.load(Ordering::Acquire))),
+        }
+    }
+}
+
+impl<const N: usize> fmt::Debug for FXSyncChanges<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FXSyncChanges")
+            .field(
+                "versions",
+                &self.versions.iter().map(|v| v.load(Ordering::Acquire)).collect::<Vec<_>>(),
+            )
+            .field("dirty", &(0..N).filter(|idx| self.is_dirty(*idx)).collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<const N: usize> PartialEq for FXSyncChanges<N> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<const N: usize> Eq for FXSyncChanges<N> {}
+
+impl<const N: usize> Hash for FXSyncChanges<N> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
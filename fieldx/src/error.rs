@@ -9,6 +9,18 @@ pub enum FieldXError {
     /// A post-build method may report a problem with this variant.
     #[error("Post-build task failed: {0}")]
     PostBuild(String),
+    /// Builder doesn't have a field with the given name.
+    #[error("Unknown field '{0}'")]
+    UnknownField(String),
+    /// A string value could not be parsed into the type of the field.
+    #[error("Cannot parse value for field '{field}': {message}")]
+    FieldParse { field: String, message: String },
+    /// A value given to a builder setter with deferred `try_into` could not be converted into the type of the field.
+    #[error("Cannot convert value for field '{field}': {message}")]
+    FieldConversion { field: String, message: String },
+    /// Builder `check` rules that failed for the newly constructed object.
+    #[error("Validation failed: {}", .0.join("; "))]
+    Validation(Vec<String>),
 }
 
 impl FieldXError {
@@ -17,6 +29,32 @@ impl FieldXError {
         FieldXError::UninitializedField(field_name)
     }
 
+    #[doc(hidden)]
+    pub fn unknown_field<S: ToString>(field_name: S) -> FieldXError {
+        FieldXError::UnknownField(field_name.to_string())
+    }
+
+    #[doc(hidden)]
+    pub fn field_parse<S: ToString, E: std::fmt::Display>(field_name: S, err: E) -> FieldXError {
+        FieldXError::FieldParse {
+            field:   field_name.to_string(),
+            message: err.to_string(),
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn field_conversion<S: ToString, M: ToString>(field_name: S, message: M) -> FieldXError {
+        FieldXError::FieldConversion {
+            field:   field_name.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn validation(failed: Vec<String>) -> FieldXError {
+        FieldXError::Validation(failed)
+    }
+
     /// A convenience method for post-build method.
     pub fn post_build<S: ToString>(msg: S) -> FieldXError {
         FieldXError::PostBuild(msg.to_string())
@@ -89,32 +89,53 @@
 //! | *Feature* | *Description* |
 //! |-|-|
 //! | **sync** | Support for sync-safe mode of operation |
+//! | **sync-std** | Support for sync-safe mode of operation using only the standard library synchronization primitives. |
 //! | **async** | Support for async mode of operation |
 //! | **tokio-backend** | Selects the Tokio backend for async mode. A no-op without the `async` feature. |
 //! | **async-lock-backend** | Selects the `async-lock` backend for async mode. A no-op without the `async` feature. |
 //! | **async-tokio** | Combines `async` and `tokio-backend` features. |
 //! | **async-lock** | Combines `async` and `async-lock-backend` features. |
-//! | **clonable-lock** | Enables the [clonable lock wrapper type](more_on_locks.md). |
+//! | **clonable-lock** | Enables the [clonable lock wrapper type](more_on_locks.md) and the clonable container of atomic fields. |
 //! | **send_guard** | See corresponding feature of the [`parking_lot` crate](https://crates.io/crates/parking_lot) |
 //! | **serde** | Enable support for `serde` marshalling. |
+//! | **schemars** | Implement [`JsonSchema`](https://docs.rs/schemars) for structs with `serde(schema)`. Implies `serde`. |
+//! | **arc-swap** | Enable lock-free `swap` fields backed by the [`arc-swap` crate](https://crates.io/crates/arc-swap). |
 //! | **diagnostics** | Enable additional diagnostics for compile time errors. Experimental, requires Rust nightly toolset. |
 //!
 //! **Note:** The `tokio-backend` and `async-lock-backend` features are mutually exclusive. You can only use one of them
 //! at a time or FieldX will produce a compile-time error.
+//!
+//! **Note:** The `sync` feature takes precedence over `sync-std`: when both are enabled, for example due to feature
+//! unification, the `parking_lot` backend is used and `sync-std` is a no-op. `sync-std` depends neither on
+//! `parking_lot` nor on `once_cell` and implements the lazy field containers on top of `std::sync::OnceLock`; the
+//! plain mode uses `std::cell::OnceCell`. Lock poisoning is ignored with this backend: a lock, poisoned by a panicking
+//! thread, is acquired normally and provides the data in whatever state it was left. This is the same behavior as with
+//! `parking_lot`, which doesn't implement poisoning.
 
 #[cfg(feature = "async")]
 pub mod r#async;
+#[cfg(feature = "clonable-lock")]
+pub mod atomic;
+pub mod changes;
 pub mod error;
 pub mod lock_guards;
 pub mod plain;
-#[cfg(feature = "sync")]
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(any(feature = "sync", feature = "sync-std"))]
 pub mod sync;
 pub mod traits;
 
+#[cfg(feature = "arc-swap")]
+#[doc(hidden)]
+pub use arc_swap;
 #[doc(hidden)]
 pub use fieldx_aux::FXOrig;
 #[doc(inline)]
 pub use fieldx_derive::fxstruct;
+#[cfg(feature = "schemars")]
+#[doc(hidden)]
+pub use schemars;
 #[cfg(feature = "async")]
 #[doc(hidden)]
 pub use std::fmt;
@@ -1,5 +1,6 @@
 use std::borrow::Borrow;
 use std::borrow::BorrowMut;
+use std::cell::RefCell;
 use std::fmt;
 use std::ops::Deref;
 use std::ops::DerefMut;
@@ -18,6 +19,11 @@ where
     pub fn new(guard: G) -> Self {
         Self { guard }
     }
+
+    #[doc(hidden)]
+    pub fn into_inner(self) -> G {
+        self.guard
+    }
 }
 
 impl<G, T> Deref for FXProxyReadGuard<G, T>
@@ -140,3 +146,236 @@ where
         self.guard.deref().as_ref().unwrap().fmt(f)
     }
 }
+
+/// Read guard that dereferences the value of the guard it wraps. Used by `get(deref)` accessors of lock-protected
+/// fields where the lock implementation doesn't provide mapped guards of its own.
+pub struct FXDerefReadGuard<G>
+where
+    G: Deref,
+    G::Target: Deref,
+{
+    guard: G,
+}
+
+impl<G> FXDerefReadGuard<G>
+where
+    G: Deref,
+    G::Target: Deref,
+{
+    pub fn new(guard: G) -> Self {
+        Self { guard }
+    }
+}
+
+impl<G> Deref for FXDerefReadGuard<G>
+where
+    G: Deref,
+    G::Target: Deref,
+{
+    type Target = <G::Target as Deref>::Target;
+
+    fn deref(&self) -> &Self::Target {
+        self.guard.deref().deref()
+    }
+}
+
+impl<G> fmt::Debug for FXDerefReadGuard<G>
+where
+    G: Deref,
+    G::Target: Deref,
+    <G::Target as Deref>::Target: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
+/// Read guard of a lock group member. Wraps the guard of the group lock and gives access to a single field of the
+/// group.
+pub struct FXGroupReadGuard<G, T>
+where
+    G: Deref,
+{
+    guard:   G,
+    project: fn(&G::Target) -> &T,
+}
+
+impl<G, T> FXGroupReadGuard<G, T>
+where
+    G: Deref,
+{
+    pub fn new(guard: G, project: fn(&G::Target) -> &T) -> Self {
+        Self { guard, project }
+    }
+}
+
+impl<G, T> Deref for FXGroupReadGuard<G, T>
+where
+    G: Deref,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        (self.project)(self.guard.deref())
+    }
+}
+
+impl<G, T> AsRef<T> for FXGroupReadGuard<G, T>
+where
+    G: Deref,
+{
+    fn as_ref(&self) -> &T {
+        self.deref()
+    }
+}
+
+impl<G, T> Borrow<T> for FXGroupReadGuard<G, T>
+where
+    G: Deref,
+{
+    fn borrow(&self) -> &T {
+        self.deref()
+    }
+}
+
+impl<G, T> fmt::Debug for FXGroupReadGuard<G, T>
+where
+    G: Deref,
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
+/// Write guard of a lock group member. Wraps the guard of the group lock and gives access to a single field of the
+/// group.
+pub struct FXGroupWriteGuard<G, T>
+where
+    G: DerefMut,
+{
+    guard:       G,
+    project:     fn(&G::Target) -> &T,
+    project_mut: fn(&mut G::Target) -> &mut T,
+}
+
+impl<G, T> FXGroupWriteGuard<G, T>
+where
+    G: DerefMut,
+{
+    pub fn new(guard: G, project: fn(&G::Target) -> &T, project_mut: fn(&mut G::Target) -> &mut T) -> Self {
+        Self {
+            guard,
+            project,
+            project_mut,
+        }
+    }
+}
+
+impl<G, T> Deref for FXGroupWriteGuard<G, T>
+where
+    G: DerefMut,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        (self.project)(self.guard.deref())
+    }
+}
+
+impl<G, T> DerefMut for FXGroupWriteGuard<G, T>
+where
+    G: DerefMut,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        (self.project_mut)(self.guard.deref_mut())
+    }
+}
+
+impl<G, T> AsRef<T> for FXGroupWriteGuard<G, T>
+where
+    G: DerefMut,
+{
+    fn as_ref(&self) -> &T {
+        self.deref()
+    }
+}
+
+impl<G, T> AsMut<T> for FXGroupWriteGuard<G, T>
+where
+    G: DerefMut,
+{
+    fn as_mut(&mut self) -> &mut T {
+        self.deref_mut()
+    }
+}
+
+impl<G, T> Borrow<T> for FXGroupWriteGuard<G, T>
+where
+    G: DerefMut,
+{
+    fn borrow(&self) -> &T {
+        self.deref()
+    }
+}
+
+impl<G, T> BorrowMut<T> for FXGroupWriteGuard<G, T>
+where
+    G: DerefMut,
+{
+    fn borrow_mut(&mut self) -> &mut T {
+        self.deref_mut()
+    }
+}
+
+impl<G, T> fmt::Debug for FXGroupWriteGuard<G, T>
+where
+    G: DerefMut,
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
+/// Writer of a lazy lock group member. Like [`FXWriter`](crate::sync::FXWriter), it allows storing a value into the
+/// field or resetting it into uninitialized state without calling the builder.
+pub struct FXGroupWriter<G, T>
+where
+    G: DerefMut,
+{
+    guard:       RefCell<G>,
+    project_mut: fn(&mut G::Target) -> &mut Option<T>,
+}
+
+impl<G, T> FXGroupWriter<G, T>
+where
+    G: DerefMut,
+{
+    pub fn new(guard: G, project_mut: fn(&mut G::Target) -> &mut Option<T>) -> Self {
+        Self {
+            guard: RefCell::new(guard),
+            project_mut,
+        }
+    }
+
+    /// Store a new value into the field and returns the previous value or `None`.
+    pub fn store(&mut self, value: T) -> Option<T> {
+        (self.project_mut)(self.guard.get_mut().deref_mut()).replace(value)
+    }
+
+    /// Resets the field into uninitialized state
+    pub fn clear(&self) -> Option<T> {
+        (self.project_mut)(self.guard.borrow_mut().deref_mut()).take()
+    }
+}
+
+impl<G, T> fmt::Debug for FXGroupWriter<G, T>
+where
+    G: DerefMut,
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.project_mut)(self.guard.borrow_mut().deref_mut()).fmt(f)
+    }
+}
@@ -1,4 +1,6 @@
-pub use once_cell::unsync::OnceCell;
+mod once;
+
+pub use once::OnceCell;
 #[doc(hidden)]
 pub use std::cell::Ref;
 #[doc(hidden)]
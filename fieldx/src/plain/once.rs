@@ -0,0 +1,113 @@
+use std::cell;
+use std::fmt;
+use std::fmt::Debug;
+
+/// Lazy field container of the plain mode
+///
+/// A wrapper around [`std::cell::OnceCell`] which adds fallible initialization.
+pub struct OnceCell<T> {
+    cell: cell::OnceCell<T>,
+}
+
+impl<T> OnceCell<T> {
+    /// Creates a new empty cell.
+    pub const fn new() -> Self {
+        Self {
+            cell: cell::OnceCell::new(),
+        }
+    }
+
+    /// Returns the value if the cell is initialized.
+    pub fn get(&self) -> Option<&T> {
+        self.cell.get()
+    }
+
+    /// Returns a mutable reference to the value if the cell is initialized.
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        self.cell.get_mut()
+    }
+
+    /// Initializes the cell with the value. Gives the value back if the cell is already initialized.
+    pub fn set(&self, value: T) -> Result<(), T> {
+        self.cell.set(value)
+    }
+
+    /// Returns the value, initializing the cell with `f` if necessary.
+    ///
+    /// # Panics
+    ///
+    /// Reentrant initialization of the cell from `f` results in a panic.
+    pub fn get_or_init<F>(&self, f: F) -> &T
+    where
+        F: FnOnce() -> T,
+    {
+        self.cell.get_or_init(f)
+    }
+
+    /// Returns the value, initializing the cell with `f` if necessary. If `f` fails the cell remains uninitialized and
+    /// the error is returned.
+    ///
+    /// # Panics
+    ///
+    /// Reentrant initialization of the cell from `f` results in a panic.
+    pub fn get_or_try_init<F, E>(&self, f: F) -> Result<&T, E>
+    where
+        F: FnOnce() -> Result<T, E>,
+    {
+        if let Some(value) = self.cell.get() {
+            return Ok(value);
+        }
+
+        let value = f()?;
+        if self.cell.set(value).is_err() {
+            panic!("reentrant init");
+        }
+        Ok(self.cell.get().expect("the cell has just been initialized"))
+    }
+
+    /// Takes the value out of the cell, leaving it uninitialized.
+    pub fn take(&mut self) -> Option<T> {
+        self.cell.take()
+    }
+
+    /// Consumes the cell and returns the wrapped value, if any.
+    pub fn into_inner(self) -> Option<T> {
+        self.cell.into_inner()
+    }
+}
+
+impl<T> Default for OnceCell<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<T> for OnceCell<T> {
+    fn from(value: T) -> Self {
+        Self {
+            cell: cell::OnceCell::from(value),
+        }
+    }
+}
+
+impl<T: Clone> Clone for OnceCell<T> {
+    fn clone(&self) -> Self {
+        Self {
+            cell: self.cell.clone(),
+        }
+    }
+}
+
+impl<T: Debug> Debug for OnceCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.cell.fmt(f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for OnceCell<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cell == other.cell
+    }
+}
+
+impl<T: Eq> Eq for OnceCell<T> {}
@@ -0,0 +1,361 @@
+//! Support for serializing structs without cloning them, and for versioned serialization.
+//!
+//! Fields of plain and sync structs are serialized via a borrowed shadow struct. Its fields refer to the values of
+//! the original struct fields either directly, or through lock guards, or hold copies of atomic values.
+//!
+//! Structs with `serde(version = N)` are serialized as a `version` tag followed by the `data`. On deserialization the
+//! tag selects the layout to read the data with, and the data is then migrated to the current layout. If the data
+//! precedes the tag in the input it is buffered until the tag is read; data types borrowing from the input can't be
+//! read from the buffer.
+mod content;
+
+#[doc(hidden)]
+pub use ::serde::Deserialize;
+#[doc(hidden)]
+pub use ::serde::Deserializer;
+#[doc(hidden)]
+pub use ::serde::Serialize;
+#[doc(hidden)]
+pub use ::serde::Serializer;
+use content::FXContent;
+use content::FXContentDeserializer;
+use serde::de;
+use serde::de::MapAccess;
+use serde::de::SeqAccess;
+use serde::de::Visitor;
+use serde::ser::SerializeStruct;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+const VERSIONED_NAME: &str = "FXVersioned";
+const VERSIONED_FIELDS: &[&str] = &["version", "data"];
+
+/// Value of a struct field, borrowed for serialization.
+pub enum FXSerializeRef<'a, T> {
+    /// A reference to the field value.
+    Ref(&'a T),
+    /// A copy of the field value, for fields that cannot be referenced, like atomics.
+    Owned(T),
+    /// A guard, providing access to the field value.
+    Guard(Box<dyn Deref<Target = T> + 'a>),
+}
+
+impl<'a, T> FXSerializeRef<'a, T> {
+    pub fn guard<G>(guard: G) -> Self
+    where
+        G: Deref<Target = T> + 'a,
+    {
+        Self::Guard(Box::new(guard))
+    }
+
+    /// Borrow the value of an optional field.
+    pub fn option_ref(value: &'a Option<T>) -> Option<Self> {
+        value.as_ref().map(Self::Ref)
+    }
+
+    /// Take the guard of an optional field value. Returns `None` and releases the guard if the field is not set.
+    pub fn option_guard<G>(guard: G) -> Option<Self>
+    where
+        G: Deref<Target = Option<T>> + 'a,
+    {
+        if guard.is_some() {
+            Some(Self::Guard(Box::new(FXSomeGuard(guard))))
+        }
+        else {
+            None
+        }
+    }
+}
+
+impl<T> Deref for FXSerializeRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            Self::Ref(value) => value,
+            Self::Owned(value) => value,
+            Self::Guard(guard) => guard,
+        }
+    }
+}
+
+impl<T> Serialize for FXSerializeRef<'_, T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (**self).serialize(serializer)
+    }
+}
+
+impl<T> fmt::Debug for FXSerializeRef<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+// A guard of an `Option` which is known to be `Some`.
+struct FXSomeGuard<G>(G);
+
+impl<G, T> Deref for FXSomeGuard<G>
+where
+    G: Deref<Target = Option<T>>,
+{
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.0.as_ref().unwrap()
+    }
+}
+
+/// Serialized form of a versioned struct: the layout version and the data.
+#[derive(Debug)]
+pub struct FXVersioned<T> {
+    version: u32,
+    data:    T,
+}
+
+impl<T> FXVersioned<T> {
+    pub fn new(version: u32, data: T) -> Self {
+        Self { version, data }
+    }
+
+    /// The version of the layout the data was serialized with.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// The data, migrated to the current layout if deserialized.
+    pub fn into_data(self) -> T {
+        self.data
+    }
+}
+
+impl<T> Serialize for FXVersioned<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(VERSIONED_NAME, 2)?;
+        state.serialize_field("version", &self.version)?;
+        state.serialize_field("data", &self.data)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl<T> schemars::JsonSchema for FXVersioned<T>
+where
+    T: schemars::JsonSchema,
+{
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        format!("{VERSIONED_NAME}_for_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        format!("fieldx::serde::{VERSIONED_NAME}<{}>", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "object",
+            "properties": {
+                "version": generator.subschema_for::<u32>(),
+                "data": generator.subschema_for::<T>(),
+            },
+            "required": VERSIONED_FIELDS,
+        })
+    }
+}
+
+impl<'de, T> Deserialize<'de> for FXVersioned<T>
+where
+    T: FXMigrate<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(VERSIONED_NAME, VERSIONED_FIELDS, FXVersionedVisitor(PhantomData))
+    }
+}
+
+/// Shadow structs of versioned structs implement this trait to read data serialized with any of the supported layout
+/// versions.
+pub trait FXMigrate<'de>: Deserialize<'de> {
+    /// Read the data serialized with layout `version` and upgrade it to the current layout.
+    fn migrate<V>(version: u32, data: &mut V) -> Result<Self, V::Error>
+    where
+        V: FXVersionedData<'de>;
+}
+
+/// Source of the data part of a versioned struct.
+pub trait FXVersionedData<'de> {
+    type Error: de::Error;
+
+    /// Read the data with the current layout.
+    fn next<T>(&mut self) -> Result<T, Self::Error>
+    where
+        T: Deserialize<'de>;
+
+    /// Read the data with the layout taken by `migrate` and apply it.
+    fn migrate<T, R>(&mut self, migrate: impl FnOnce(T) -> R) -> Result<R, Self::Error>
+    where
+        T: Deserialize<'de>,
+    {
+        self.next().map(migrate)
+    }
+}
+
+#[doc(hidden)]
+pub fn unsupported_version<E: de::Error>(version: u32) -> E {
+    E::custom(format_args!("unsupported layout version {version}"))
+}
+
+// Patch fields of clearable fields: a missing key means "leave as is", a `null` means "clear".
+#[doc(hidden)]
+pub fn deserialize_some<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+struct FXSeqData<A>(A);
+
+impl<'de, A> FXVersionedData<'de> for FXSeqData<A>
+where
+    A: SeqAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next<T>(&mut self) -> Result<T, Self::Error>
+    where
+        T: Deserialize<'de>,
+    {
+        self.0
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &"version and data"))
+    }
+}
+
+struct FXMapData<A>(A);
+
+impl<'de, A> FXVersionedData<'de> for FXMapData<A>
+where
+    A: MapAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next<T>(&mut self) -> Result<T, Self::Error>
+    where
+        T: Deserialize<'de>,
+    {
+        self.0.next_value()
+    }
+}
+
+// Data which preceded its version in the input.
+struct FXContentData<E> {
+    content: Option<FXContent>,
+    error:   PhantomData<E>,
+}
+
+impl<E> FXContentData<E> {
+    fn new(content: FXContent) -> Self {
+        Self {
+            content: Some(content),
+            error:   PhantomData,
+        }
+    }
+}
+
+impl<'de, E> FXVersionedData<'de> for FXContentData<E>
+where
+    E: de::Error,
+{
+    type Error = E;
+
+    fn next<T>(&mut self) -> Result<T, Self::Error>
+    where
+        T: Deserialize<'de>,
+    {
+        let content = self.content.take().ok_or_else(|| de::Error::custom("versioned data is already read"))?;
+        T::deserialize(FXContentDeserializer::new(content))
+    }
+}
+
+struct FXVersionedVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for FXVersionedVisitor<T>
+where
+    T: FXMigrate<'de>,
+{
+    type Value = FXVersioned<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("versioned struct data")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let version = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &"version and data"))?;
+        let data = T::migrate(version, &mut FXSeqData(seq))?;
+        Ok(FXVersioned { version, data })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        // The layout of the data depends on the version. When the data comes first it is buffered until the version
+        // is known.
+        let mut version: Option<u32> = None;
+        let mut migrated: Option<T> = None;
+        let mut buffered: Option<FXContent> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "version" => {
+                    if version.is_some() {
+                        return Err(de::Error::duplicate_field("version"));
+                    }
+                    version = Some(map.next_value()?);
+                }
+                "data" => {
+                    if migrated.is_some() || buffered.is_some() {
+                        return Err(de::Error::duplicate_field("data"));
+                    }
+                    if let Some(version) = version {
+                        migrated = Some(T::migrate(version, &mut FXMapData(&mut map))?);
+                    }
+                    else {
+                        buffered = Some(map.next_value()?);
+                    }
+                }
+                _ => return Err(de::Error::unknown_field(&key, VERSIONED_FIELDS)),
+            }
+        }
+        let version = version.ok_or_else(|| de::Error::missing_field("version"))?;
+        let data = match (migrated, buffered) {
+            (Some(data), _) => data,
+            (None, Some(content)) => T::migrate(version, &mut FXContentData::<A::Error>::new(content))?,
+            (None, None) => return Err(de::Error::missing_field("data")),
+        };
+        Ok(FXVersioned { version, data })
+    }
+}
@@ -0,0 +1,349 @@
+// Format-agnostic buffer for a value which can't be deserialized right away, like the data of a versioned struct that
+// comes before its version. Values are owned, hence data types borrowing from the input can't be read from it.
+use serde::de;
+use serde::de::value::MapDeserializer;
+use serde::de::value::SeqDeserializer;
+use serde::de::DeserializeSeed;
+use serde::de::EnumAccess;
+use serde::de::IntoDeserializer;
+use serde::de::MapAccess;
+use serde::de::SeqAccess;
+use serde::de::VariantAccess;
+use serde::de::Visitor;
+use serde::forward_to_deserialize_any;
+use serde::Deserialize;
+use serde::Deserializer;
+use std::fmt;
+use std::marker::PhantomData;
+
+#[derive(Debug)]
+pub(crate) enum FXContent {
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    F32(f32),
+    F64(f64),
+    Char(char),
+    String(String),
+    Bytes(Vec<u8>),
+    None,
+    Some(Box<FXContent>),
+    Unit,
+    Newtype(Box<FXContent>),
+    Seq(Vec<FXContent>),
+    Map(Vec<(FXContent, FXContent)>),
+}
+
+struct FXContentVisitor;
+
+impl<'de> Visitor<'de> for FXContentVisitor {
+    type Value = FXContent;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<FXContent, E> {
+        Ok(FXContent::Bool(v))
+    }
+
+    fn visit_u8<E: de::Error>(self, v: u8) -> Result<FXContent, E> {
+        Ok(FXContent::U8(v))
+    }
+
+    fn visit_u16<E: de::Error>(self, v: u16) -> Result<FXContent, E> {
+        Ok(FXContent::U16(v))
+    }
+
+    fn visit_u32<E: de::Error>(self, v: u32) -> Result<FXContent, E> {
+        Ok(FXContent::U32(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<FXContent, E> {
+        Ok(FXContent::U64(v))
+    }
+
+    fn visit_i8<E: de::Error>(self, v: i8) -> Result<FXContent, E> {
+        Ok(FXContent::I8(v))
+    }
+
+    fn visit_i16<E: de::Error>(self, v: i16) -> Result<FXContent, E> {
+        Ok(FXContent::I16(v))
+    }
+
+    fn visit_i32<E: de::Error>(self, v: i32) -> Result<FXContent, E> {
+        Ok(FXContent::I32(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<FXContent, E> {
+        Ok(FXContent::I64(v))
+    }
+
+    fn visit_f32<E: de::Error>(self, v: f32) -> Result<FXContent, E> {
+        Ok(FXContent::F32(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<FXContent, E> {
+        Ok(FXContent::F64(v))
+    }
+
+    fn visit_char<E: de::Error>(self, v: char) -> Result<FXContent, E> {
+        Ok(FXContent::Char(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<FXContent, E> {
+        Ok(FXContent::String(v.to_owned()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<FXContent, E> {
+        Ok(FXContent::String(v))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<FXContent, E> {
+        Ok(FXContent::Bytes(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<FXContent, E> {
+        Ok(FXContent::Bytes(v))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<FXContent, E> {
+        Ok(FXContent::None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<FXContent, D::Error> {
+        FXContent::deserialize(deserializer).map(|v| FXContent::Some(Box::new(v)))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<FXContent, E> {
+        Ok(FXContent::Unit)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<FXContent, D::Error> {
+        FXContent::deserialize(deserializer).map(|v| FXContent::Newtype(Box::new(v)))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<FXContent, A::Error> {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(FXContent::Seq(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<FXContent, A::Error> {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0).min(4096));
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(FXContent::Map(entries))
+    }
+}
+
+impl<'de> Deserialize<'de> for FXContent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(FXContentVisitor)
+    }
+}
+
+impl<'de, E: de::Error> IntoDeserializer<'de, E> for FXContent {
+    type Deserializer = FXContentDeserializer<E>;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        FXContentDeserializer::new(self)
+    }
+}
+
+pub(crate) struct FXContentDeserializer<E> {
+    content: FXContent,
+    error:   PhantomData<E>,
+}
+
+impl<E> FXContentDeserializer<E> {
+    pub(crate) fn new(content: FXContent) -> Self {
+        Self {
+            content,
+            error: PhantomData,
+        }
+    }
+}
+
+impl<'de, E: de::Error> Deserializer<'de> for FXContentDeserializer<E> {
+    type Error = E;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        match self.content {
+            FXContent::Bool(v) => visitor.visit_bool(v),
+            FXContent::U8(v) => visitor.visit_u8(v),
+            FXContent::U16(v) => visitor.visit_u16(v),
+            FXContent::U32(v) => visitor.visit_u32(v),
+            FXContent::U64(v) => visitor.visit_u64(v),
+            FXContent::I8(v) => visitor.visit_i8(v),
+            FXContent::I16(v) => visitor.visit_i16(v),
+            FXContent::I32(v) => visitor.visit_i32(v),
+            FXContent::I64(v) => visitor.visit_i64(v),
+            FXContent::F32(v) => visitor.visit_f32(v),
+            FXContent::F64(v) => visitor.visit_f64(v),
+            FXContent::Char(v) => visitor.visit_char(v),
+            FXContent::String(v) => visitor.visit_string(v),
+            FXContent::Bytes(v) => visitor.visit_byte_buf(v),
+            FXContent::None => visitor.visit_none(),
+            FXContent::Some(v) => visitor.visit_some(Self::new(*v)),
+            FXContent::Unit => visitor.visit_unit(),
+            FXContent::Newtype(v) => visitor.visit_newtype_struct(Self::new(*v)),
+            FXContent::Seq(v) => {
+                let mut seq = SeqDeserializer::new(v.into_iter());
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            FXContent::Map(v) => {
+                let mut map = MapDeserializer::new(v.into_iter());
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        match self.content {
+            FXContent::None | FXContent::Unit => visitor.visit_none(),
+            FXContent::Some(v) => visitor.visit_some(Self::new(*v)),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, E> {
+        match self.content {
+            FXContent::Newtype(v) => visitor.visit_newtype_struct(Self::new(*v)),
+            _ => visitor.visit_newtype_struct(self),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        // Self-describing formats represent enums either as the variant name or as a single-entry map of the variant
+        // name to its content.
+        let (variant, value) = match self.content {
+            FXContent::Map(mut entries) if entries.len() == 1 => {
+                let (variant, value) = entries.pop().unwrap();
+                (variant, Some(value))
+            }
+            variant @ FXContent::String(_) => (variant, None),
+            other => return Err(de::Error::invalid_type(other.unexpected(), &"enum variant")),
+        };
+        visitor.visit_enum(FXEnumDeserializer {
+            variant,
+            value,
+            error: PhantomData,
+        })
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+impl FXContent {
+    fn unexpected(&self) -> de::Unexpected<'_> {
+        match self {
+            FXContent::Bool(v) => de::Unexpected::Bool(*v),
+            FXContent::U8(v) => de::Unexpected::Unsigned(*v as u64),
+            FXContent::U16(v) => de::Unexpected::Unsigned(*v as u64),
+            FXContent::U32(v) => de::Unexpected::Unsigned(*v as u64),
+            FXContent::U64(v) => de::Unexpected::Unsigned(*v),
+            FXContent::I8(v) => de::Unexpected::Signed(*v as i64),
+            FXContent::I16(v) => de::Unexpected::Signed(*v as i64),
+            FXContent::I32(v) => de::Unexpected::Signed(*v as i64),
+            FXContent::I64(v) => de::Unexpected::Signed(*v),
+            FXContent::F32(v) => de::Unexpected::Float(*v as f64),
+            FXContent::F64(v) => de::Unexpected::Float(*v),
+            FXContent::Char(v) => de::Unexpected::Char(*v),
+            FXContent::String(v) => de::Unexpected::Str(v),
+            FXContent::Bytes(v) => de::Unexpected::Bytes(v),
+            FXContent::None | FXContent::Some(_) => de::Unexpected::Option,
+            FXContent::Unit => de::Unexpected::Unit,
+            FXContent::Newtype(_) => de::Unexpected::NewtypeStruct,
+            FXContent::Seq(_) => de::Unexpected::Seq,
+            FXContent::Map(_) => de::Unexpected::Map,
+        }
+    }
+}
+
+struct FXEnumDeserializer<E> {
+    variant: FXContent,
+    value:   Option<FXContent>,
+    error:   PhantomData<E>,
+}
+
+impl<'de, E: de::Error> EnumAccess<'de> for FXEnumDeserializer<E> {
+    type Error = E;
+    type Variant = FXVariantDeserializer<E>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self::Variant), E> {
+        let variant = seed.deserialize(FXContentDeserializer::new(self.variant))?;
+        Ok((
+            variant,
+            FXVariantDeserializer {
+                value: self.value,
+                error: PhantomData,
+            },
+        ))
+    }
+}
+
+struct FXVariantDeserializer<E> {
+    value: Option<FXContent>,
+    error: PhantomData<E>,
+}
+
+impl<'de, E: de::Error> VariantAccess<'de> for FXVariantDeserializer<E> {
+    type Error = E;
+
+    fn unit_variant(self) -> Result<(), E> {
+        match self.value {
+            None | Some(FXContent::Unit) => Ok(()),
+            Some(other) => Err(de::Error::invalid_type(other.unexpected(), &"unit variant")),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, E> {
+        match self.value {
+            Some(value) => seed.deserialize(FXContentDeserializer::new(value)),
+            None => Err(de::Error::invalid_type(de::Unexpected::UnitVariant, &"newtype variant")),
+        }
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, E> {
+        match self.value {
+            Some(value @ FXContent::Seq(_)) => FXContentDeserializer::new(value).deserialize_any(visitor),
+            Some(other) => Err(de::Error::invalid_type(other.unexpected(), &"tuple variant")),
+            None => Err(de::Error::invalid_type(de::Unexpected::UnitVariant, &"tuple variant")),
+        }
+    }
+
+    fn struct_variant<V: Visitor<'de>>(self, _fields: &'static [&'static str], visitor: V) -> Result<V::Value, E> {
+        match self.value {
+            Some(value @ (FXContent::Map(_) | FXContent::Seq(_))) => {
+                FXContentDeserializer::new(value).deserialize_any(visitor)
+            }
+            Some(other) => Err(de::Error::invalid_type(other.unexpected(), &"struct variant")),
+            None => Err(de::Error::invalid_type(de::Unexpected::UnitVariant, &"struct variant")),
+        }
+    }
+}
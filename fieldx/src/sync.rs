@@ -1,29 +1,82 @@
+mod fxbuilder;
 #[cfg(feature = "clonable-lock")]
 pub mod fxlock;
+#[cfg(feature = "sync")]
 pub mod fxproxy;
+#[cfg(feature = "sync")]
+#[doc(hidden)]
+pub mod single_lock;
+#[cfg(not(feature = "sync"))]
+pub mod std_backend;
 
-#[cfg(feature = "clonable-lock")]
-pub use fxlock::FXRwLock;
 #[doc(hidden)]
-pub use fxproxy::FXBuilderFallible;
+pub use fxbuilder::FXBuilderFallible;
 #[doc(hidden)]
-pub use fxproxy::FXBuilderInfallible;
+pub use fxbuilder::FXBuilderInfallible;
+#[cfg(feature = "clonable-lock")]
+pub use fxlock::FXRwLock;
+
+#[cfg(feature = "sync")]
 pub use fxproxy::FXProxy;
+#[cfg(feature = "sync")]
 pub use fxproxy::FXProxyReadGuard;
+#[cfg(feature = "sync")]
 pub use fxproxy::FXProxyWriteGuard;
+#[cfg(feature = "sync")]
 pub use fxproxy::FXWriter;
+#[cfg(feature = "sync")]
 pub use once_cell::sync::OnceCell;
+#[cfg(feature = "sync")]
 #[doc(hidden)]
 pub use parking_lot::MappedRwLockReadGuard;
+#[cfg(feature = "sync")]
 #[doc(hidden)]
 pub use parking_lot::MappedRwLockWriteGuard;
-#[cfg(not(feature = "clonable-lock"))]
+#[cfg(feature = "sync")]
+#[doc(hidden)]
+pub use parking_lot::Mutex;
+#[cfg(feature = "sync")]
+#[doc(hidden)]
+pub use parking_lot::MutexGuard;
+#[cfg(all(feature = "sync", not(feature = "clonable-lock")))]
 pub use parking_lot::RwLock;
+#[cfg(feature = "sync")]
 #[doc(hidden)]
 pub use parking_lot::RwLockReadGuard;
+#[cfg(feature = "sync")]
+#[doc(hidden)]
+pub use parking_lot::RwLockUpgradableReadGuard;
+#[cfg(feature = "sync")]
 #[doc(hidden)]
 pub use parking_lot::RwLockWriteGuard;
 
+#[cfg(not(feature = "sync"))]
+pub use crate::lock_guards::FXDerefReadGuard;
+#[cfg(not(feature = "sync"))]
+#[doc(hidden)]
+pub use std::sync::MutexGuard;
+#[cfg(not(feature = "sync"))]
+#[doc(hidden)]
+pub use std::sync::RwLockReadGuard;
+#[cfg(not(feature = "sync"))]
+#[doc(hidden)]
+pub use std::sync::RwLockWriteGuard;
+#[cfg(not(feature = "sync"))]
+pub use std_backend::FXProxy;
+#[cfg(not(feature = "sync"))]
+pub use std_backend::FXProxyReadGuard;
+#[cfg(not(feature = "sync"))]
+pub use std_backend::FXProxyWriteGuard;
+#[cfg(not(feature = "sync"))]
+pub use std_backend::FXWriter;
+#[cfg(not(feature = "sync"))]
+#[doc(hidden)]
+pub use std_backend::Mutex;
+#[cfg(not(feature = "sync"))]
+pub use std_backend::OnceCell;
+#[cfg(not(feature = "sync"))]
+pub use std_backend::RwLock;
+
 #[inline(always)]
 pub fn new_lazy_container<T>(value: Option<T>) -> OnceCell<T> {
     if let Some(v) = value {
@@ -0,0 +1,58 @@
+use crate::traits::FXBuilderWrapper;
+use crate::traits::FXStruct;
+use std::fmt::Debug;
+
+#[doc(hidden)]
+pub trait FXBuilderWrapperSync: FXBuilderWrapper {
+    fn invoke(&self, owner: &Self::Owner) -> Result<Self::Value, Self::Error>;
+}
+
+#[doc(hidden)]
+#[derive(Clone)]
+pub struct FXBuilderInfallible<S, T> {
+    builder: fn(&S) -> T,
+}
+
+impl<S, T> FXBuilderInfallible<S, T> {
+    pub fn new(builder: fn(&S) -> T) -> Self {
+        Self { builder }
+    }
+}
+
+impl<S: FXStruct, T> FXBuilderWrapper for FXBuilderInfallible<S, T> {
+    type Error = ();
+    type Owner = S;
+    type Value = T;
+}
+
+impl<S: FXStruct, T> FXBuilderWrapperSync for FXBuilderInfallible<S, T> {
+    #[inline(always)]
+    fn invoke(&self, owner: &Self::Owner) -> Result<Self::Value, Self::Error> {
+        Ok((self.builder)(owner))
+    }
+}
+
+#[doc(hidden)]
+#[derive(Clone)]
+pub struct FXBuilderFallible<S, T, E> {
+    builder: fn(&S) -> Result<T, E>,
+}
+
+impl<S, T, E> FXBuilderFallible<S, T, E> {
+    pub fn new(builder: fn(&S) -> Result<T, E>) -> Self {
+        Self { builder }
+    }
+}
+
+impl<S: FXStruct, T, E: Debug> FXBuilderWrapper for FXBuilderFallible<S, T, E> {
+    type Error = E;
+    type Owner = S;
+    type Value = T;
+}
+
+impl<S: FXStruct, T, E: Debug> FXBuilderWrapperSync for FXBuilderFallible<S, T, E> {
+    #[inline(always)]
+    fn invoke(&self, owner: &Self::Owner) -> Result<Self::Value, Self::Error> {
+        (self.builder)(owner)
+    }
+}
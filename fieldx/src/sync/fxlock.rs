@@ -1,4 +1,7 @@
+#[cfg(feature = "sync")]
 use parking_lot::RwLock;
+#[cfg(not(feature = "sync"))]
+use super::std_backend::RwLock;
 use std::borrow::Borrow;
 use std::fmt;
 use std::fmt::Debug;
@@ -1,5 +1,5 @@
-use crate::traits::FXBuilderWrapper;
-use crate::traits::FXStruct;
+#[doc(hidden)]
+pub use super::fxbuilder::FXBuilderWrapperSync;
 use parking_lot::RwLock;
 use parking_lot::RwLockReadGuard;
 use parking_lot::RwLockUpgradableReadGuard;
@@ -11,65 +11,12 @@ use std::fmt::Formatter;
 use std::fmt::{self};
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
 
 pub type FXProxyReadGuard<'a, T> = crate::lock_guards::FXProxyReadGuard<RwLockReadGuard<'a, Option<T>>, T>;
 pub type FXProxyWriteGuard<'a, T> = crate::lock_guards::FXProxyWriteGuard<RwLockWriteGuard<'a, Option<T>>, T>;
 
-#[doc(hidden)]
-pub trait FXBuilderWrapperSync: FXBuilderWrapper {
-    fn invoke(&self, owner: &Self::Owner) -> Result<Self::Value, Self::Error>;
-}
-
-#[doc(hidden)]
-#[derive(Clone)]
-pub struct FXBuilderInfallible<S, T> {
-    builder: fn(&S) -> T,
-}
-
-impl<S, T> FXBuilderInfallible<S, T> {
-    pub fn new(builder: fn(&S) -> T) -> Self {
-        Self { builder }
-    }
-}
-
-impl<S: FXStruct, T> FXBuilderWrapper for FXBuilderInfallible<S, T> {
-    type Error = ();
-    type Owner = S;
-    type Value = T;
-}
-
-impl<S: FXStruct, T> FXBuilderWrapperSync for FXBuilderInfallible<S, T> {
-    #[inline(always)]
-    fn invoke(&self, owner: &Self::Owner) -> Result<Self::Value, Self::Error> {
-        Ok((self.builder)(owner))
-    }
-}
-
-#[doc(hidden)]
-#[derive(Clone)]
-pub struct FXBuilderFallible<S, T, E> {
-    builder: fn(&S) -> Result<T, E>,
-}
-
-impl<S, T, E> FXBuilderFallible<S, T, E> {
-    pub fn new(builder: fn(&S) -> Result<T, E>) -> Self {
-        Self { builder }
-    }
-}
-
-impl<S: FXStruct, T, E: Debug> FXBuilderWrapper for FXBuilderFallible<S, T, E> {
-    type Error = E;
-    type Owner = S;
-    type Value = T;
-}
-
-impl<S: FXStruct, T, E: Debug> FXBuilderWrapperSync for FXBuilderFallible<S, T, E> {
-    #[inline(always)]
-    fn invoke(&self, owner: &Self::Owner) -> Result<Self::Value, Self::Error> {
-        (self.builder)(owner)
-    }
-}
-
 /// Container type for lazy fields
 pub struct FXProxy<B>
 where
@@ -141,6 +88,25 @@ where
         let _ = self.read_or_init(owner);
     }
 
+    fn init_with_lock(
+        &self,
+        wguard: &mut RwLockWriteGuard<Option<B::Value>>,
+        owner: &B::Owner,
+    ) -> Result<(), B::Error> {
+        // Still uninitialized? Means no other thread took care of it yet.
+        if wguard.is_none() {
+            // No value has been set yet
+            match *self.builder.read() {
+                Some(ref builder_cb) => {
+                    **wguard = Some((*builder_cb).invoke(owner)?);
+                    self.is_set_raw().store(true, Ordering::SeqCst);
+                }
+                None => panic!("Builder is not set"),
+            }
+        }
+        Ok(())
+    }
+
     fn read_or_init<'a>(
         &'a self,
         owner: &B::Owner,
@@ -148,17 +114,7 @@ where
         let guard = self.value.upgradable_read();
         Ok(if (*guard).is_none() {
             let mut wguard = RwLockUpgradableReadGuard::upgrade(guard);
-            // Still uninitialized? Means no other thread took care of it yet.
-            if wguard.is_none() {
-                // No value has been set yet
-                match *self.builder.read() {
-                    Some(ref builder_cb) => {
-                        *wguard = Some((*builder_cb).invoke(owner)?);
-                        self.is_set_raw().store(true, Ordering::SeqCst);
-                    }
-                    None => panic!("Builder is not set"),
-                }
-            }
+            self.init_with_lock(&mut wguard, owner)?;
             RwLockWriteGuard::downgrade_to_upgradable(wguard)
         }
         else {
@@ -166,6 +122,12 @@ where
         })
     }
 
+    /// Lock the container for reading without initializing it.
+    #[doc(hidden)]
+    pub fn read_raw(&self) -> RwLockReadGuard<'_, Option<B::Value>> {
+        self.value.read()
+    }
+
     /// Lazy-initialize the field if necessary and return lock read guard for the inner value.
     ///
     /// Panics if fallible field builder returns an error.
@@ -198,11 +160,54 @@ where
         )))
     }
 
+    /// Lazy-initialize the field if necessary and return lock read guard for the inner value, waiting for the lock no
+    /// longer than `timeout`. Returns `None` if the time is out. The time the builder takes is not counted.
+    ///
+    /// Panics if fallible field builder returns an error.
+    pub fn read_within<'a>(&'a self, owner: &B::Owner, timeout: Duration) -> Option<FXProxyReadGuard<'a, B::Value>> {
+        self.try_read_within(owner, timeout).unwrap()
+    }
+
+    /// Lazy-initialize the field if necessary and return lock read guard for the inner value, waiting for the lock no
+    /// longer than `timeout`. Returns `Ok(None)` if the time is out. The time the builder takes is not counted.
+    ///
+    /// Return the same error, as fallible field builder if it errors out.
+    pub fn try_read_within<'a>(
+        &'a self,
+        owner: &B::Owner,
+        timeout: Duration,
+    ) -> Result<Option<FXProxyReadGuard<'a, B::Value>>, B::Error> {
+        let start = Instant::now();
+        let Some(guard) = self.value.try_read_for(timeout)
+        else {
+            return Ok(None);
+        };
+        if guard.is_some() {
+            return Ok(Some(FXProxyReadGuard::new(guard)));
+        }
+        drop(guard);
+
+        let Some(mut wguard) = self.value.try_write_for(timeout.saturating_sub(start.elapsed()))
+        else {
+            return Ok(None);
+        };
+        self.init_with_lock(&mut wguard, owner)?;
+        Ok(Some(FXProxyReadGuard::new(RwLockWriteGuard::downgrade(wguard))))
+    }
+
     /// Provides write-lock to directly store the value. Never calls the lazy builder.
     pub fn write<'a>(&'a self) -> FXWriter<'a, B> {
         FXWriter::<'a, B>::new(self.value.write(), self)
     }
 
+    /// Same as [`write`](Self::write) but waits for the lock no longer than `timeout`. Returns `None` if the time is
+    /// out.
+    pub fn write_within<'a>(&'a self, timeout: Duration) -> Option<FXWriter<'a, B>> {
+        self.value
+            .try_write_for(timeout)
+            .map(|wguard| FXWriter::<'a, B>::new(wguard, self))
+    }
+
     fn clear_with_lock(&self, wguard: &mut RwLockWriteGuard<Option<B::Value>>) -> Option<B::Value> {
         self.is_set_raw().store(false, Ordering::SeqCst);
         wguard.take()
@@ -0,0 +1,245 @@
+//! Support for lazy fields of structs declared with `sync(single_lock)`.
+//!
+//! All lock-protected fields of such structs are stored in a single group struct behind one `RwLock`. A lazy field is
+//! kept there as an `Option`. Its builder runs without holding the lock, so it can read other fields of the struct or
+//! initialize other lazy fields of it. The built value is then stored under an upgradable read lock unless another
+//! value has been stored in the meantime. Builders of the same struct are serialized between threads to make sure a
+//! field is built only once.
+//!
+//! A thread holding a read guard of any field of the struct must not cause initialization of a lazy field of it as
+//! the lock cannot be upgraded until the guard is released.
+//!
+//! The functions in this module are used by the generated code and are not meant to be called directly.
+use parking_lot::MappedRwLockReadGuard;
+use parking_lot::MappedRwLockWriteGuard;
+use parking_lot::RwLock;
+use parking_lot::RwLockReadGuard;
+use parking_lot::RwLockUpgradableReadGuard;
+use parking_lot::RwLockWriteGuard;
+use std::convert::Infallible;
+use std::sync::Condvar;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::thread;
+use std::thread::ThreadId;
+use std::time::Duration;
+use std::time::Instant;
+
+// Locks with a lazy builder currently running: lock address, owning thread, and the nesting depth.
+static CLAIMS: Mutex<Vec<(usize, ThreadId, usize)>> = Mutex::new(Vec::new());
+static CLAIM_RELEASED: Condvar = Condvar::new();
+
+// Claims the right to run lazy builders of the lock for as long as it lives. Claims are reentrant for the owning
+// thread so that builders can initialize other lazy fields of the same struct.
+struct InitClaim(usize);
+
+impl InitClaim {
+    // Waits for the claim until the deadline, if there is one. Returns `None` if the time is out.
+    fn new<G>(lock: &RwLock<G>, deadline: Option<Instant>) -> Option<Self> {
+        let addr = lock as *const RwLock<G> as usize;
+        let thread = thread::current().id();
+        let mut claims = CLAIMS.lock().unwrap_or_else(PoisonError::into_inner);
+        loop {
+            match claims.iter_mut().find(|(claimed, ..)| *claimed == addr) {
+                Some((_, owner, depth)) if *owner == thread => {
+                    *depth += 1;
+                    break;
+                }
+                Some(_) => {
+                    claims = match deadline {
+                        Some(deadline) => {
+                            let now = Instant::now();
+                            if now >= deadline {
+                                return None;
+                            }
+                            CLAIM_RELEASED
+                                .wait_timeout(claims, deadline - now)
+                                .unwrap_or_else(PoisonError::into_inner)
+                                .0
+                        }
+                        None => CLAIM_RELEASED.wait(claims).unwrap_or_else(PoisonError::into_inner),
+                    };
+                }
+                None => {
+                    claims.push((addr, thread, 1));
+                    break;
+                }
+            }
+        }
+        Some(Self(addr))
+    }
+}
+
+impl Drop for InitClaim {
+    fn drop(&mut self) {
+        let mut claims = CLAIMS.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(idx) = claims.iter().position(|(claimed, ..)| *claimed == self.0) {
+            claims[idx].2 -= 1;
+            if claims[idx].2 == 0 {
+                claims.swap_remove(idx);
+                CLAIM_RELEASED.notify_all();
+            }
+        }
+    }
+}
+
+// Without a timeout it waits for the locks for as long as necessary and never returns `Ok(None)`. The timeout is
+// restarted after the builder is done.
+fn read_or_init_within<'a, G, T, E>(
+    lock: &'a RwLock<G>,
+    get: fn(&G) -> &Option<T>,
+    get_mut: fn(&mut G) -> &mut Option<T>,
+    init: impl FnOnce() -> Result<T, E>,
+    timeout: Option<Duration>,
+) -> Result<Option<RwLockUpgradableReadGuard<'a, G>>, E> {
+    let start_deadline = || timeout.map(|timeout| Instant::now() + timeout);
+    let upgradable_read = |deadline| match deadline {
+        Some(deadline) => lock.try_upgradable_read_until(deadline),
+        None => Some(lock.upgradable_read()),
+    };
+
+    let deadline = start_deadline();
+    let Some(_claim) = InitClaim::new(lock, deadline)
+    else {
+        return Ok(None);
+    };
+    {
+        // Another thread could have initialized the field while we were waiting for the claim.
+        let Some(guard) = upgradable_read(deadline)
+        else {
+            return Ok(None);
+        };
+        if get(&guard).is_some() {
+            return Ok(Some(guard));
+        }
+    }
+    let value = init()?;
+    let deadline = start_deadline();
+    let Some(guard) = upgradable_read(deadline)
+    else {
+        return Ok(None);
+    };
+    // The field could have been set by a writer while the builder was running; the stored value wins then.
+    if get(&guard).is_some() {
+        return Ok(Some(guard));
+    }
+    let mut wguard = match deadline {
+        Some(deadline) => match RwLockUpgradableReadGuard::try_upgrade_until(guard, deadline) {
+            Ok(wguard) => wguard,
+            Err(_) => return Ok(None),
+        },
+        None => RwLockUpgradableReadGuard::upgrade(guard),
+    };
+    *get_mut(&mut wguard) = Some(value);
+    Ok(Some(RwLockWriteGuard::downgrade_to_upgradable(wguard)))
+}
+
+fn read_or_init<'a, G, T, E>(
+    lock: &'a RwLock<G>,
+    get: fn(&G) -> &Option<T>,
+    get_mut: fn(&mut G) -> &mut Option<T>,
+    init: impl FnOnce() -> Result<T, E>,
+) -> Result<RwLockUpgradableReadGuard<'a, G>, E> {
+    Ok(read_or_init_within(lock, get, get_mut, init, None)?.expect("lazy initialization without a deadline timed out"))
+}
+
+#[inline(always)]
+fn infallible<T>(result: Result<T, Infallible>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(never) => match never {},
+    }
+}
+
+/// Initialize the field if necessary. Errors of fallible builders are ignored.
+pub fn lazy_init<G, T, E>(
+    lock: &RwLock<G>,
+    get: fn(&G) -> &Option<T>,
+    get_mut: fn(&mut G) -> &mut Option<T>,
+    init: impl FnOnce() -> Result<T, E>,
+) {
+    let _ = read_or_init(lock, get, get_mut, init);
+}
+
+/// Initialize the field if necessary and return a read guard for its value.
+///
+/// Return the same error as the fallible field builder if it errors out.
+pub fn try_lazy_read<'a, G, T, E>(
+    lock: &'a RwLock<G>,
+    get: fn(&G) -> &Option<T>,
+    get_mut: fn(&mut G) -> &mut Option<T>,
+    init: impl FnOnce() -> Result<T, E>,
+) -> Result<MappedRwLockReadGuard<'a, T>, E> {
+    if let Ok(guard) = RwLockReadGuard::try_map(lock.read(), |g| get(g).as_ref()) {
+        return Ok(guard);
+    }
+    let guard = RwLockUpgradableReadGuard::downgrade(read_or_init(lock, get, get_mut, init)?);
+    Ok(RwLockReadGuard::map(guard, |g| get(g).as_ref().unwrap()))
+}
+
+/// Initialize the field if necessary and return a read guard for its value.
+pub fn lazy_read<'a, G, T>(
+    lock: &'a RwLock<G>,
+    get: fn(&G) -> &Option<T>,
+    get_mut: fn(&mut G) -> &mut Option<T>,
+    init: impl FnOnce() -> T,
+) -> MappedRwLockReadGuard<'a, T> {
+    infallible(try_lazy_read(lock, get, get_mut, || Ok(init())))
+}
+
+/// Initialize the field if necessary and return a read guard for its value, waiting for the lock no longer than
+/// `timeout`. Returns `Ok(None)` if the time is out. The time the builder takes is not counted.
+///
+/// Return the same error as the fallible field builder if it errors out.
+pub fn try_lazy_read_within<'a, G, T, E>(
+    lock: &'a RwLock<G>,
+    get: fn(&G) -> &Option<T>,
+    get_mut: fn(&mut G) -> &mut Option<T>,
+    init: impl FnOnce() -> Result<T, E>,
+    timeout: Duration,
+) -> Result<Option<MappedRwLockReadGuard<'a, T>>, E> {
+    let Some(guard) = lock.try_read_for(timeout)
+    else {
+        return Ok(None);
+    };
+    if let Ok(guard) = RwLockReadGuard::try_map(guard, |g| get(g).as_ref()) {
+        return Ok(Some(guard));
+    }
+    Ok(read_or_init_within(lock, get, get_mut, init, Some(timeout))?
+        .map(|guard| RwLockReadGuard::map(RwLockUpgradableReadGuard::downgrade(guard), |g| get(g).as_ref().unwrap())))
+}
+
+/// Initialize the field if necessary and return a read guard for its value, waiting for the lock no longer than
+/// `timeout`. Returns `None` if the time is out.
+pub fn lazy_read_within<'a, G, T>(
+    lock: &'a RwLock<G>,
+    get: fn(&G) -> &Option<T>,
+    get_mut: fn(&mut G) -> &mut Option<T>,
+    init: impl FnOnce() -> T,
+    timeout: Duration,
+) -> Option<MappedRwLockReadGuard<'a, T>> {
+    infallible(try_lazy_read_within(lock, get, get_mut, || Ok(init()), timeout))
+}
+
+/// Initialize the field if necessary and return a write guard for its value.
+///
+/// Return the same error as the fallible field builder if it errors out.
+pub fn try_lazy_write<'a, G, T, E>(
+    lock: &'a RwLock<G>,
+    get: fn(&G) -> &Option<T>,
+    get_mut: fn(&mut G) -> &mut Option<T>,
+    init: impl FnOnce() -> Result<T, E>,
+) -> Result<MappedRwLockWriteGuard<'a, T>, E> {
+    let guard = RwLockUpgradableReadGuard::upgrade(read_or_init(lock, get, get_mut, init)?);
+    Ok(RwLockWriteGuard::map(guard, |g| get_mut(g).as_mut().unwrap()))
+}
+
+/// Initialize the field if necessary and return a write guard for its value.
+pub fn lazy_write<'a, G, T>(
+    lock: &'a RwLock<G>,
+    get: fn(&G) -> &Option<T>,
+    get_mut: fn(&mut G) -> &mut Option<T>,
+    init: impl FnOnce() -> T,
+) -> MappedRwLockWriteGuard<'a, T> {
+    infallible(try_lazy_write(lock, get, get_mut, || Ok(init())))
+}
@@ -0,0 +1,16 @@
+//! Implementation of the `sync-std` backend, based solely on the standard library synchronization primitives.
+//!
+//! Standard locks are poisoned when a thread panics while holding them. FieldX ignores poisoning: a poisoned lock is
+//! acquired as if nothing happened, and the data is handed over in whatever state the panicking thread left it. This
+//! is consistent with the default `parking_lot` backend, which doesn't implement poisoning at all.
+mod fxproxy;
+mod lock;
+mod once;
+
+pub use fxproxy::FXProxy;
+pub use fxproxy::FXProxyReadGuard;
+pub use fxproxy::FXProxyWriteGuard;
+pub use fxproxy::FXWriter;
+pub use lock::Mutex;
+pub use lock::RwLock;
+pub use once::OnceCell;
@@ -0,0 +1,166 @@
+use std::fmt;
+use std::fmt::Debug;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::sync::RwLockReadGuard;
+use std::sync::RwLockWriteGuard;
+use std::sync::TryLockError;
+use std::sync::TryLockResult;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+#[inline(always)]
+fn try_lock_recover<G>(result: TryLockResult<G>) -> Option<G> {
+    match result {
+        Ok(guard) => Some(guard),
+        Err(TryLockError::Poisoned(err)) => Some(err.into_inner()),
+        Err(TryLockError::WouldBlock) => None,
+    }
+}
+
+// The standard library has no timed locking, so the lock is polled with exponentially growing pauses until either it
+// is acquired or the time is out.
+fn try_lock_for<G>(timeout: Duration, mut try_lock: impl FnMut() -> Option<G>) -> Option<G> {
+    let start = Instant::now();
+    let mut pause = Duration::from_micros(1);
+    loop {
+        if let Some(guard) = try_lock() {
+            return Some(guard);
+        }
+        let elapsed = start.elapsed();
+        if elapsed >= timeout {
+            return None;
+        }
+        thread::sleep(pause.min(timeout - elapsed));
+        pause = (pause * 2).min(Duration::from_millis(1));
+    }
+}
+
+/// Read-write lock of the `sync-std` backend
+///
+/// A wrapper around [`std::sync::RwLock`] which locking methods return guards directly, the same way `parking_lot`
+/// does. Lock poisoning is ignored: if a thread panics while holding the lock, the next locker receives the data in
+/// whatever state it was left in.
+pub struct RwLock<T: ?Sized>(std::sync::RwLock<T>);
+
+impl<T> RwLock<T> {
+    /// Creates a new lock wrapping the value.
+    pub const fn new(value: T) -> Self {
+        Self(std::sync::RwLock::new(value))
+    }
+
+    /// Consumes the lock and returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T: ?Sized> RwLock<T> {
+    /// Locks for shared read access, blocking the current thread until the lock can be acquired.
+    pub fn read(&self) -> RwLockReadGuard<'_, T> {
+        self.0.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Locks for exclusive write access, blocking the current thread until the lock can be acquired.
+    pub fn write(&self) -> RwLockWriteGuard<'_, T> {
+        self.0.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Attempts to lock for shared read access without blocking. Returns `None` if the lock is held exclusively.
+    pub fn try_read(&self) -> Option<RwLockReadGuard<'_, T>> {
+        try_lock_recover(self.0.try_read())
+    }
+
+    /// Attempts to lock for exclusive write access without blocking. Returns `None` if the lock is held.
+    pub fn try_write(&self) -> Option<RwLockWriteGuard<'_, T>> {
+        try_lock_recover(self.0.try_write())
+    }
+
+    /// Attempts to lock for shared read access, waiting no longer than `timeout`. Returns `None` if the time is out.
+    pub fn try_read_for(&self, timeout: Duration) -> Option<RwLockReadGuard<'_, T>> {
+        try_lock_for(timeout, || self.try_read())
+    }
+
+    /// Attempts to lock for exclusive write access, waiting no longer than `timeout`. Returns `None` if the time is
+    /// out.
+    pub fn try_write_for(&self, timeout: Duration) -> Option<RwLockWriteGuard<'_, T>> {
+        try_lock_for(timeout, || self.try_write())
+    }
+
+    /// Returns a mutable reference to the wrapped value. No locking is needed since the borrow is exclusive.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.0.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> From<T> for RwLock<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Default> Default for RwLock<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for RwLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Mutex of the `sync-std` backend
+///
+/// A wrapper around [`std::sync::Mutex`] which follows the same rules as [`RwLock`]: guards are returned directly
+/// and poisoning is ignored.
+pub struct Mutex<T: ?Sized>(std::sync::Mutex<T>);
+
+impl<T> Mutex<T> {
+    /// Creates a new mutex wrapping the value.
+    pub const fn new(value: T) -> Self {
+        Self(std::sync::Mutex::new(value))
+    }
+
+    /// Consumes the mutex and returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T: ?Sized> Mutex<T> {
+    /// Acquires the mutex, blocking the current thread until it is able to do so.
+    pub fn lock(&self) -> MutexGuard<'_, T> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Attempts to acquire the mutex without blocking. Returns `None` if it is already locked.
+    pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
+        try_lock_recover(self.0.try_lock())
+    }
+
+    /// Returns a mutable reference to the wrapped value. No locking is needed since the borrow is exclusive.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.0.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> From<T> for Mutex<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Default> Default for Mutex<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for Mutex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
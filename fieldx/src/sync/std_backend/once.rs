@@ -0,0 +1,115 @@
+use std::fmt;
+use std::fmt::Debug;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::sync::PoisonError;
+
+/// Lazy field container of the `sync-std` backend
+///
+/// A wrapper around [`std::sync::OnceLock`] which adds fallible initialization. Concurrent fallible initializers are
+/// serialized so that only one of them runs at a time; if it fails the next one gets its chance.
+pub struct OnceCell<T> {
+    cell:      OnceLock<T>,
+    init_lock: Mutex<()>,
+}
+
+impl<T> OnceCell<T> {
+    /// Creates a new empty cell.
+    pub const fn new() -> Self {
+        Self {
+            cell:      OnceLock::new(),
+            init_lock: Mutex::new(()),
+        }
+    }
+
+    /// Returns the value if the cell is initialized.
+    pub fn get(&self) -> Option<&T> {
+        self.cell.get()
+    }
+
+    /// Returns a mutable reference to the value if the cell is initialized.
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        self.cell.get_mut()
+    }
+
+    /// Initializes the cell with the value. Gives the value back if the cell is already initialized.
+    pub fn set(&self, value: T) -> Result<(), T> {
+        self.cell.set(value)
+    }
+
+    /// Returns the value, initializing the cell with `f` if necessary.
+    pub fn get_or_init<F>(&self, f: F) -> &T
+    where
+        F: FnOnce() -> T,
+    {
+        self.cell.get_or_init(f)
+    }
+
+    /// Returns the value, initializing the cell with `f` if necessary. If `f` fails the cell remains uninitialized and
+    /// the error is returned.
+    pub fn get_or_try_init<F, E>(&self, f: F) -> Result<&T, E>
+    where
+        F: FnOnce() -> Result<T, E>,
+    {
+        if let Some(value) = self.cell.get() {
+            return Ok(value);
+        }
+
+        // The lock is only guarding the initializer, there is no data to be poisoned.
+        let _guard = self.init_lock.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(value) = self.cell.get() {
+            return Ok(value);
+        }
+
+        let value = f()?;
+        Ok(self.cell.get_or_init(|| value))
+    }
+
+    /// Takes the value out of the cell, leaving it uninitialized.
+    pub fn take(&mut self) -> Option<T> {
+        self.cell.take()
+    }
+
+    /// Consumes the cell and returns the wrapped value, if any.
+    pub fn into_inner(self) -> Option<T> {
+        self.cell.into_inner()
+    }
+}
+
+impl<T> Default for OnceCell<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<T> for OnceCell<T> {
+    fn from(value: T) -> Self {
+        Self {
+            cell:      OnceLock::from(value),
+            init_lock: Mutex::new(()),
+        }
+    }
+}
+
+impl<T: Clone> Clone for OnceCell<T> {
+    fn clone(&self) -> Self {
+        match self.cell.get() {
+            Some(value) => Self::from(value.clone()),
+            None => Self::new(),
+        }
+    }
+}
+
+impl<T: Debug> Debug for OnceCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.cell.fmt(f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for OnceCell<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cell.get() == other.cell.get()
+    }
+}
+
+impl<T: Eq> Eq for OnceCell<T> {}
@@ -0,0 +1,133 @@
+use fieldx::fxstruct;
+use std::path::Path;
+use std::path::PathBuf;
+
+#[fxstruct(get(deref))]
+struct FooPlain {
+    #[fieldx(default("foo".to_string()))]
+    name: String,
+
+    #[fieldx(default(vec![1, 2, 3]))]
+    items: Vec<u32>,
+
+    #[fieldx(default(PathBuf::from("/tmp")))]
+    path: PathBuf,
+
+    #[fieldx(optional, default("comment".to_string()))]
+    comment: String,
+
+    #[fieldx(lazy)]
+    computed: String,
+
+    #[fieldx(get(copy))]
+    count: u32,
+}
+
+impl FooPlain {
+    fn build_computed(&self) -> String {
+        format!("{}!", self.name)
+    }
+}
+
+#[test]
+fn plain_deref() {
+    let foo = FooPlain::new();
+
+    let name: &str = foo.name();
+    assert_eq!(name, "foo");
+    let items: &[u32] = foo.items();
+    assert_eq!(items, &[1, 2, 3]);
+    let path: &Path = foo.path();
+    assert_eq!(path, Path::new("/tmp"));
+    let comment: Option<&str> = foo.comment();
+    assert_eq!(comment, Some("comment"));
+    let computed: &str = foo.computed();
+    assert_eq!(computed, "foo!");
+    assert_eq!(foo.count(), 0);
+}
+
+#[cfg(feature = "sync")]
+mod sync {
+    use fieldx::fxstruct;
+    use fieldx::sync::MappedRwLockReadGuard;
+
+    #[fxstruct(sync, get(deref))]
+    struct FooSync {
+        #[fieldx(default("bare".to_string()))]
+        bare: String,
+
+        #[fieldx(lock, default("locked".to_string()))]
+        locked: String,
+
+        #[fieldx(lock, lazy)]
+        lazy_locked: Vec<u8>,
+
+        #[fieldx(lock, lazy, fallible(error(String)))]
+        fallible: String,
+
+        #[fieldx(lazy)]
+        lazy_bare: String,
+    }
+
+    impl FooSync {
+        fn build_lazy_locked(&self) -> Vec<u8> {
+            vec![4, 5]
+        }
+
+        fn build_fallible(&self) -> Result<String, String> {
+            Ok("fallible".to_string())
+        }
+
+        fn build_lazy_bare(&self) -> String {
+            "lazy bare".to_string()
+        }
+    }
+
+    #[test]
+    fn sync_deref() {
+        let foo = FooSync::new();
+
+        let bare: &str = foo.bare();
+        assert_eq!(bare, "bare");
+
+        let locked: MappedRwLockReadGuard<str> = foo.locked();
+        assert_eq!(&*locked, "locked");
+        drop(locked);
+
+        let lazy_locked: MappedRwLockReadGuard<[u8]> = foo.lazy_locked();
+        assert_eq!(&*lazy_locked, &[4, 5]);
+
+        assert_eq!(&*foo.fallible().unwrap(), "fallible");
+
+        let lazy_bare: &str = foo.lazy_bare();
+        assert_eq!(lazy_bare, "lazy bare");
+    }
+}
+
+#[cfg(feature = "async")]
+mod asyncish {
+    use fieldx::fxstruct;
+
+    #[fxstruct(r#async, get(deref))]
+    struct FooAsync {
+        #[fieldx(lock, default("locked".to_string()))]
+        locked: String,
+
+        #[fieldx(lock, lazy)]
+        lazy_locked: Vec<u8>,
+    }
+
+    impl FooAsync {
+        async fn build_lazy_locked(&self) -> Vec<u8> {
+            vec![6, 7]
+        }
+    }
+
+    #[tokio::test]
+    async fn async_deref() {
+        let foo = FooAsync::new();
+
+        assert_eq!(&*foo.locked().await, "locked");
+        assert_eq!(&*foo.lazy_locked().await, &[6, 7]);
+    }
+}
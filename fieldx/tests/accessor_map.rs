@@ -0,0 +1,111 @@
+use fieldx::fxstruct;
+use std::collections::HashMap;
+
+fn first_segment(path: &str) -> &str {
+    path.split('/').find(|s| !s.is_empty()).unwrap_or("")
+}
+
+fn vec_len(v: &[u32]) -> usize {
+    v.len()
+}
+
+fn push_one(v: &mut Vec<u32>) -> usize {
+    v.push(1);
+    v.len()
+}
+
+fn map_len(m: &HashMap<String, u32>) -> usize {
+    m.len()
+}
+
+#[fxstruct(get)]
+struct FooPlain {
+    #[fieldx(get(map(first_segment, &str)), default("/usr/local".to_string()))]
+    path: String,
+
+    #[fieldx(get("items_len", map(vec_len, usize)), get_mut("push_item", map = push_one), default(vec![1, 2]))]
+    items: Vec<u32>,
+
+    #[fieldx(optional, get(map(map_len, usize)))]
+    maybe: HashMap<String, u32>,
+
+    #[fieldx(get(map(first_segment, impl std::fmt::Display + '_)), default("/opt/bin".to_string()))]
+    shown: String,
+
+    #[fieldx(inner_mut, get(map(map_len, usize)))]
+    cache: HashMap<String, u32>,
+
+    #[fieldx(lazy, get(map(vec_len, usize)))]
+    computed: Vec<u32>,
+}
+
+impl FooPlain {
+    fn build_computed(&self) -> Vec<u32> {
+        vec![1, 2, 3, 4]
+    }
+}
+
+#[test]
+fn plain_map() {
+    let mut foo = FooPlain::new();
+
+    assert_eq!(foo.path(), "usr");
+    assert_eq!(foo.items_len(), 2);
+    // Without the type the result is opaque, the projection is used for its side effect here.
+    let _ = foo.push_item();
+    assert_eq!(foo.items_len(), 3);
+    assert_eq!(foo.shown().to_string(), "opt");
+    assert_eq!(foo.maybe(), None);
+    foo.maybe = Some(HashMap::from([("a".to_string(), 1)]));
+    assert_eq!(foo.maybe(), Some(1));
+    assert_eq!(foo.cache(), 0);
+    foo.cache.borrow_mut().insert("a".to_string(), 1);
+    assert_eq!(foo.cache(), 1);
+    assert_eq!(foo.computed(), 4);
+}
+
+#[cfg(feature = "sync")]
+mod sync {
+    use super::map_len;
+    use super::push_one;
+    use super::vec_len;
+    use fieldx::fxstruct;
+    use std::collections::HashMap;
+
+    #[fxstruct(sync, get)]
+    struct FooSync {
+        #[fieldx(lock, get(map(map_len, usize)))]
+        map: HashMap<String, u32>,
+
+        #[fieldx(lock, get_mut(map = push_one), get(map(vec_len, usize)), default(vec![7]))]
+        items: Vec<u32>,
+
+        #[fieldx(lock, lazy, get(map(vec_len, usize)))]
+        lazy_items: Vec<u32>,
+
+        #[fieldx(lazy, fallible(error(String)), get(map(vec_len, usize)))]
+        fallible: Vec<u32>,
+    }
+
+    impl FooSync {
+        fn build_lazy_items(&self) -> Vec<u32> {
+            vec![1, 2]
+        }
+
+        fn build_fallible(&self) -> Result<Vec<u32>, String> {
+            Ok(vec![1, 2, 3])
+        }
+    }
+
+    #[test]
+    fn sync_map() {
+        let foo = FooSync::new();
+
+        assert_eq!(foo.map(), 0);
+        assert_eq!(foo.items(), 1);
+        let _ = foo.items_mut();
+        assert_eq!(foo.items(), 2);
+        assert_eq!(foo.lazy_items(), 2);
+        assert_eq!(foo.fallible(), Ok(3));
+    }
+}
@@ -0,0 +1,130 @@
+#![cfg(all(feature = "sync", feature = "arc-swap"))]
+use fieldx::fxstruct;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::thread;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Routes {
+    table:   HashMap<String, u16>,
+    version: u32,
+}
+
+#[fxstruct(sync, get, set, builder)]
+struct Router {
+    #[fieldx(swap, default)]
+    routes: Routes,
+
+    #[fieldx(swap, set(into), default("primary".to_string()))]
+    name: String,
+
+    #[fieldx(lock, get(copy), default(1))]
+    hits: u32,
+}
+
+#[test]
+fn swap_accessors() {
+    let router = Router::new();
+
+    let snapshot = router.routes();
+    assert_eq!(snapshot.version, 0);
+
+    let old = router.set_routes(Routes {
+        table:   HashMap::from([("/".to_string(), 80)]),
+        version: 1,
+    });
+    assert_eq!(old.version, 0);
+    // The snapshot taken earlier is not affected.
+    assert_eq!(snapshot.version, 0);
+    assert_eq!(router.routes().table.get("/"), Some(&80));
+
+    let old = router.update_routes(|r| {
+        let mut r = r.clone();
+        r.version += 1;
+        r
+    });
+    assert_eq!(old.version, 1);
+    assert_eq!(router.routes().version, 2);
+
+    assert_eq!(*router.name(), "primary");
+    assert_eq!(*router.set_name("secondary"), "primary");
+    assert_eq!(*router.name(), "secondary");
+
+    assert_eq!(router.set_hits(2), 1);
+    assert_eq!(router.hits(), 2);
+}
+
+#[test]
+fn swap_builder() {
+    let router = Router::builder()
+        .name("built".to_string())
+        .hits(3)
+        .build()
+        .expect("Failed to build Router");
+    assert_eq!(*router.name(), "built");
+    assert_eq!(*router.routes(), Routes::default());
+    assert_eq!(router.hits(), 3);
+
+    let router = Router::builder()
+        .routes(Routes {
+            table:   HashMap::new(),
+            version: 7,
+        })
+        .build()
+        .expect("Failed to build Router");
+    assert_eq!(router.routes().version, 7);
+}
+
+#[test]
+fn swap_concurrent_update() {
+    let router = Arc::new(Router::new());
+
+    let handles = (0..4)
+        .map(|_| {
+            let router = Arc::clone(&router);
+            thread::spawn(move || {
+                for _ in 0..100 {
+                    router.update_routes(|r| Routes {
+                        table:   r.table.clone(),
+                        version: r.version + 1,
+                    });
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    assert_eq!(router.routes().version, 400);
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use fieldx::fxstruct;
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    #[fxstruct(sync, get, serde(default))]
+    struct Config {
+        #[fieldx(swap)]
+        peers: Vec<String>,
+    }
+
+    // ArcSwap is not Clone, but serialization goes through a clone of the struct.
+    impl Clone for Config {
+        fn clone(&self) -> Self {
+            Self {
+                peers: fieldx::arc_swap::ArcSwap::new(self.peers()),
+            }
+        }
+    }
+
+    #[test]
+    fn swap_serde() {
+        let config: Config = serde_json::from_str(r#"{"peers": ["a", "b"]}"#).unwrap();
+        assert_eq!(*config.peers(), vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"peers":["a","b"]}"#);
+    }
+}
@@ -0,0 +1,147 @@
+#![cfg(feature = "sync")]
+use fieldx::fxstruct;
+use std::sync::Arc;
+use std::thread;
+
+#[fxstruct(sync, get, set, builder)]
+struct Stats {
+    #[fieldx(atomic, default(10))]
+    hits: u64,
+
+    #[fieldx(atomic(ordering(Relaxed)))]
+    delta: i32,
+
+    #[fieldx(atomic(ordering(AcqRel)), get_mut)]
+    ready: bool,
+
+    #[fieldx(atomic, builder(off), get(off), set(off), default(1))]
+    slots: usize,
+
+    #[fieldx(lock, get(copy))]
+    plain: u32,
+}
+
+#[test]
+fn atomic_accessors() {
+    let mut stats = Stats::new();
+
+    assert_eq!(stats.hits(), 10);
+    assert_eq!(stats.set_hits(20), 10);
+    assert_eq!(stats.fetch_add_hits(5), 20);
+    assert_eq!(stats.hits(), 25);
+    assert_eq!(stats.swap_hits(1), 25);
+    assert_eq!(stats.compare_exchange_hits(1, 2), Ok(1));
+    assert_eq!(stats.compare_exchange_hits(1, 3), Err(2));
+
+    assert_eq!(stats.fetch_add_delta(-3), 0);
+    assert_eq!(stats.set_delta(4), -3);
+    assert_eq!(stats.delta(), 4);
+
+    assert!(!stats.ready());
+    assert!(!stats.set_ready(true));
+    assert_eq!(stats.compare_exchange_ready(true, false), Ok(true));
+    *stats.ready_mut() = true;
+    assert!(stats.ready());
+
+    assert_eq!(stats.fetch_add_slots(1), 1);
+    assert_eq!(stats.set_plain(1), 0);
+    assert_eq!(stats.plain(), 1);
+}
+
+#[test]
+fn atomic_builder() {
+    let stats = Stats::builder()
+        .hits(3)
+        .delta(-1)
+        .ready(true)
+        .plain(2)
+        .build()
+        .expect("Failed to build Stats");
+    assert_eq!(stats.hits(), 3);
+    assert_eq!(stats.delta(), -1);
+    assert!(stats.ready());
+    assert_eq!(stats.plain(), 2);
+}
+
+#[test]
+fn atomic_threads() {
+    let stats = Arc::new(Stats::new());
+
+    let handles = (0..4)
+        .map(|_| {
+            let stats = Arc::clone(&stats);
+            thread::spawn(move || {
+                for _ in 0..1000 {
+                    stats.fetch_add_hits(1);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    assert_eq!(stats.hits(), 4010);
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use fieldx::fxstruct;
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    #[fxstruct(sync, get, serde(default))]
+    #[derive(Clone)]
+    struct Counters {
+        #[fieldx(atomic)]
+        total: u32,
+        #[fieldx(atomic)]
+        enabled: bool,
+    }
+
+    #[test]
+    fn atomic_serde() {
+        let counters: Counters = serde_json::from_str(r#"{"total": 7, "enabled": true}"#).unwrap();
+        assert_eq!(counters.total(), 7);
+        assert!(counters.enabled());
+        counters.fetch_add_total(1);
+        assert_eq!(
+            serde_json::to_string(&counters).unwrap(),
+            r#"{"total":8,"enabled":true}"#
+        );
+
+        let copy = counters.clone();
+        counters.fetch_add_total(1);
+        assert_eq!(copy.total(), 8);
+        assert_eq!(counters.total(), 9);
+    }
+}
+
+#[cfg(feature = "async")]
+mod asyncish {
+    use fieldx::fxstruct;
+
+    #[fxstruct(r#async, get(copy), set)]
+    struct FooAsync {
+        #[fieldx(atomic, default(1))]
+        counter: u32,
+
+        #[fieldx(lock, default(2))]
+        locked: u32,
+    }
+
+    #[tokio::test]
+    async fn async_atomic() {
+        let foo = FooAsync::new();
+
+        // Atomic helpers never wait.
+        assert_eq!(foo.counter(), 1);
+        assert_eq!(foo.set_counter(5), 1);
+        assert_eq!(foo.fetch_add_counter(1), 5);
+        assert_eq!(foo.counter(), 6);
+
+        assert_eq!(foo.set_locked(3).await, 2);
+        assert_eq!(foo.locked().await, 3);
+    }
+}
@@ -0,0 +1,134 @@
+use fieldx::error::FieldXError;
+use fieldx::fxstruct;
+
+#[fxstruct(
+    builder(
+        check(self.min <= self.max, "min must not exceed max"),
+        check(has_valid_name, "name must not be empty"),
+        post_build
+    ),
+    get(copy)
+)]
+#[derive(Debug)]
+struct Range {
+    min:  i32,
+    max:  i32,
+    #[fieldx(get(clone), default(String::from("range")))]
+    name: String,
+    #[fieldx(default(false))]
+    post_built: bool,
+}
+
+impl Range {
+    fn has_valid_name(&self) -> bool {
+        !self.name.is_empty()
+    }
+
+    fn post_build(mut self) -> Self {
+        self.post_built = true;
+        self
+    }
+}
+
+#[fxstruct(rc, builder(check(self.limit > 0, "limit must be positive")), get(copy))]
+#[derive(Debug)]
+struct Limited {
+    limit: u32,
+}
+
+#[cfg(feature = "sync")]
+#[fxstruct(sync, builder(check(*self.count.read() <= 10, "count is too big")))]
+struct Counter {
+    #[fieldx(lock, get(copy))]
+    count: u32,
+}
+
+mod my {
+    use fieldx::error::FieldXError;
+    use thiserror::Error;
+
+    #[derive(Error, Debug)]
+    pub enum Error {
+        #[error("builder error: {0}")]
+        Builder(#[from] FieldXError),
+
+        #[error("{0}")]
+        Invalid(String),
+    }
+
+    // Not convertible from `FieldXError`.
+    #[derive(Debug)]
+    pub enum Rejected {
+        Message(Box<str>),
+    }
+}
+
+#[fxstruct(builder(error(my::Error), check(self.port != 0, "port must be set")), get(copy))]
+#[derive(Debug)]
+struct Server {
+    port: u16,
+}
+
+#[fxstruct(builder(error(my::Error, my::Error::Invalid), check(self.port != 0, "port must be set")), get(copy))]
+#[derive(Debug)]
+struct Client {
+    port: u16,
+}
+
+#[fxstruct(builder(error(my::Rejected, my::Rejected::Message), check(self.port != 0, "port must be set")), get(copy))]
+#[derive(Debug)]
+struct Proxy {
+    port: u16,
+}
+
+#[test]
+fn passing() {
+    let range = Range::builder().min(1).max(2).build().unwrap();
+    assert_eq!(range.min(), 1);
+    assert_eq!(range.max(), 2);
+    assert!(range.post_built(), "post_build is called when all checks pass");
+
+    let limited = Limited::builder().limit(3).build().unwrap();
+    assert_eq!(limited.limit(), 3);
+}
+
+#[test]
+fn failing() {
+    let err = Range::builder().min(2).max(1).build().unwrap_err();
+    let FieldXError::Validation(failed) = err
+    else {
+        panic!("expected validation error, got {err:?}");
+    };
+    assert_eq!(failed, vec!["min must not exceed max".to_string()]);
+
+    let err = Range::builder().min(2).max(1).name(String::new()).build().unwrap_err();
+    assert_eq!(err.to_string(), "Validation failed: min must not exceed max; name must not be empty");
+
+    // Missing fields are reported before any check is evaluated.
+    let err = Range::builder().min(2).build().unwrap_err();
+    assert!(matches!(err, FieldXError::UninitializedField(ref name) if name == "max"));
+
+    let err = Limited::builder().limit(0).build().unwrap_err();
+    assert!(matches!(err, FieldXError::Validation(ref failed) if failed == &["limit must be positive"]));
+
+    let err = Server::builder().port(0).build().unwrap_err();
+    assert!(matches!(err, my::Error::Builder(FieldXError::Validation(_))));
+    assert_eq!(Server::builder().port(80).build().unwrap().port(), 80);
+
+    let err = Client::builder().port(0).build().unwrap_err();
+    assert!(matches!(err, my::Error::Invalid(ref msg) if msg == "Validation failed: port must be set"));
+    assert_eq!(Client::builder().port(80).build().unwrap().port(), 80);
+
+    let err = Proxy::builder().port(0).build().unwrap_err();
+    assert!(matches!(err, my::Rejected::Message(ref msg) if &**msg == "Validation failed: port must be set"));
+    let err = Proxy::builder().build().unwrap_err();
+    assert!(matches!(err, my::Rejected::Message(ref msg) if &**msg == "port"));
+    assert_eq!(Proxy::builder().port(80).build().unwrap().port(), 80);
+}
+
+#[cfg(feature = "sync")]
+#[test]
+fn nsync() {
+    assert_eq!(Counter::builder().count(10).build().unwrap().count(), 10);
+    assert!(matches!(Counter::builder().count(11).build(), Err(FieldXError::Validation(_))));
+}
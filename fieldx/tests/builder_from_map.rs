@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use fieldx::error::FieldXError;
+use fieldx::fxstruct;
+
+#[fxstruct(builder(from_map, attributes(derive(Debug))), get)]
+#[derive(Debug)]
+struct FooPlain {
+    #[fieldx(get(copy))]
+    port: u16,
+
+    host: String,
+
+    #[fieldx(rename("ratio"), get(copy), default(0.5))]
+    factor: f32,
+
+    #[fieldx(optional, get(copy))]
+    verbose: bool,
+
+    #[fieldx(lazy, builder(off), get(copy))]
+    computed: u32,
+
+    // Doesn't implement `FromStr`.
+    #[fieldx(builder(from_map(off)), default(vec![1]))]
+    tags: Vec<u32>,
+}
+
+impl FooPlain {
+    fn build_computed(&self) -> u32 {
+        u32::from(self.port) + 1
+    }
+}
+
+#[cfg(feature = "sync")]
+#[fxstruct(sync, builder(from_map), get)]
+#[derive(Debug)]
+struct FooSync {
+    #[fieldx(get(copy))]
+    port: u16,
+
+    #[fieldx(lock, get(clone))]
+    host: String,
+}
+
+fn str_map(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+#[test]
+fn plain() {
+    let foo = FooPlainBuilder::from_str_map(&str_map(&[("port", "8080"), ("host", "localhost"), ("ratio", "0.25")]))
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(foo.port(), 8080);
+    assert_eq!(foo.host(), "localhost");
+    assert_eq!(foo.ratio(), 0.25);
+    assert_eq!(foo.verbose(), None);
+    assert_eq!(foo.computed(), 8081);
+    assert_eq!(foo.tags(), &vec![1]);
+
+    let mut builder = FooPlain::builder().port(2).host("example.com".to_string());
+    builder
+        .try_set_by_name("port", "1")
+        .unwrap()
+        .try_set_by_name("verbose", "true")
+        .unwrap();
+    let foo = builder.build().unwrap();
+    assert_eq!(foo.port(), 1);
+    assert_eq!(foo.ratio(), 0.5);
+    assert_eq!(foo.verbose(), Some(true));
+}
+
+#[test]
+fn errors() {
+    let err = FooPlainBuilder::from_str_map(&str_map(&[("port", "1"), ("factor", "0.1")])).unwrap_err();
+    assert!(matches!(err, FieldXError::UnknownField(ref name) if name == "factor"));
+
+    // Fields without builder methods cannot be set by name either.
+    let err = FooPlainBuilder::from_str_map(&str_map(&[("computed", "1")])).unwrap_err();
+    assert!(matches!(err, FieldXError::UnknownField(ref name) if name == "computed"));
+
+    // Neither can be the fields that opted out.
+    let err = FooPlainBuilder::from_str_map(&str_map(&[("tags", "1")])).unwrap_err();
+    assert!(matches!(err, FieldXError::UnknownField(ref name) if name == "tags"));
+    let foo = FooPlain::builder()
+        .port(1)
+        .host("localhost".to_string())
+        .tags(vec![2, 3])
+        .build()
+        .unwrap();
+    assert_eq!(foo.tags(), &vec![2, 3]);
+
+    let err = FooPlainBuilder::from_str_map(&str_map(&[("port", "not a port")])).unwrap_err();
+    assert!(matches!(err, FieldXError::FieldParse { ref field, .. } if field == "port"));
+    assert_eq!(err.to_string(), "Cannot parse value for field 'port': invalid digit found in string");
+
+    let err = FooPlainBuilder::from_str_map(&str_map(&[("port", "1")]))
+        .unwrap()
+        .build()
+        .unwrap_err();
+    assert!(matches!(err, FieldXError::UninitializedField(ref name) if name == "host"));
+}
+
+#[cfg(feature = "sync")]
+#[test]
+fn nsync() {
+    let foo = FooSyncBuilder::from_str_map(&str_map(&[("port", "443"), ("host", "example.org")]))
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(foo.port(), 443);
+    assert_eq!(foo.host(), "example.org");
+
+    let mut builder = FooSync::builder().port(80).host("example.org".to_string());
+    builder.try_set_by_name("host", "localhost").unwrap();
+    let foo = builder.build().unwrap();
+    assert_eq!(foo.port(), 80);
+    assert_eq!(foo.host(), "localhost");
+}
@@ -0,0 +1,203 @@
+#![cfg(feature = "sync")]
+use fieldx::fxstruct;
+use std::time::Duration;
+
+#[fxstruct(sync, get(copy), builder, transaction)]
+#[derive(Debug)]
+struct Server {
+    #[fieldx(lock(group = "net"), get(clone), default("localhost".to_string()))]
+    host: String,
+
+    #[fieldx(lock, default(1))]
+    generation: u32,
+
+    #[fieldx(lock(group = "net"), set, reader, writer, default(80))]
+    port: u16,
+
+    #[fieldx(lock(group = "net"), optional, get(clone), set, clearer, predicate)]
+    proxy: String,
+
+    #[fieldx(lock(group = "limits"), get_mut, default(10))]
+    max_conn: usize,
+
+    #[fieldx(get(off), default(true))]
+    plain: bool,
+}
+
+#[test]
+fn group_accessors() {
+    let server = Server::new();
+
+    assert_eq!(server.host(), "localhost");
+    assert_eq!(server.port(), 80);
+    assert_eq!(server.generation(), 1);
+    assert_eq!(server.max_conn(), 10);
+    assert!(server.plain);
+
+    assert_eq!(server.set_port(8080), 80);
+    assert_eq!(server.port(), 8080);
+    *server.write_port() += 1;
+    assert_eq!(*server.read_port(), 8081);
+
+    assert!(!server.has_proxy());
+    assert_eq!(server.set_proxy("proxy:3128".to_string()), None);
+    assert!(server.has_proxy());
+    assert_eq!(server.proxy(), Some("proxy:3128".to_string()));
+    assert_eq!(server.clear_proxy(), Some("proxy:3128".to_string()));
+    assert!(!server.has_proxy());
+
+    *server.max_conn_mut() = 20;
+    assert_eq!(server.max_conn(), 20);
+}
+
+#[test]
+fn group_guards() {
+    let server = Server::new();
+
+    {
+        let mut net = server.write_net();
+        net.host = "example.com".to_string();
+        net.port = 443;
+    }
+    {
+        let net = server.read_net();
+        assert_eq!(net.host, "example.com");
+        assert_eq!(net.port, 443);
+        assert_eq!(net.proxy, None);
+        // Readers of the group share the lock.
+        assert_eq!(*server.read_port(), 443);
+    }
+
+    // All members are protected by the same lock.
+    {
+        let _net = server.write_net();
+        assert!(server.try_read_port().is_none());
+        assert!(server.try_write_port_for(Duration::from_millis(10)).is_none());
+    }
+    assert_eq!(server.try_read_port().map(|p| *p), Some(443));
+
+    // Other groups are independent.
+    let _limits = server.write_limits();
+    assert_eq!(server.port(), 443);
+}
+
+#[test]
+fn group_builder() {
+    let server = Server::builder().host("builder.local".to_string()).max_conn(5).build().unwrap();
+
+    assert_eq!(server.host(), "builder.local");
+    assert_eq!(server.port(), 80);
+    assert_eq!(server.max_conn(), 5);
+    assert!(format!("{server:?}").contains("builder.local"));
+}
+
+#[test]
+fn group_transaction() {
+    let server = Server::new();
+
+    server.transaction(|tx| {
+        tx.set_host("tx.local".to_string());
+        *tx.port_mut() = 8443;
+        tx.set_proxy("tx-proxy".to_string());
+        *tx.generation_mut() += 1;
+    });
+
+    assert_eq!(server.host(), "tx.local");
+    assert_eq!(server.port(), 8443);
+    assert_eq!(server.proxy(), Some("tx-proxy".to_string()));
+    assert_eq!(server.generation(), 2);
+}
+
+#[fxstruct(sync)]
+struct Cache<K: Clone + Default + Send + Sync + 'static, V: Default + Send + Sync + 'static> {
+    #[fieldx(lock(group = "data"), get(clone))]
+    last_key: K,
+
+    #[fieldx(lock(group = "data"), get)]
+    value: V,
+
+    #[fieldx(lock(group = "stats"), get(copy))]
+    hits: u64,
+}
+
+#[test]
+fn group_generics() {
+    let cache = Cache::<String, Vec<u8>>::new();
+
+    {
+        let mut data = cache.write_data();
+        data.last_key = "key".to_string();
+        data.value.push(42);
+    }
+    cache.write_stats().hits += 1;
+
+    assert_eq!(cache.last_key(), "key");
+    assert_eq!(*cache.value(), vec![42]);
+    assert_eq!(cache.hits(), 1);
+}
+
+#[cfg(feature = "async")]
+mod asyncish {
+    use fieldx::fxstruct;
+
+    #[fxstruct(r#async, get(copy))]
+    struct Session {
+        #[fieldx(lock(group = "auth"), get(clone), set)]
+        user:  String,
+        #[fieldx(lock(group = "auth"), set)]
+        token: u64,
+    }
+
+    #[tokio::test]
+    async fn async_group() {
+        let session = Session::new();
+
+        session.set_user("alice".to_string()).await;
+        session.set_token(17).await;
+
+        {
+            let auth = session.read_auth().await;
+            assert_eq!(auth.user, "alice");
+            assert_eq!(auth.token, 17);
+        }
+
+        session.write_auth().await.token += 1;
+        assert_eq!(session.token().await, 18);
+        assert_eq!(session.user().await, "alice");
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_group {
+    use fieldx::fxstruct;
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    #[fxstruct(sync, serde(default))]
+    #[derive(Clone, Debug)]
+    struct Endpoint {
+        #[fieldx(lock(group = "addr"), get(deref), default("127.0.0.1".to_string()))]
+        host:    String,
+        #[fieldx(lock(group = "addr"), get(copy), serde(off), default(8000))]
+        port:    u16,
+        #[fieldx(lock(group = "addr"), get(copy))]
+        tls:     bool,
+        #[fieldx(lock, get(copy), default(3))]
+        retries: u8,
+    }
+
+    #[test]
+    fn serde_group() {
+        let endpoint = Endpoint::new();
+        endpoint.write_addr().tls = true;
+
+        let json = serde_json::to_string(&endpoint).unwrap();
+        assert_eq!(json, r#"{"host":"127.0.0.1","tls":true,"retries":3}"#);
+
+        let endpoint = serde_json::from_str::<Endpoint>(r#"{"host":"example.com","retries":5}"#).unwrap();
+        assert_eq!(&*endpoint.host(), "example.com");
+        assert_eq!(endpoint.port(), 8000);
+        assert!(!endpoint.tls());
+        assert_eq!(endpoint.retries(), 5);
+    }
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "sync")]
+use fieldx::fxstruct;
+use std::cell::Cell;
+
+#[fxstruct(sync, get, set)]
+struct FooSync {
+    #[fieldx(lock(mutex), get(copy), default(1))]
+    counter: u32,
+
+    #[fieldx(lock(mutex), get_mut, set(off), default(vec![1, 2]))]
+    items: Vec<u32>,
+
+    #[fieldx(lock(mutex), optional, clearer, predicate, get(clone))]
+    label: String,
+
+    // Send, but not Sync
+    #[fieldx(lock(mutex), get(off), set(off), default(Cell::new(0)))]
+    cell: Cell<u32>,
+
+    #[fieldx(lock, get(copy))]
+    rw: u32,
+}
+
+fn assert_sync<T: Sync>(_: &T) {}
+
+#[test]
+fn mutex_helpers() {
+    let foo = FooSync::new();
+
+    assert_sync(&foo);
+
+    assert_eq!(foo.counter(), 1);
+    *foo.lock_counter() += 10;
+    assert_eq!(foo.counter(), 11);
+    assert_eq!(foo.set_counter(3), 11);
+    assert_eq!(*foo.lock_counter(), 3);
+
+    foo.items_mut().push(3);
+    assert_eq!(*foo.items(), vec![1, 2, 3]);
+    assert_eq!(foo.lock_items().len(), 3);
+
+    assert!(!foo.has_label());
+    assert_eq!(foo.set_label("lbl".to_string()), None);
+    assert_eq!(foo.label(), Some("lbl".to_string()));
+    assert_eq!(foo.lock_label().as_deref(), Some("lbl"));
+    assert_eq!(foo.clear_label(), Some("lbl".to_string()));
+    assert!(!foo.has_label());
+
+    foo.lock_cell().set(42);
+    assert_eq!(foo.lock_cell().get(), 42);
+
+    assert_eq!(foo.set_rw(7), 0);
+    assert_eq!(foo.rw(), 7);
+}
+
+#[fxstruct(sync, lock(mutex), get(copy))]
+struct FooStructLevel {
+    a: u32,
+    #[fieldx(lock(mutex(off)))]
+    b: u32,
+    #[fieldx(lock(off))]
+    c: u32,
+}
+
+#[test]
+fn struct_level_mutex() {
+    let foo = FooStructLevel::new();
+    *foo.lock_a() = 1;
+    *foo.b.write() = 2;
+    assert_eq!(foo.a(), 1);
+    assert_eq!(foo.b(), 2);
+    assert_eq!(foo.c(), 0);
+}
+
+#[cfg(feature = "async")]
+mod asyncish {
+    use fieldx::fxstruct;
+
+    #[fxstruct(r#async, get(copy), set)]
+    struct FooAsync {
+        #[fieldx(lock(mutex), default(1))]
+        counter: u32,
+
+        #[fieldx(lock(mutex), optional, get(clone))]
+        label: String,
+    }
+
+    #[tokio::test]
+    async fn async_mutex() {
+        let foo = FooAsync::new();
+
+        *foo.lock_counter().await += 1;
+        assert_eq!(foo.counter().await, 2);
+        assert_eq!(foo.set_counter(5).await, 2);
+        assert_eq!(*foo.lock_counter().await, 5);
+
+        assert_eq!(foo.set_label("lbl".to_string()).await, None);
+        assert_eq!(foo.label().await, Some("lbl".to_string()));
+        assert_eq!(foo.lock_label().await.take(), Some("lbl".to_string()));
+        assert_eq!(foo.label().await, None);
+    }
+}
@@ -0,0 +1,322 @@
+use fieldx::error::FieldXError;
+use fieldx::fxstruct;
+
+#[fxstruct(get, patch, track_changes)]
+struct Profile {
+    #[fieldx(default("anonymous".to_string()))]
+    name:     String,
+    #[fieldx(optional, clearer)]
+    email:    String,
+    #[fieldx(get(copy), inner_mut, default(18))]
+    age:      u8,
+    #[fieldx(lazy, clearer)]
+    greeting: String,
+}
+
+impl Profile {
+    fn build_greeting(&self) -> String {
+        format!("Hi, {}!", self.name)
+    }
+}
+
+#[test]
+fn plain_patch() {
+    let mut profile = Profile::new();
+    profile.apply_patch(ProfilePatch {
+        name: Some("alice".to_string()),
+        email: Some(Some("alice@example.com".to_string())),
+        ..Default::default()
+    });
+
+    assert_eq!(profile.name(), "alice");
+    assert_eq!(profile.email().as_deref(), Some("alice@example.com"));
+    assert_eq!(profile.age(), 18);
+    assert_eq!(profile.greeting(), "Hi, alice!");
+    assert_eq!(profile.dirty_fields(), vec!["name", "email"]);
+
+    profile.mark_clean();
+    profile.apply_patch(ProfilePatch {
+        email: Some(None),
+        age: Some(21),
+        greeting: Some(None),
+        ..Default::default()
+    });
+
+    assert!(profile.email().is_none());
+    assert_eq!(profile.age(), 21);
+    assert_eq!(profile.dirty_fields(), vec!["email", "age", "greeting"]);
+
+    profile.apply_patch(ProfilePatch {
+        greeting: Some(Some("Hello".to_string())),
+        ..Default::default()
+    });
+    assert_eq!(profile.greeting(), "Hello");
+
+    // Patching and helpers are interchangeable.
+    assert_eq!(profile.clear_greeting().as_deref(), Some("Hello"));
+    assert_eq!(profile.clear_email(), None);
+}
+
+#[fxstruct(get(clone), patch("update"))]
+struct Labels<T: Clone + Default> {
+    #[fieldx(inner_mut)]
+    primary: T,
+    #[fieldx(inner_mut, optional)]
+    backup:  T,
+}
+
+#[test]
+fn inner_mut_patch() {
+    // All fields have inner mutability, therefore the struct doesn't need to be mutable.
+    let labels = Labels::<u32>::new();
+    labels.update(LabelsPatch {
+        primary: Some(1),
+        backup: Some(2),
+        ..Default::default()
+    });
+
+    assert_eq!(labels.primary(), 1);
+    assert_eq!(labels.backup(), Some(2));
+}
+
+#[fxstruct(get(copy), patch, track_changes, builder(check(self.min <= self.max, "min must not exceed max")))]
+struct Bounds {
+    min:   i32,
+    max:   i32,
+    #[fieldx(optional, clearer, get(clone))]
+    label: String,
+}
+
+#[test]
+fn checked_patch() {
+    let mut bounds = Bounds::builder().min(1).max(5).label("range".to_string()).build().unwrap();
+
+    let err = bounds
+        .apply_patch(BoundsPatch {
+            max: Some(0),
+            label: Some(None),
+            ..Default::default()
+        })
+        .unwrap_err();
+    assert!(matches!(err, FieldXError::Validation(ref failed) if failed == &["min must not exceed max"]));
+    assert_eq!((bounds.min(), bounds.max()), (1, 5));
+    assert_eq!(bounds.label().as_deref(), Some("range"));
+    assert!(bounds.dirty_fields().is_empty(), "a rejected patch changes nothing");
+
+    // The checks see the merged state: both bounds move at once.
+    bounds
+        .apply_patch(BoundsPatch {
+            min: Some(10),
+            max: Some(20),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!((bounds.min(), bounds.max()), (10, 20));
+    assert_eq!(bounds.dirty_fields(), vec!["min", "max"]);
+}
+
+#[cfg(feature = "sync")]
+mod sync {
+    use fieldx::error::FieldXError;
+    use fieldx::fxstruct;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[fxstruct(sync, get(copy), patch, track_changes)]
+    struct Limits {
+        #[fieldx(lock, default(10))]
+        soft:  u32,
+        #[fieldx(lock, default(20))]
+        hard:  u32,
+        #[fieldx(lock, optional, clearer)]
+        burst: u32,
+    }
+
+    #[test]
+    fn sync_patch() {
+        let limits = Arc::new(Limits::new());
+
+        let handle = {
+            let limits = limits.clone();
+            thread::spawn(move || {
+                limits.apply_patch(LimitsPatch {
+                    hard: Some(30),
+                    burst: Some(Some(5)),
+                    ..Default::default()
+                })
+            })
+        };
+        handle.join().unwrap();
+
+        assert_eq!((limits.soft(), limits.hard(), limits.burst()), (10, 30, Some(5)));
+        assert_eq!(limits.dirty_fields(), vec!["hard", "burst"]);
+
+        limits.apply_patch(LimitsPatch {
+            burst: Some(None),
+            ..Default::default()
+        });
+        assert_eq!(limits.burst(), None);
+    }
+
+    #[fxstruct(sync, get(copy), patch, track_changes, builder(check(within_hard, "soft limit exceeds the hard one")))]
+    struct Quota {
+        #[fieldx(lock, default(10))]
+        soft:  u32,
+        #[fieldx(lock, default(20))]
+        hard:  u32,
+        #[fieldx(lock, optional, clearer)]
+        burst: u32,
+    }
+
+    impl Quota {
+        // Reads the fields through their locks while the patch holds them.
+        fn within_hard(&self) -> bool {
+            self.soft() <= self.hard()
+        }
+    }
+
+    #[test]
+    fn sync_checked_patch() {
+        let quota = Arc::new(Quota::builder().burst(3).build().unwrap());
+
+        let handle = {
+            let quota = quota.clone();
+            thread::spawn(move || {
+                quota.apply_patch(QuotaPatch {
+                    hard: Some(5),
+                    burst: Some(None),
+                    ..Default::default()
+                })
+            })
+        };
+        let err = handle.join().unwrap().unwrap_err();
+        assert!(matches!(err, FieldXError::Validation(ref failed) if failed == &["soft limit exceeds the hard one"]));
+        assert_eq!((quota.soft(), quota.hard(), quota.burst()), (10, 20, Some(3)));
+        assert!(quota.dirty_fields().is_empty());
+
+        quota
+            .apply_patch(QuotaPatch {
+                soft: Some(4),
+                hard: Some(5),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!((quota.soft(), quota.hard()), (4, 5));
+        assert_eq!(quota.dirty_fields(), vec!["soft", "hard"]);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use fieldx::fxstruct;
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    #[fxstruct(get, patch, serde(default))]
+    struct Settings {
+        #[fieldx(default("light".to_string()))]
+        theme:   String,
+        #[fieldx(get(copy), serde(rename("fontSize")), default(12))]
+        size:    u8,
+        #[fieldx(optional, clearer)]
+        proxy:   String,
+        #[fieldx(serde(off))]
+        session: u64,
+    }
+
+    #[test]
+    fn deserialized_patch() {
+        let mut settings = Settings::new();
+
+        let patch: SettingsPatch = serde_json::from_str(r#"{"fontSize":14,"proxy":"localhost:3128"}"#).unwrap();
+        settings.apply_patch(patch);
+        assert_eq!(settings.theme(), "light");
+        assert_eq!(settings.size(), 14);
+        assert_eq!(settings.proxy().as_deref(), Some("localhost:3128"));
+
+        // An explicit `null` clears the field; a missing key leaves it as is.
+        let patch: SettingsPatch = serde_json::from_str(r#"{"theme":"dark","proxy":null}"#).unwrap();
+        settings.apply_patch(patch);
+        assert_eq!(settings.theme(), "dark");
+        assert_eq!(settings.size(), 14);
+        assert!(settings.proxy().is_none());
+        assert_eq!(*settings.session(), 0);
+        assert_eq!(settings.clear_proxy(), None);
+    }
+}
+
+#[cfg(feature = "async")]
+mod asyncish {
+    use fieldx::fxstruct;
+
+    #[fxstruct(r#async, get(copy), patch)]
+    struct Quota {
+        #[fieldx(lock, default(1))]
+        used:  u32,
+        #[fieldx(lock, optional, clearer)]
+        limit: u32,
+    }
+
+    #[tokio::test]
+    async fn async_patch() {
+        let quota = Quota::new();
+        quota
+            .apply_patch(QuotaPatch {
+                used:  Some(3),
+                limit: Some(Some(10)),
+            })
+            .await;
+        assert_eq!((quota.used().await, quota.limit().await), (3, Some(10)));
+
+        quota
+            .apply_patch(QuotaPatch {
+                limit: Some(None),
+                ..Default::default()
+            })
+            .await;
+        assert_eq!(quota.limit().await, None);
+    }
+}
+
+#[cfg(feature = "async-lock")]
+mod async_lock {
+    use fieldx::error::FieldXError;
+    use fieldx::fxstruct;
+
+    #[fxstruct(r#async, get(copy), patch, builder(check(within_limit, "usage exceeds the limit")))]
+    struct Quota {
+        #[fieldx(lock, default(1))]
+        used:  u32,
+        #[fieldx(lock, default(10))]
+        limit: u32,
+    }
+
+    impl Quota {
+        fn within_limit(&self) -> bool {
+            matches!((self.used.try_read(), self.limit.try_read()), (Some(used), Some(limit)) if *used <= *limit)
+        }
+    }
+
+    #[tokio::test]
+    async fn async_checked_patch() {
+        let quota = Quota::builder().build().unwrap();
+        let err = quota
+            .apply_patch(QuotaPatch {
+                used: Some(11),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, FieldXError::Validation(ref failed) if failed == &["usage exceeds the limit"]));
+        assert_eq!(quota.used().await, 1);
+
+        quota
+            .apply_patch(QuotaPatch {
+                used:  Some(11),
+                limit: Some(20),
+            })
+            .await
+            .unwrap();
+        assert_eq!((quota.used().await, quota.limit().await), (11, 20));
+    }
+}
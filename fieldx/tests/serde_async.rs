@@ -0,0 +1,105 @@
+#![cfg(all(feature = "serde", feature = "async"))]
+use fieldx::fxstruct;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+
+#[fxstruct(r#async, get(copy), serde(default))]
+#[derive(Clone)]
+struct Session {
+    #[fieldx(lock, set, get(clone), default("guest".to_string()))]
+    user: String,
+
+    #[fieldx(lock, optional, set)]
+    token: u64,
+
+    #[fieldx(lazy, lock, get(clone))]
+    greeting: String,
+
+    #[fieldx(lazy, lock)]
+    hits: u32,
+
+    #[fieldx(lock(group = "window"), set, default(640))]
+    width: u32,
+
+    #[fieldx(lock(group = "window"), default(480))]
+    height: u32,
+}
+
+impl Session {
+    async fn build_greeting(&self) -> String {
+        format!("Hello, {}!", self.user().await)
+    }
+
+    async fn build_hits(&self) -> u32 {
+        1
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn async_serialize() {
+    let session = Arc::new(Session::new());
+    session.set_token(42).await;
+    session.set_width(800).await;
+
+    // The futures are Send and can be awaited on any worker thread.
+    let handle = {
+        let session = session.clone();
+        tokio::spawn(async move {
+            let mut out = vec![];
+            session
+                .serialize_async(&mut serde_json::Serializer::new(&mut out))
+                .await
+                .unwrap();
+            String::from_utf8(out).unwrap()
+        })
+    };
+
+    assert_eq!(
+        handle.await.unwrap(),
+        r#"{"user":"guest","token":42,"greeting":"Hello, guest!","hits":1,"width":800,"height":480}"#
+    );
+
+    let shadow = session.to_shadow().await;
+    assert_eq!(
+        serde_json::to_string(&shadow).unwrap(),
+        r#"{"user":"guest","token":42,"greeting":"Hello, guest!","hits":1,"width":800,"height":480}"#
+    );
+    assert_eq!(session.greeting().await, "Hello, guest!");
+
+    session.set_user("alice".to_string()).await;
+    assert_eq!(session.token().await, Some(42));
+    assert_eq!(session.hits().await, 1);
+    assert_eq!((session.width().await, session.height().await), (800, 480));
+}
+
+#[test]
+fn blocking_serialize() {
+    // `Serialize` works with a clone of the struct and blocks on its locks. Lazy fields are not initialized by it.
+    let session = Session::new();
+    assert_eq!(
+        serde_json::to_string(&session).unwrap(),
+        r#"{"user":"guest","token":null,"greeting":null,"hits":null,"width":640,"height":480}"#
+    );
+}
+
+#[fxstruct(r#async, get(clone), serde(default))]
+#[derive(Clone)]
+struct Tagged<T: Clone + Default + Send + Sync + 'static> {
+    #[fieldx(lock, set)]
+    value: T,
+}
+
+#[tokio::test]
+async fn async_serialize_generic() {
+    let tagged = Tagged::<Vec<u8>>::new();
+    tagged.set_value(vec![1, 2]).await;
+
+    let mut out = vec![];
+    tagged
+        .serialize_async(&mut serde_json::Serializer::new(&mut out))
+        .await
+        .unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), r#"{"value":[1,2]}"#);
+    assert_eq!(tagged.value().await, vec![1, 2]);
+}
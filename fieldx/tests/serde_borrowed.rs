@@ -0,0 +1,177 @@
+#![cfg(feature = "serde")]
+use fieldx::fxstruct;
+use serde::Deserialize;
+use serde::Serialize;
+use std::cell::Cell;
+
+// Not Clone.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct Payload {
+    id: u32,
+}
+
+#[fxstruct(get, serde(default))]
+struct Plain {
+    #[fieldx(default(Payload { id: 1 }))]
+    payload: Payload,
+
+    #[fieldx(optional)]
+    note: String,
+
+    #[fieldx(inner_mut, get(copy), default(2))]
+    counter: u32,
+
+    #[fieldx(lazy)]
+    derived: String,
+
+    #[fieldx(serde(off))]
+    builds: Cell<u32>,
+}
+
+impl Plain {
+    fn build_derived(&self) -> String {
+        self.builds.set(self.builds.get() + 1);
+        format!("payload#{}", self.payload().id)
+    }
+}
+
+#[test]
+fn plain_borrowed() {
+    let plain = Plain::new();
+
+    let json = serde_json::to_string(&plain).unwrap();
+    assert_eq!(
+        json,
+        r#"{"payload":{"id":1},"note":null,"counter":2,"derived":"payload#1"}"#
+    );
+    // Lazy fields are initialized once and kept.
+    serde_json::to_string(&plain).unwrap();
+    assert_eq!(plain.builds().get(), 1);
+    assert_eq!(plain.derived(), "payload#1");
+
+    let plain = serde_json::from_str::<Plain>(r#"{"payload":{"id":3},"note":"hi"}"#).unwrap();
+    assert_eq!(plain.payload(), &Payload { id: 3 });
+    assert_eq!(plain.note().as_deref(), Some("hi"));
+    assert_eq!(plain.counter(), 2);
+}
+
+#[cfg(feature = "sync")]
+mod sync {
+    use super::Payload;
+    use fieldx::fxstruct;
+    use serde::Deserialize;
+    use serde::Serialize;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[fxstruct(sync, get, serde(default))]
+    struct Shared {
+        #[fieldx(lock, default(Payload { id: 1 }))]
+        payload: Payload,
+
+        #[fieldx(lock(group = "size"), get(copy), default(640))]
+        width: u32,
+
+        #[fieldx(lock(group = "size"), get(copy), default(480))]
+        height: u32,
+
+        #[fieldx(lock, optional, set, get(clone))]
+        label: String,
+
+        #[fieldx(lazy, get(copy))]
+        area: u32,
+
+        #[fieldx(lazy, lock, get(copy))]
+        perimeter: u32,
+
+        #[fieldx(atomic, default(5))]
+        hits: u64,
+
+        #[fieldx(default("static".to_string()))]
+        kind: String,
+    }
+
+    impl Shared {
+        fn build_area(&self) -> u32 {
+            self.width() * self.height()
+        }
+
+        fn build_perimeter(&self) -> u32 {
+            2 * (self.width() + self.height())
+        }
+    }
+
+    #[test]
+    fn sync_borrowed() {
+        let shared = Shared::new();
+        shared.set_label("main".to_string());
+
+        let json = serde_json::to_string(&shared).unwrap();
+        assert_eq!(
+            json,
+            r#"{"payload":{"id":1},"width":640,"height":480,"label":"main","area":307200,"perimeter":2240,"hits":5,"kind":"static"}"#
+        );
+        assert_eq!(shared.area(), 307200);
+        assert_eq!(shared.perimeter(), 2240);
+        assert_eq!(shared.hits(), 5);
+        assert_eq!(*shared.kind(), "static");
+
+        let shared = serde_json::from_str::<Shared>(r#"{"payload":{"id":7},"width":10,"height":20}"#).unwrap();
+        assert_eq!(shared.payload().id, 7);
+        assert_eq!(shared.area(), 200);
+        assert_eq!(shared.label(), None);
+    }
+
+    #[test]
+    fn sync_borrowed_threads() {
+        let shared = Arc::new(Shared::new());
+
+        let handles = (0..4)
+            .map(|i| {
+                let shared = shared.clone();
+                thread::spawn(move || {
+                    for j in 0..50 {
+                        if j % 2 == 0 {
+                            shared.set_label(format!("{i}-{j}"));
+                        }
+                        else {
+                            serde_json::to_string(&*shared).unwrap();
+                        }
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert!(shared.label().is_some());
+    }
+
+    #[fxstruct(sync(single_lock), get(copy), serde(default))]
+    struct Single<T: Copy + Default + Send + Sync + 'static> {
+        #[fieldx(default(3))]
+        base: u32,
+        #[fieldx(lazy)]
+        doubled: u32,
+        value: T,
+    }
+
+    impl<T: Copy + Default + Send + Sync + 'static> Single<T> {
+        fn build_doubled(&self) -> u32 {
+            self.base() * 2
+        }
+    }
+
+    #[test]
+    fn single_lock_borrowed() {
+        let single = Single::<i8>::new();
+        assert_eq!(
+            serde_json::to_string(&single).unwrap(),
+            r#"{"base":3,"doubled":6,"value":0}"#
+        );
+        assert_eq!(single.doubled(), 6);
+        assert_eq!(single.value(), 0);
+    }
+}
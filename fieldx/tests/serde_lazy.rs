@@ -0,0 +1,144 @@
+#![cfg(feature = "serde")]
+use fieldx::fxstruct;
+use serde::Deserialize;
+use serde::Serialize;
+use std::cell::Cell;
+
+#[fxstruct(get, serde(lazy(skip_unset)))]
+struct Report {
+    title: String,
+
+    #[fieldx(lazy)]
+    body: String,
+
+    #[fieldx(lazy, serde(lazy(force)))]
+    digest: u32,
+
+    #[fieldx(serde(off))]
+    builds: Cell<u32>,
+}
+
+impl Report {
+    fn build_body(&self) -> String {
+        self.builds.set(self.builds.get() + 1);
+        format!("body of {}", self.title)
+    }
+
+    fn build_digest(&self) -> u32 {
+        self.title.len() as u32
+    }
+}
+
+#[test]
+fn plain_skip_unset() {
+    let report = serde_json::from_str::<Report>(r#"{"title":"draft"}"#).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&report).unwrap(),
+        r#"{"title":"draft","digest":5}"#
+    );
+    assert_eq!(report.builds().get(), 0);
+
+    assert_eq!(report.body(), "body of draft");
+    assert_eq!(
+        serde_json::to_string(&report).unwrap(),
+        r#"{"title":"draft","body":"body of draft","digest":5}"#
+    );
+
+    // A serialized value is taken as is, an absent one leaves the field uninitialized.
+    let report = serde_json::from_str::<Report>(r#"{"title":"final","body":"ready"}"#).unwrap();
+    assert_eq!(report.body(), "ready");
+    assert_eq!(*report.digest(), 5);
+    assert_eq!(report.builds().get(), 0);
+}
+
+#[cfg(feature = "sync")]
+mod sync {
+    use fieldx::fxstruct;
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    #[fxstruct(sync, get(copy), serde(default))]
+    struct Stats {
+        #[fieldx(default(3))]
+        count: u32,
+
+        #[fieldx(lazy, serde(lazy(skip_unset)))]
+        squared: u32,
+
+        #[fieldx(lazy, lock, serde(lazy(skip_unset)))]
+        cubed: u32,
+
+        #[fieldx(lazy)]
+        doubled: u32,
+    }
+
+    impl Stats {
+        fn build_squared(&self) -> u32 {
+            self.count() * self.count()
+        }
+
+        fn build_cubed(&self) -> u32 {
+            self.squared() * self.count()
+        }
+
+        fn build_doubled(&self) -> u32 {
+            self.count() * 2
+        }
+    }
+
+    #[test]
+    fn sync_skip_unset() {
+        let stats = Stats::new();
+        assert_eq!(serde_json::to_string(&stats).unwrap(), r#"{"count":3,"doubled":6}"#);
+
+        assert_eq!(stats.cubed(), 27);
+        assert_eq!(
+            serde_json::to_string(&stats).unwrap(),
+            r#"{"count":3,"squared":9,"cubed":27,"doubled":6}"#
+        );
+
+        let stats = serde_json::from_str::<Stats>(r#"{"count":2,"cubed":100}"#).unwrap();
+        assert_eq!(stats.cubed(), 100);
+        assert_eq!(stats.squared(), 4);
+        assert_eq!(stats.doubled(), 4);
+    }
+}
+
+#[cfg(feature = "async")]
+mod asyncish {
+    use fieldx::fxstruct;
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    #[fxstruct(r#async, get(copy), serde(default, lazy(skip_unset)))]
+    #[derive(Clone)]
+    struct Stats {
+        #[fieldx(default(3))]
+        count: u32,
+
+        #[fieldx(lazy, lock)]
+        squared: u32,
+    }
+
+    impl Stats {
+        async fn build_squared(&self) -> u32 {
+            self.count() * self.count()
+        }
+    }
+
+    #[tokio::test]
+    async fn async_skip_unset() {
+        let stats = Stats::new();
+        assert_eq!(
+            serde_json::to_string(&stats.to_shadow().await).unwrap(),
+            r#"{"count":3}"#
+        );
+
+        assert_eq!(stats.squared().await, 9);
+        assert_eq!(
+            serde_json::to_string(&stats.to_shadow().await).unwrap(),
+            r#"{"count":3,"squared":9}"#
+        );
+    }
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "serde")]
+use fieldx::fxstruct;
+use serde::Deserialize;
+use serde::Serialize;
+use std::rc::Rc;
+
+#[fxstruct(rc, get, serde(default))]
+struct Node {
+    #[fieldx(default("root".to_string()))]
+    name: String,
+
+    #[fieldx(lazy)]
+    path: String,
+}
+
+impl Node {
+    fn build_path(&self) -> String {
+        // The lazy builder can only reach the container if `myself` is wired.
+        let me = self.myself().unwrap();
+        format!("/{}", me.name())
+    }
+}
+
+#[derive(Deserialize)]
+struct Tree {
+    #[serde(deserialize_with = "Node::deserialize_rc")]
+    root: Rc<Node>,
+}
+
+#[test]
+fn plain_rc() {
+    let node = Node::deserialize_rc(&mut serde_json::Deserializer::from_str(r#"{"name":"leaf"}"#)).unwrap();
+
+    assert!(node.myself().is_some());
+    assert_eq!(node.path(), "/leaf");
+    assert_eq!(
+        serde_json::to_string(&*node).unwrap(),
+        r#"{"name":"leaf","path":"/leaf"}"#
+    );
+
+    let tree = serde_json::from_str::<Tree>(r#"{"root":{"path":"/custom"}}"#).unwrap();
+    assert_eq!(tree.root.name(), "root");
+    assert_eq!(tree.root.path(), "/custom");
+    assert!(Rc::ptr_eq(&tree.root.myself().unwrap(), &tree.root));
+}
+
+#[cfg(feature = "sync")]
+mod sync {
+    use fieldx::fxstruct;
+    use serde::Deserialize;
+    use serde::Serialize;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[fxstruct(sync, rc, get(copy), serde(default))]
+    struct Counter {
+        #[fieldx(lock, set, default(0))]
+        value: u64,
+
+        #[fieldx(lazy, get(clone))]
+        label: String,
+    }
+
+    impl Counter {
+        fn build_label(&self) -> String {
+            format!("counter#{}", self.myself().unwrap().value())
+        }
+    }
+
+    #[test]
+    fn sync_rc() {
+        let counter = Counter::deserialize_rc(&mut serde_json::Deserializer::from_str(r#"{"value":3}"#)).unwrap();
+
+        assert!(Arc::ptr_eq(&counter.myself().unwrap(), &counter));
+
+        let handle = {
+            let counter = counter.clone();
+            thread::spawn(move || counter.set_value(4))
+        };
+        assert_eq!(handle.join().unwrap(), 3);
+
+        assert_eq!(
+            serde_json::to_string(&*counter).unwrap(),
+            r#"{"value":4,"label":"counter#4"}"#
+        );
+        assert_eq!(counter.label(), "counter#4");
+    }
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "schemars")]
+use fieldx::fxstruct;
+use schemars::schema_for;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+
+/// Service configuration.
+#[fxstruct(get, serde(default, schema))]
+struct Config {
+    /// Host to bind to.
+    #[fieldx(default("localhost".to_string()))]
+    host:    String,
+    /// Port to listen on.
+    #[fieldx(get(copy), serde(rename("listenPort")), default(8080))]
+    port:    u16,
+    #[fieldx(optional)]
+    proxy:   String,
+    #[fieldx(serde(off), get(copy))]
+    session: u64,
+}
+
+#[test]
+fn schema_of_shadow() {
+    let schema = schema_for!(Config).to_value();
+
+    assert_eq!(schema["title"], "Config");
+    assert_eq!(schema["description"], "Service configuration.");
+    assert_eq!(
+        schema["properties"]["host"],
+        json!({"description": "Host to bind to.", "type": "string", "default": "localhost"})
+    );
+    assert_eq!(schema["properties"]["listenPort"]["description"], "Port to listen on.");
+    assert_eq!(schema["properties"]["listenPort"]["default"], 8080);
+    assert_eq!(schema["properties"]["proxy"]["type"], json!(["string", "null"]));
+    assert!(schema["properties"].get("session").is_none());
+
+    let config = Config::new();
+    assert_eq!((config.host().as_str(), config.port(), config.session()), ("localhost", 8080, 0));
+    assert!(config.proxy().is_none());
+}
+
+#[fxstruct(get(copy), serde(version = 2, default, rename("settings"), schema))]
+struct Settings {
+    #[fieldx(default(3))]
+    retries: u8,
+}
+
+#[test]
+fn versioned_schema() {
+    let schema = schema_for!(Settings).to_value();
+
+    assert_eq!(schema["required"], json!(["version", "data"]));
+    assert_eq!(schema["properties"]["data"]["$ref"], "#/$defs/settings");
+    assert_eq!(schema["$defs"]["settings"]["properties"]["retries"]["default"], 3);
+    assert_eq!(Settings::new().retries(), 3);
+}
+
+#[derive(Default, Clone, Serialize, Deserialize)]
+struct Opaque(u64);
+
+// Field types don't implement `JsonSchema`, hence the struct doesn't opt in.
+#[fxstruct(get, serde(default))]
+struct Session {
+    token: Opaque,
+}
+
+#[test]
+fn no_schema() {
+    let session: Session = serde_json::from_str(r#"{"token":42}"#).unwrap();
+    assert_eq!(session.token().0, 42);
+}
+
+#[cfg(feature = "sync")]
+mod sync {
+    use fieldx::fxstruct;
+    use schemars::schema_for;
+    use serde::Deserialize;
+    use serde::Serialize;
+    use serde_json::json;
+
+    #[fxstruct(sync, get(clone), serde(default, rename("inventory"), schema))]
+    struct Inventory<T: Clone + Default> {
+        /// Stored items.
+        #[fieldx(lock)]
+        items: Vec<T>,
+        #[fieldx(lazy, lock, get(copy))]
+        count: usize,
+    }
+
+    impl<T: Clone + Default> Inventory<T> {
+        fn build_count(&self) -> usize {
+            self.items().len()
+        }
+    }
+
+    #[test]
+    fn generic_schema() {
+        let schema = schema_for!(Inventory<String>).to_value();
+
+        assert_eq!(schema["title"], "inventory");
+        assert_eq!(
+            schema["properties"]["items"],
+            json!({"description": "Stored items.", "type": "array", "items": {"type": "string"}})
+        );
+        assert_eq!(schema["properties"]["count"]["type"], json!(["integer", "null"]));
+        assert_eq!(Inventory::<String>::new().count(), 0);
+    }
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "serde")]
+use fieldx::fxstruct;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[fxstruct(get, serde(skip_none))]
+struct Profile {
+    name:     String,
+    #[fieldx(optional, set)]
+    nickname: String,
+    #[fieldx(clearer, set)]
+    avatar:   String,
+    #[fieldx(predicate, get(copy), set)]
+    age:      u8,
+    #[fieldx(optional, set, serde(skip_none(off)))]
+    email:    String,
+}
+
+#[test]
+fn skip_unset_optionals() {
+    let mut profile = Profile::new();
+    assert_eq!(serde_json::to_string(&profile).unwrap(), r#"{"name":"","email":null}"#);
+
+    profile.set_nickname("bob".to_string());
+    profile.set_age(42);
+    assert_eq!(
+        serde_json::to_string(&profile).unwrap(),
+        r#"{"name":"","nickname":"bob","age":42,"email":null}"#
+    );
+}
+
+#[test]
+fn missing_optionals() {
+    let profile: Profile = serde_json::from_str(r#"{"name":"alice","avatar":"a.png"}"#).unwrap();
+    assert_eq!(profile.name(), "alice");
+    assert!(profile.nickname().is_none());
+    assert_eq!(profile.avatar().as_deref(), Some("a.png"));
+    assert!(!profile.has_age());
+    assert!(profile.age().is_none());
+    assert!(profile.email().is_none());
+}
+
+#[fxstruct(get, serde(default))]
+struct Entry {
+    key:   String,
+    #[fieldx(optional, serde(skip_none))]
+    value: String,
+    #[fieldx(optional)]
+    note:  String,
+}
+
+#[test]
+fn field_level_skip_none() {
+    let entry: Entry = serde_json::from_str(r#"{"key":"k"}"#).unwrap();
+    assert_eq!(entry.key(), "k");
+    assert!(entry.note().is_none());
+    assert_eq!(serde_json::to_string(&entry).unwrap(), r#"{"key":"k","note":null}"#);
+
+    let entry: Entry = serde_json::from_str(r#"{"key":"k","value":"v"}"#).unwrap();
+    assert_eq!(entry.value().as_deref(), Some("v"));
+    assert_eq!(serde_json::to_string(&entry).unwrap(), r#"{"key":"k","value":"v","note":null}"#);
+}
+
+#[cfg(feature = "sync")]
+mod sync {
+    use fieldx::fxstruct;
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    #[fxstruct(sync, get(clone), serde(skip_none))]
+    struct Session {
+        #[fieldx(lock, default("guest".to_string()))]
+        user:  String,
+        #[fieldx(lock, clearer, set)]
+        token: String,
+        #[fieldx(lazy, lock)]
+        label: String,
+    }
+
+    impl Session {
+        fn build_label(&self) -> String {
+            format!("session of {}", self.user())
+        }
+    }
+
+    #[test]
+    fn sync_skip_none() {
+        let session = Session::new();
+        // Lazy fields are not affected.
+        assert_eq!(
+            serde_json::to_string(&session).unwrap(),
+            r#"{"user":"guest","label":"session of guest"}"#
+        );
+
+        session.set_token("secret".to_string());
+        let session: Session = serde_json::from_str(&serde_json::to_string(&session).unwrap()).unwrap();
+        assert_eq!(session.token().as_deref(), Some("secret"));
+
+        session.clear_token();
+        let session: Session = serde_json::from_str(&serde_json::to_string(&session).unwrap()).unwrap();
+        assert!(session.token().is_none());
+        assert_eq!(session.label(), "session of guest");
+    }
+}
@@ -0,0 +1,167 @@
+#![cfg(all(feature = "serde", feature = "sync"))]
+use fieldx::fxstruct;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+use std::thread;
+
+#[fxstruct(sync, get(copy), transaction, serde(default, snapshot))]
+struct Ledger {
+    #[fieldx(lock, default(100))]
+    checking: i64,
+    #[fieldx(lock(group = "savings"), default(0))]
+    savings:  i64,
+    #[fieldx(lock(group = "savings"), default(0))]
+    moves:    u32,
+    #[fieldx(lock(mutex), default(0))]
+    reserved: i64,
+    #[fieldx(lock, optional, get(clone), set)]
+    note:     String,
+    #[fieldx(lazy, lock)]
+    total:    i64,
+}
+
+impl Ledger {
+    fn build_total(&self) -> i64 {
+        self.checking() + self.savings() + *self.lock_reserved()
+    }
+
+    fn move_funds(&self, amount: i64) {
+        self.transaction(|tx| {
+            *tx.checking_mut() -= amount;
+            *tx.savings_mut() += amount;
+            *tx.moves_mut() += 1;
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct LedgerOutput {
+    checking: i64,
+    savings:  i64,
+    moves:    u32,
+    reserved: i64,
+    note:     Option<String>,
+    total:    i64,
+}
+
+#[test]
+fn snapshot_output() {
+    let ledger = Ledger::new();
+    ledger.set_note("opening".to_string());
+    ledger.move_funds(30);
+
+    let output: LedgerOutput = serde_json::from_str(&serde_json::to_string(&ledger).unwrap()).unwrap();
+    assert_eq!((output.checking, output.savings, output.moves), (70, 30, 1));
+    assert_eq!(output.reserved, 0);
+    assert_eq!(output.note.as_deref(), Some("opening"));
+    assert_eq!(output.total, 100);
+
+    // The locks are released once serialization is over.
+    ledger.move_funds(10);
+    assert_eq!((ledger.checking(), ledger.savings()), (60, 40));
+    assert_eq!((ledger.reserved(), ledger.total()), (0, 100));
+    assert_eq!(ledger.note().as_deref(), Some("opening"));
+}
+
+#[test]
+fn snapshot_concurrent_writer() {
+    let ledger = Arc::new(Ledger::new());
+
+    let writer = {
+        let ledger = ledger.clone();
+        thread::spawn(move || {
+            for i in 0..500 {
+                ledger.move_funds(if i % 2 == 0 { 7 } else { -3 });
+            }
+        })
+    };
+
+    for _ in 0..200 {
+        let output: LedgerOutput = serde_json::from_str(&serde_json::to_string(&*ledger).unwrap()).unwrap();
+        assert_eq!(output.checking + output.savings, 100);
+    }
+
+    writer.join().unwrap();
+    assert_eq!(ledger.moves(), 500);
+}
+
+#[fxstruct(sync(single_lock), get(copy), transaction, serde(default, snapshot))]
+struct Counters {
+    #[fieldx(default(1))]
+    hits:   u32,
+    #[fieldx(default(1))]
+    misses: u32,
+    #[fieldx(lazy)]
+    ratio:  u32,
+}
+
+impl Counters {
+    fn build_ratio(&self) -> u32 {
+        self.hits() / self.misses()
+    }
+}
+
+#[test]
+fn snapshot_single_lock() {
+    let counters = Counters::new();
+    counters.transaction(|tx| {
+        tx.set_hits(10);
+        tx.set_misses(5);
+    });
+
+    assert_eq!(
+        serde_json::to_string(&counters).unwrap(),
+        r#"{"hits":10,"misses":5,"ratio":2}"#
+    );
+    assert_eq!(counters.ratio(), 2);
+}
+
+#[cfg(feature = "async")]
+mod asyncish {
+    use fieldx::fxstruct;
+    use serde::Deserialize;
+    use serde::Serialize;
+    use std::sync::Arc;
+
+    #[fxstruct(r#async, get(copy), transaction, serde(default, snapshot))]
+    #[derive(Clone)]
+    struct Stock {
+        #[fieldx(lock, default(10))]
+        available: u32,
+        #[fieldx(lock, default(0))]
+        reserved:  u32,
+        #[fieldx(lock, optional, get(clone))]
+        supplier:  String,
+        #[fieldx(lazy, lock)]
+        total:     u32,
+    }
+
+    impl Stock {
+        async fn build_total(&self) -> u32 {
+            self.available().await + self.reserved().await
+        }
+    }
+
+    #[tokio::test]
+    async fn async_snapshot() {
+        let stock = Arc::new(Stock::new());
+        stock
+            .transaction(|tx| {
+                *tx.available_mut() -= 4;
+                *tx.reserved_mut() += 4;
+            })
+            .await;
+
+        // The guards are held across awaits, the future must still be `Send`.
+        let shadow = tokio::spawn({
+            let stock = stock.clone();
+            async move { serde_json::to_string(&stock.to_shadow().await).unwrap() }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(shadow, r#"{"available":6,"reserved":4,"supplier":null,"total":10}"#);
+        assert_eq!((stock.supplier().await, stock.total().await), (None, 10));
+    }
+}
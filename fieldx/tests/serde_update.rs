@@ -0,0 +1,125 @@
+#![cfg(all(feature = "serde", feature = "sync"))]
+use fieldx::fxstruct;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+
+#[fxstruct(sync, rc, get(clone), track_changes, serde(default, update))]
+struct Config {
+    #[fieldx(lock, default("localhost".to_string()))]
+    host:    String,
+    #[fieldx(lock, get(copy), default(80))]
+    port:    u16,
+    #[fieldx(lock, optional, set)]
+    proxy:   String,
+    #[fieldx(lazy, lock)]
+    address: String,
+    #[fieldx(serde(off), get(copy), default(1))]
+    epoch:   u32,
+}
+
+impl Config {
+    fn build_address(&self) -> String {
+        format!("{}:{}", self.host(), self.port())
+    }
+}
+
+#[test]
+fn update_in_place() {
+    let config = Config::new();
+    let weak = Arc::downgrade(&config);
+    config.set_proxy("proxy:3128".to_string());
+    config.mark_clean();
+
+    assert_eq!(config.address(), "localhost:80");
+
+    config
+        .update_from_deserializer(&mut serde_json::Deserializer::from_str(r#"{"port":8080}"#))
+        .unwrap();
+
+    // The object is the same, only the fields present in the input are changed.
+    assert!(Arc::ptr_eq(&weak.upgrade().unwrap(), &config));
+    assert_eq!(config.host(), "localhost");
+    assert_eq!(config.port(), 8080);
+    assert_eq!(config.proxy().as_deref(), Some("proxy:3128"));
+    assert_eq!(config.address(), "localhost:80");
+    assert_eq!(config.epoch(), 1);
+    assert_eq!(config.dirty_fields(), vec!["port"]);
+
+    config
+        .update_from_deserializer(&mut serde_json::Deserializer::from_str(
+            r#"{"host":"example.com","proxy":null,"address":null}"#,
+        ))
+        .unwrap();
+
+    assert_eq!(config.host(), "example.com");
+    assert!(config.proxy().is_none());
+    assert_eq!(config.address(), "example.com:8080");
+}
+
+#[test]
+fn update_errors() {
+    let config = Config::new();
+    assert!(config
+        .update_from_deserializer(&mut serde_json::Deserializer::from_str(r#"{"port":"http"}"#))
+        .is_err());
+    assert_eq!(config.port(), 80);
+    assert!(config.dirty_fields().is_empty());
+}
+
+#[fxstruct(sync, get(clone), serde(default, update(clear_lazy)))]
+struct Cache {
+    #[fieldx(lock, default(vec![]))]
+    keys:  Vec<String>,
+    #[fieldx(lazy, lock, get(copy))]
+    count: usize,
+}
+
+impl Cache {
+    fn build_count(&self) -> usize {
+        self.keys().len()
+    }
+}
+
+#[test]
+fn update_clear_lazy() {
+    let cache = Arc::new(Cache::new());
+    assert_eq!(cache.count(), 0);
+
+    cache
+        .update_from_deserializer(&mut serde_json::Deserializer::from_str(r#"{"keys":["a","b"]}"#))
+        .unwrap();
+    // Not present in the input, hence reset and rebuilt from the new keys.
+    assert_eq!(cache.count(), 2);
+
+    cache
+        .update_from_deserializer(&mut serde_json::Deserializer::from_str(r#"{"count":10}"#))
+        .unwrap();
+    assert_eq!(cache.count(), 10);
+}
+
+#[cfg(feature = "async")]
+mod asyncish {
+    use fieldx::fxstruct;
+    use serde::Deserialize;
+
+    #[fxstruct(r#async, get(clone), serde(deserialize, default, update))]
+    struct Session {
+        #[fieldx(lock, default("guest".to_string()))]
+        user:  String,
+        #[fieldx(lock, optional, get(copy))]
+        token: u64,
+    }
+
+    #[tokio::test]
+    async fn async_update() {
+        let session = Session::new();
+        session
+            .update_from_deserializer(&mut serde_json::Deserializer::from_str(r#"{"token":42}"#))
+            .await
+            .unwrap();
+
+        assert_eq!(session.user().await, "guest");
+        assert_eq!(session.token().await, Some(42));
+    }
+}
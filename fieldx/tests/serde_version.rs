@@ -0,0 +1,145 @@
+#![cfg(feature = "serde")]
+use fieldx::fxstruct;
+use serde::Deserialize;
+use serde::Serialize;
+
+// Layout version 1: a single `name`.
+#[derive(Deserialize)]
+struct ConfigV1 {
+    name: String,
+}
+
+// Layout version 2: `name` was split into `host` and `port`.
+#[derive(Deserialize)]
+struct ConfigV2 {
+    host: String,
+    port: u16,
+}
+
+#[fxstruct(get, serde(version = 3, migrate_from(2 = Self::from_v2, 1 = Self::from_v1), shadow_name("ConfigShadow")))]
+#[derive(Debug)]
+struct Config {
+    host:    String,
+    #[fieldx(get(copy))]
+    port:    u16,
+    #[fieldx(get(copy), default(false))]
+    secure:  bool,
+    #[fieldx(lazy, get(clone))]
+    address: String,
+}
+
+impl Config {
+    fn build_address(&self) -> String {
+        format!("{}:{}", self.host, self.port)
+    }
+
+    fn from_v1(old: ConfigV1) -> ConfigV2 {
+        let (host, port) = old.name.split_once(':').unwrap();
+        ConfigV2 {
+            host: host.to_string(),
+            port: port.parse().unwrap(),
+        }
+    }
+
+    fn from_v2(old: ConfigV2) -> ConfigShadow {
+        ConfigShadow {
+            host:    old.host,
+            port:    old.port,
+            secure:  old.port == 443,
+            address: None,
+        }
+    }
+}
+
+#[test]
+fn versioned_roundtrip() {
+    let config = serde_json::from_str::<Config>(
+        r#"{"version":3,"data":{"host":"localhost","port":80,"secure":false,"address":null}}"#,
+    )
+    .unwrap();
+    assert_eq!(config.address(), "localhost:80");
+
+    assert_eq!(
+        serde_json::to_string(&config).unwrap(),
+        r#"{"version":3,"data":{"host":"localhost","port":80,"secure":false,"address":"localhost:80"}}"#
+    );
+}
+
+#[test]
+fn versioned_migration() {
+    let config =
+        serde_json::from_str::<Config>(r#"{"version":2,"data":{"host":"example.com","port":443}}"#).unwrap();
+    assert_eq!(config.host(), "example.com");
+    assert!(config.secure());
+
+    // Version 1 data goes through both migrations.
+    let config = serde_json::from_str::<Config>(r#"{"version":1,"data":{"name":"example.org:8080"}}"#).unwrap();
+    assert_eq!(config.port(), 8080);
+    assert!(!config.secure());
+    assert_eq!(config.address(), "example.org:8080");
+}
+
+#[test]
+fn versioned_errors() {
+    let err = serde_json::from_str::<Config>(r#"{"version":4,"data":{}}"#).unwrap_err();
+    assert!(err.to_string().contains("unsupported layout version 4"));
+
+    let err = serde_json::from_str::<Config>(r#"{"data":{"name":"example.org:8080"}}"#).unwrap_err();
+    assert!(err.to_string().contains("missing field `version`"));
+
+    let err = serde_json::from_str::<Config>(r#"{"version":1,"version":1,"data":{}}"#).unwrap_err();
+    assert!(err.to_string().contains("duplicate field `version`"));
+
+    let err = serde_json::from_str::<Config>(r#"{"data":{},"version":4}"#).unwrap_err();
+    assert!(err.to_string().contains("unsupported layout version 4"));
+}
+
+#[test]
+fn versioned_data_first() {
+    let config = serde_json::from_str::<Config>(
+        r#"{"data":{"host":"localhost","port":443,"secure":false,"address":null},"version":3}"#,
+    )
+    .unwrap();
+    assert_eq!(config.address(), "localhost:443");
+    assert!(!config.secure());
+
+    let config = serde_json::from_str::<Config>(r#"{"data":{"host":"example.com","port":443},"version":2}"#).unwrap();
+    assert!(config.secure());
+
+    let config = serde_json::from_str::<Config>(r#"{"data":{"name":"example.org:8080"},"version":1}"#).unwrap();
+    assert_eq!(config.port(), 8080);
+    assert_eq!(config.address(), "example.org:8080");
+}
+
+#[cfg(feature = "sync")]
+mod sync {
+    use fieldx::fxstruct;
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    #[fxstruct(sync, rc, get(copy), serde(default, version = 2, migrate_from(1 = Self::from_v1)))]
+    struct Counter {
+        #[fieldx(lock, set)]
+        value: u64,
+    }
+
+    impl Counter {
+        fn from_v1(old: u32) -> __CounterShadow {
+            __CounterShadow { value: old as u64 }
+        }
+    }
+
+    #[test]
+    fn versioned_rc() {
+        let counter =
+            Counter::deserialize_rc(&mut serde_json::Deserializer::from_str(r#"{"version":1,"data":7}"#)).unwrap();
+        assert_eq!(counter.value(), 7);
+        assert!(counter.myself().is_some());
+
+        counter.set_value(8);
+        assert_eq!(
+            serde_json::to_string(&*counter).unwrap(),
+            r#"{"version":2,"data":{"value":8}}"#
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use fieldx::fxstruct;
+
+#[fxstruct(set(chain, into), with, get)]
+struct FooPlain {
+    #[fieldx(get(copy))]
+    count: u32,
+
+    name: String,
+
+    #[fieldx(optional)]
+    comment: String,
+
+    #[fieldx(inner_mut, get(copy))]
+    flags: u8,
+
+    #[fieldx(lazy, get(copy))]
+    computed: u32,
+}
+
+impl FooPlain {
+    fn build_computed(&self) -> u32 {
+        42
+    }
+}
+
+#[test]
+fn plain_chain() {
+    let mut foo = FooPlain::new();
+    foo.set_count(1u32).set_name("foo").set_comment("a comment").set_flags(3u8);
+
+    assert_eq!(foo.count(), 1);
+    assert_eq!(foo.name(), "foo");
+    assert_eq!(foo.comment(), &Some("a comment".to_string()));
+    assert_eq!(foo.flags(), 3);
+
+    foo.set_computed(13u32).set_count(2u32);
+    assert_eq!(foo.computed(), 13);
+    assert_eq!(foo.count(), 2);
+}
+
+#[test]
+fn plain_with() {
+    let foo = FooPlain::new()
+        .with_count(10u32)
+        .with_name("bar")
+        .with_comment("with comment")
+        .with_flags(7u8)
+        .with_computed(1u32);
+
+    assert_eq!(foo.count(), 10);
+    assert_eq!(foo.name(), "bar");
+    assert_eq!(foo.comment(), &Some("with comment".to_string()));
+    assert_eq!(foo.flags(), 7);
+    assert_eq!(foo.computed(), 1);
+}
+
+#[fxstruct(get)]
+struct FooFieldLevel {
+    #[fieldx(set(chain), get(copy))]
+    x: i32,
+
+    #[fieldx(with("using_y"), get(copy))]
+    y: i32,
+
+    #[fieldx(set, with(off), get(copy))]
+    z: i32,
+}
+
+#[test]
+fn field_level() {
+    let mut foo = FooFieldLevel::new().using_y(2);
+    foo.set_x(1).set_x(3);
+    let old_z = foo.set_z(4);
+
+    assert_eq!(foo.x(), 3);
+    assert_eq!(foo.y(), 2);
+    assert_eq!(old_z, 0);
+    assert_eq!(foo.z(), 4);
+}
+
+#[cfg(feature = "sync")]
+mod sync {
+    use fieldx::fxstruct;
+
+    #[fxstruct(sync, set(chain), with, get)]
+    struct FooSync {
+        #[fieldx(get(copy))]
+        count: u32,
+
+        #[fieldx(lock, get(copy))]
+        locked: u32,
+
+        #[fieldx(lock, optional, get(clone))]
+        label: String,
+
+        #[fieldx(lazy, get(copy))]
+        computed: u32,
+    }
+
+    impl FooSync {
+        fn build_computed(&self) -> u32 {
+            42
+        }
+    }
+
+    #[test]
+    fn sync_chain() {
+        let mut foo = FooSync::new();
+        foo.set_count(1).set_computed(2);
+        foo.set_locked(3).set_label("lbl".to_string());
+
+        assert_eq!(foo.count(), 1);
+        assert_eq!(foo.computed(), 2);
+        assert_eq!(foo.locked(), 3);
+        assert_eq!(foo.label(), Some("lbl".to_string()));
+    }
+
+    #[test]
+    fn sync_with() {
+        let foo = FooSync::new()
+            .with_count(5)
+            .with_locked(6)
+            .with_label("with".to_string())
+            .with_computed(7);
+
+        assert_eq!(foo.count(), 5);
+        assert_eq!(foo.locked(), 6);
+        assert_eq!(foo.label(), Some("with".to_string()));
+        assert_eq!(foo.computed(), 7);
+    }
+}
+
+#[cfg(feature = "async")]
+mod asyncish {
+    use fieldx::fxstruct;
+
+    #[fxstruct(r#async, set(chain), with, get)]
+    struct FooAsync {
+        #[fieldx(lock, get(copy))]
+        locked: u32,
+
+        #[fieldx(get(copy))]
+        plain: u32,
+    }
+
+    #[tokio::test]
+    async fn async_chain_with() {
+        let mut foo = FooAsync::new().with_locked(1).await.with_plain(2);
+        assert_eq!(foo.locked().await, 1);
+        assert_eq!(foo.plain(), 2);
+
+        foo.set_plain(4).set_locked(3).await;
+        assert_eq!(foo.locked().await, 3);
+        assert_eq!(foo.plain(), 4);
+    }
+}
@@ -0,0 +1,195 @@
+use fieldx::error::FieldXError;
+use fieldx::fxstruct;
+use std::num::TryFromIntError;
+
+#[fxstruct(set(try_into), builder(try_into), get(copy))]
+#[derive(Debug)]
+struct FooPlain {
+    port: u16,
+
+    #[fieldx(optional)]
+    timeout: u32,
+
+    #[fieldx(lazy, builder(off))]
+    retries: u8,
+
+    #[fieldx(set(into, try_into(off)), builder(off))]
+    scale: u64,
+}
+
+impl FooPlain {
+    fn build_retries(&self) -> u8 {
+        3
+    }
+}
+
+#[test]
+fn plain_setter() {
+    let mut foo = FooPlain::new();
+
+    assert_eq!(foo.set_port(8080u64), Ok(0));
+    assert_eq!(foo.port(), 8080);
+
+    let _err: TryFromIntError = foo.set_port(70000u64).unwrap_err();
+    assert_eq!(foo.port(), 8080, "field is left intact on failed conversion");
+
+    assert_eq!(foo.set_timeout(30i64), Ok(None));
+    assert_eq!(foo.timeout(), Some(30));
+    assert!(foo.set_timeout(-1i64).is_err());
+    assert_eq!(foo.timeout(), Some(30));
+
+    assert_eq!(foo.retries(), 3);
+    assert_eq!(foo.set_retries(5u32), Ok(Some(3)));
+    assert!(foo.set_retries(500u32).is_err());
+    assert_eq!(foo.retries(), 5);
+
+    assert_eq!(foo.set_scale(2u8), 0);
+    assert_eq!(foo.scale(), 2);
+}
+
+#[test]
+fn plain_builder() {
+    let foo = FooPlain::builder()
+        .port(443u32)
+        .unwrap()
+        .timeout(10i8)
+        .unwrap()
+        .build()
+        .expect("FooPlain builder failed");
+
+    assert_eq!(foo.port(), 443);
+    assert_eq!(foo.timeout(), Some(10));
+
+    assert!(FooPlain::builder().port(-1i32).is_err());
+}
+
+#[fxstruct(builder(try_into(defer)), get(copy))]
+#[derive(Debug)]
+struct FooDeferred {
+    port: u16,
+
+    #[fieldx(optional)]
+    timeout: u32,
+
+    #[fieldx(builder(try_into(defer(off))))]
+    retries: u8,
+}
+
+#[test]
+fn deferred_builder() {
+    let foo = FooDeferred::builder()
+        .port(443u32)
+        .timeout(10i8)
+        .retries(3u64)
+        .unwrap()
+        .build()
+        .expect("FooDeferred builder failed");
+    assert_eq!((foo.port(), foo.timeout(), foo.retries()), (443, Some(10), 3));
+
+    let err = FooDeferred::builder()
+        .port(-1i32)
+        .timeout(-2i64)
+        .retries(1u8)
+        .unwrap()
+        .build()
+        .unwrap_err();
+    assert!(matches!(err, FieldXError::FieldConversion { ref field, .. } if field == "port"));
+    assert!(err.to_string().starts_with("Cannot convert value for field 'port': "));
+
+    // A successful conversion discards the error.
+    let foo = FooDeferred::builder()
+        .port(70000u32)
+        .port(80u32)
+        .retries(1u8)
+        .unwrap()
+        .build()
+        .expect("FooDeferred builder failed");
+    assert_eq!((foo.port(), foo.timeout()), (80, None));
+
+    // Not deferred for this field.
+    assert!(FooDeferred::builder().retries(256u16).is_err());
+}
+
+// The error type is not convertible from `FieldXError`.
+#[derive(Debug)]
+enum BuildError {
+    Failed(String),
+}
+
+#[fxstruct(builder(try_into(defer), error(BuildError, BuildError::Failed)), get(copy))]
+#[derive(Debug)]
+struct FooDeferredVariant {
+    port: u16,
+}
+
+#[test]
+fn deferred_builder_variant() {
+    let err = FooDeferredVariant::builder().port(-1i32).build().unwrap_err();
+    assert!(matches!(err, BuildError::Failed(ref msg) if msg.starts_with("Cannot convert value for field 'port': ")));
+
+    let err = FooDeferredVariant::builder().build().unwrap_err();
+    assert!(matches!(err, BuildError::Failed(ref msg) if msg == "port"));
+
+    assert_eq!(FooDeferredVariant::builder().port(8080u32).build().unwrap().port(), 8080);
+}
+
+#[fxstruct(get(copy))]
+struct FooChainWith {
+    #[fieldx(set(chain, try_into), with)]
+    x: u8,
+}
+
+#[test]
+fn chain_and_with() -> Result<(), TryFromIntError> {
+    let mut foo = FooChainWith::new().with_x(1u16)?;
+    assert_eq!(foo.x(), 1);
+
+    foo.set_x(2u32)?.set_x(3u64)?;
+    assert_eq!(foo.x(), 3);
+
+    assert!(FooChainWith::new().with_x(256u16).is_err());
+    assert!(foo.set_x(300u16).is_err());
+    assert_eq!(foo.x(), 3);
+
+    Ok(())
+}
+
+#[cfg(feature = "sync")]
+mod sync {
+    use fieldx::fxstruct;
+
+    #[fxstruct(sync, set(try_into), builder(try_into), get(copy))]
+    struct FooSync {
+        #[fieldx(lock)]
+        locked: u16,
+
+        #[fieldx(lock, optional)]
+        opt_locked: u16,
+
+        plain: u16,
+    }
+
+    #[test]
+    fn sync_setter() {
+        let mut foo = FooSync::builder()
+            .locked(1u64)
+            .unwrap()
+            .plain(2u64)
+            .unwrap()
+            .opt_locked(3u64)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(foo.set_locked(10u32), Ok(1));
+        assert!(foo.set_locked(100_000u32).is_err());
+        assert_eq!(foo.locked(), 10);
+
+        assert_eq!(foo.set_opt_locked(20u32), Ok(Some(3)));
+        assert_eq!(foo.opt_locked(), Some(20));
+
+        assert_eq!(foo.set_plain(30u32), Ok(2));
+        assert!(foo.set_plain(-1i32).is_err());
+        assert_eq!(foo.plain(), 30);
+    }
+}
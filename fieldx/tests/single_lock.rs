@@ -0,0 +1,302 @@
+#![cfg(feature = "sync")]
+use fieldx::fxstruct;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+#[fxstruct(sync(single_lock), get(copy), builder, transaction)]
+#[derive(Debug)]
+struct Counter {
+    #[fieldx(get(clone), set, default("counter".to_string()))]
+    name: String,
+
+    #[fieldx(set, reader, writer, get_mut, default(0))]
+    value: u64,
+
+    #[fieldx(optional, set, clearer, predicate)]
+    limit: u64,
+
+    #[fieldx(lazy, get(copy(off)), clearer, predicate, set, reader, writer)]
+    label: String,
+
+    #[fieldx(lazy, get(clone), get_mut)]
+    history: Vec<u64>,
+
+    #[fieldx(atomic, default(0))]
+    hits: usize,
+
+    #[fieldx(atomic, default(0))]
+    builds: usize,
+}
+
+impl Counter {
+    fn build_label(&self) -> String {
+        self.fetch_add_builds(1);
+        // Lazy builders can read other fields.
+        format!("{}#{}", self.name(), self.value())
+    }
+
+    fn build_history(&self) -> Vec<u64> {
+        vec![self.value()]
+    }
+}
+
+#[test]
+fn single_lock_accessors() {
+    let counter = Counter::new();
+
+    assert_eq!(counter.name(), "counter");
+    assert_eq!(counter.set_name("renamed".to_string()), "counter");
+    assert_eq!(counter.name(), "renamed");
+    assert_eq!(counter.set_value(3), 0);
+    *counter.write_value() += 1;
+    assert_eq!(*counter.read_value(), 4);
+    *counter.value_mut() += 1;
+    assert_eq!(counter.value(), 5);
+
+    assert!(!counter.has_limit());
+    assert_eq!(counter.set_limit(10), None);
+    assert_eq!(counter.limit(), Some(10));
+    assert_eq!(counter.clear_limit(), Some(10));
+
+    assert_eq!(counter.fetch_add_hits(2), 0);
+    assert_eq!(counter.hits(), 2);
+}
+
+#[test]
+fn single_lock_shared() {
+    let counter = Counter::new();
+
+    {
+        let mut state = counter.write_state();
+        state.name = "shared".to_string();
+        state.value = 42;
+    }
+
+    let state = counter.read_state();
+    assert_eq!(state.name, "shared");
+    // All fields are protected by the same lock.
+    assert!(counter.try_write_value().is_none());
+    assert_eq!(*counter.read_value(), 42);
+}
+
+#[test]
+fn single_lock_try_lazy() {
+    let counter = Counter::new();
+
+    {
+        let _wguard = counter.write_value();
+        assert!(counter.try_read_label().is_none());
+        assert!(counter.try_read_label_for(Duration::from_millis(10)).is_none());
+        assert!(counter.try_write_label().is_none());
+    }
+    assert!(!counter.has_label());
+    assert_eq!(counter.try_read_label().as_deref().map(String::as_str), Some("counter#0"));
+    {
+        let rguard = counter.read_label();
+        assert_eq!(*rguard, "counter#0");
+        assert!(counter.try_write_label_for(Duration::from_millis(10)).is_none());
+    }
+    assert_eq!(counter.try_write_label().unwrap().clear(), Some("counter#0".to_string()));
+    assert_eq!(
+        counter.try_read_label_for(Duration::from_millis(10)).as_deref().map(String::as_str),
+        Some("counter#0")
+    );
+    assert_eq!(counter.builds(), 2);
+}
+
+#[test]
+fn single_lock_lazy() {
+    let counter = Counter::new();
+    counter.set_value(7);
+
+    assert!(!counter.has_label());
+    assert_eq!(*counter.label(), "counter#7");
+    assert!(counter.has_label());
+    assert_eq!(*counter.label(), "counter#7");
+    assert_eq!(counter.builds(), 1);
+
+    assert_eq!(counter.clear_label(), Some("counter#7".to_string()));
+    counter.set_value(8);
+    assert_eq!(*counter.label(), "counter#8");
+    assert_eq!(counter.builds(), 2);
+
+    assert_eq!(counter.set_label("manual".to_string()), Some("counter#8".to_string()));
+    assert_eq!(*counter.label(), "manual");
+    assert_eq!(counter.write_label().clear(), Some("manual".to_string()));
+    assert!(!counter.has_label());
+    assert_eq!(counter.write_label().store("stored".to_string()), None);
+    assert_eq!(*counter.label(), "stored");
+    counter.clear_label();
+
+    counter.history_mut().push(9);
+    assert_eq!(counter.history(), vec![8, 9]);
+}
+
+#[test]
+fn single_lock_lazy_threads() {
+    let counter = Arc::new(Counter::new());
+
+    let handles = (0..8)
+        .map(|_| {
+            let counter = counter.clone();
+            thread::spawn(move || counter.label().clone())
+        })
+        .collect::<Vec<_>>();
+
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), "counter#0");
+    }
+    assert_eq!(counter.builds(), 1);
+}
+
+#[test]
+fn single_lock_builder() {
+    let counter = Counter::builder()
+        .name("built".to_string())
+        .value(5)
+        .label("preset".to_string())
+        .build()
+        .unwrap();
+
+    assert_eq!(counter.name(), "built");
+    assert_eq!(counter.value(), 5);
+    assert_eq!(*counter.label(), "preset");
+    assert_eq!(counter.builds(), 0);
+    assert!(format!("{counter:?}").contains("built"));
+}
+
+#[test]
+fn single_lock_transaction() {
+    let counter = Counter::new();
+
+    counter.transaction(|tx| {
+        tx.set_name("tx".to_string());
+        *tx.value_mut() = 11;
+    });
+
+    assert_eq!(counter.name(), "tx");
+    assert_eq!(counter.value(), 11);
+}
+
+#[fxstruct(sync(single_lock), fallible(off, error(String)))]
+struct Config<T: Clone + Default + Send + Sync + 'static> {
+    #[fieldx(get(clone), set)]
+    source: String,
+
+    #[fieldx(lazy, fallible, get(clone))]
+    parsed: T,
+
+    #[fieldx(lazy, fallible, get)]
+    checked: T,
+}
+
+impl<T: Clone + Default + Send + Sync + 'static> Config<T> {
+    fn build_parsed(&self) -> Result<T, String> {
+        if self.source().is_empty() {
+            Err("no source".to_string())
+        }
+        else {
+            Ok(T::default())
+        }
+    }
+
+    fn build_checked(&self) -> Result<T, String> {
+        if self.source() == "data" {
+            Ok(T::default())
+        }
+        else {
+            Err(format!("bad source '{}'", self.source()))
+        }
+    }
+}
+
+#[test]
+fn single_lock_fallible() {
+    let config = Config::<u32>::new();
+
+    assert_eq!(config.parsed(), Err("no source".to_string()));
+    assert!(config.checked().is_err());
+
+    config.set_source("data".to_string());
+    assert_eq!(config.parsed(), Ok(0));
+    assert_eq!(*config.checked().unwrap(), 0);
+}
+
+#[fxstruct(sync(single_lock))]
+struct Nested {
+    #[fieldx(lazy, get(copy))]
+    outer: u32,
+    #[fieldx(lazy, get(copy))]
+    inner: u32,
+}
+
+impl Nested {
+    fn build_outer(&self) -> u32 {
+        self.inner() + 1
+    }
+
+    fn build_inner(&self) -> u32 {
+        1
+    }
+}
+
+#[test]
+fn single_lock_nested_lazy() {
+    let nested = Nested::new();
+    assert_eq!(nested.outer(), 2);
+    assert_eq!(nested.inner(), 1);
+}
+
+#[test]
+fn single_lock_nested_lazy_threads() {
+    let nested = Arc::new(Nested::new());
+
+    let handles = (0..8)
+        .map(|i| {
+            let nested = nested.clone();
+            thread::spawn(move || if i % 2 == 0 { nested.outer() } else { nested.inner() + 1 })
+        })
+        .collect::<Vec<_>>();
+
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), 2);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_single_lock {
+    use fieldx::fxstruct;
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    #[fxstruct(sync(single_lock), get(clone), serde(default))]
+    #[derive(Clone, Debug)]
+    struct Profile {
+        #[fieldx(default("anonymous".to_string()))]
+        user:   String,
+        #[fieldx(lazy)]
+        avatar: String,
+        #[fieldx(get(copy), serde(off), default(1))]
+        epoch:  u32,
+    }
+
+    impl Profile {
+        fn build_avatar(&self) -> String {
+            format!("{}.png", self.user())
+        }
+    }
+
+    #[test]
+    fn serde_single_lock() {
+        let profile = Profile::new();
+
+        let json = serde_json::to_string(&profile).unwrap();
+        assert_eq!(json, r#"{"user":"anonymous","avatar":"anonymous.png"}"#);
+
+        let profile = serde_json::from_str::<Profile>(r#"{"user":"bob"}"#).unwrap();
+        assert_eq!(profile.user(), "bob");
+        assert_eq!(profile.avatar(), "bob.png");
+        assert_eq!(profile.epoch(), 1);
+    }
+}
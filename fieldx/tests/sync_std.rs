@@ -0,0 +1,109 @@
+#![cfg(feature = "sync-std")]
+use fieldx::fxstruct;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+#[fxstruct(sync, get)]
+struct Foo {
+    #[fieldx(lock, lazy, get(clone), set, clearer, predicate)]
+    name: String,
+
+    #[fieldx(lock, lazy, get_mut, reader, writer)]
+    items: Vec<u32>,
+
+    #[fieldx(lock, writer, get(copy), set, default(1))]
+    counter: u32,
+
+    #[fieldx(lock(mutex), get(copy))]
+    total: u32,
+
+    #[fieldx(lock, get(deref), default("foo".to_string()))]
+    label: String,
+
+    #[fieldx(lazy, fallible(error(String)), get(copy))]
+    checked: u32,
+}
+
+impl Foo {
+    fn build_name(&self) -> String {
+        "default".to_string()
+    }
+
+    fn build_items(&self) -> Vec<u32> {
+        vec![1, 2, 3]
+    }
+
+    fn build_checked(&self) -> Result<u32, String> {
+        Err("not today".to_string())
+    }
+}
+
+#[test]
+fn std_backend() {
+    let foo = Foo::new();
+
+    assert!(!foo.has_name());
+    assert_eq!(foo.name(), "default");
+    assert!(foo.has_name());
+    assert_eq!(foo.clear_name(), Some("default".to_string()));
+    assert_eq!(foo.set_name("bar".to_string()), None);
+    assert_eq!(foo.name(), "bar");
+
+    assert_eq!(*foo.items(), vec![1, 2, 3]);
+    foo.items_mut().push(4);
+    assert_eq!(foo.items().len(), 4);
+    {
+        let _rguard = foo.read_items();
+        assert_eq!(foo.try_read_items().map(|g| g.len()), Some(4));
+        assert!(foo.try_write_items_for(Duration::from_millis(1)).is_none());
+    }
+    assert_eq!(foo.try_write_items().unwrap().clear(), Some(vec![1, 2, 3, 4]));
+    assert_eq!(foo.try_read_items_for(Duration::from_millis(1)).map(|g| g.len()), Some(3));
+
+    assert_eq!(foo.counter(), 1);
+    assert!(foo.try_write_counter_for(Duration::from_millis(1)).is_some());
+    assert_eq!(foo.set_counter(2), 1);
+    assert_eq!(foo.counter(), 2);
+
+    *foo.lock_total() += 5;
+    assert_eq!(foo.total(), 5);
+
+    assert_eq!(&*foo.label(), "foo");
+
+    assert_eq!(foo.checked(), Err("not today".to_string()));
+}
+
+#[test]
+fn concurrent_lazy() {
+    let foo = Arc::new(Foo::new());
+
+    let handles = (0..8)
+        .map(|_| {
+            let foo = foo.clone();
+            thread::spawn(move || foo.items().len())
+        })
+        .collect::<Vec<_>>();
+
+    for h in handles {
+        assert_eq!(h.join().unwrap(), 3);
+    }
+}
+
+#[test]
+fn poisoned_lock() {
+    let foo = Arc::new(Foo::new());
+
+    let foo_clone = foo.clone();
+    let res = thread::spawn(move || {
+        let mut guard = foo_clone.write_counter();
+        *guard = 42;
+        panic!("poison the lock");
+    })
+    .join();
+    assert!(res.is_err());
+
+    // Poisoning is ignored; the value is what the panicking thread left behind.
+    assert_eq!(foo.counter(), 42);
+    assert_eq!(foo.set_counter(13), 42);
+}
@@ -0,0 +1,299 @@
+use fieldx::fxstruct;
+
+#[fxstruct(track_changes, get, set, builder)]
+#[derive(Clone, Debug, PartialEq)]
+struct Document {
+    #[fieldx(default("untitled".to_string()))]
+    title: String,
+
+    #[fieldx(optional, clearer)]
+    author: String,
+
+    #[fieldx(inner_mut, get(copy), default(1))]
+    revision: u32,
+
+    #[fieldx(lazy, clearer)]
+    summary: String,
+}
+
+impl Document {
+    fn build_summary(&self) -> String {
+        format!("{} by {:?}", self.title(), self.author())
+    }
+}
+
+#[test]
+fn plain_tracking() {
+    let mut doc = Document::new();
+
+    assert!(doc.dirty_fields().is_empty());
+    assert_eq!(doc.version_title(), 0);
+
+    doc.set_title("Report".to_string());
+    doc.set_title("Final report".to_string());
+    assert!(doc.is_title_dirty());
+    assert!(!doc.is_author_dirty());
+    assert_eq!(doc.version_title(), 2);
+    assert_eq!(doc.dirty_fields(), vec!["title"]);
+
+    // Interior mutability doesn't need a mutable reference.
+    doc.set_revision(2);
+    assert!(doc.is_revision_dirty());
+
+    doc.mark_clean();
+    assert!(doc.dirty_fields().is_empty());
+    assert_eq!(doc.version_title(), 2);
+
+    doc.set_author("Alice".to_string());
+    assert_eq!(doc.summary(), "Final report by Some(\"Alice\")");
+    // Lazy initialization is not a change.
+    assert!(!doc.is_summary_dirty());
+    doc.clear_summary();
+    doc.clear_author();
+    assert_eq!(doc.version_author(), 2);
+    assert_eq!(doc.dirty_fields(), vec!["author", "summary"]);
+}
+
+#[test]
+fn plain_tracking_construction() {
+    let doc = Document::builder().title("Built".to_string()).build().unwrap();
+    assert!(doc.dirty_fields().is_empty());
+
+    let mut copy = doc.clone();
+    copy.set_title("Copy".to_string());
+    assert!(copy.is_title_dirty());
+    assert!(!doc.is_title_dirty());
+
+    // Change tracking state doesn't take part in comparisons.
+    copy.set_title("Built".to_string());
+    assert_eq!(copy, doc);
+}
+
+#[fxstruct(track_changes, get(copy), set(try_into))]
+struct Limits {
+    #[fieldx(default(1))]
+    depth: u8,
+}
+
+#[test]
+fn failed_conversion() {
+    let mut limits = Limits::new();
+
+    assert!(limits.set_depth(1000u32).is_err());
+    assert_eq!(limits.depth(), 1);
+    assert!(!limits.is_depth_dirty());
+
+    assert_eq!(limits.set_depth(8u32), Ok(1));
+    assert_eq!(limits.version_depth(), 1);
+}
+
+#[cfg(feature = "sync")]
+mod sync {
+    use fieldx::fxstruct;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[fxstruct(sync, track_changes, get(copy), transaction)]
+    struct Settings {
+        #[fieldx(lock, set, writer, default(10))]
+        limit: u32,
+
+        #[fieldx(lock(group = "window"), set, default(640))]
+        width: u32,
+
+        #[fieldx(lock(group = "window"), default(480))]
+        height: u32,
+
+        #[fieldx(lock, optional, set, clearer)]
+        theme: u8,
+
+        #[fieldx(lazy, clearer)]
+        area: u32,
+    }
+
+    impl Settings {
+        fn build_area(&self) -> u32 {
+            self.width() * self.height()
+        }
+    }
+
+    #[test]
+    fn sync_tracking() {
+        let mut settings = Settings::new();
+
+        *settings.write_limit() += 1;
+        assert_eq!(settings.version_limit(), 1);
+        settings.set_width(800);
+        settings.set_theme(1);
+        settings.clear_theme();
+        assert_eq!(settings.theme(), None);
+        assert_eq!(settings.version_theme(), 2);
+        assert_eq!(settings.dirty_fields(), vec!["limit", "width", "theme"]);
+
+        assert_eq!(settings.area(), 800 * 480);
+        assert!(!settings.is_area_dirty());
+        settings.clear_area();
+        assert!(settings.is_area_dirty());
+
+        settings.mark_clean();
+        assert!(settings.dirty_fields().is_empty());
+
+        settings.transaction(|tx| {
+            tx.set_width(1024);
+            tx.set_height(768);
+            *tx.limit_mut() = 0;
+        });
+        // Mutable access via transaction isn't tracked, only the setters are.
+        assert_eq!(settings.dirty_fields(), vec!["width", "height"]);
+        assert_eq!(settings.version_width(), 2);
+    }
+
+    #[test]
+    fn sync_tracking_threads() {
+        let settings = Arc::new(Settings::new());
+
+        let handles = (0..4)
+            .map(|i| {
+                let settings = settings.clone();
+                thread::spawn(move || {
+                    for j in 0..100 {
+                        settings.set_limit(i * 100 + j);
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert!(settings.limit() < 400);
+        assert_eq!(settings.version_limit(), 400);
+        assert!(settings.is_limit_dirty());
+    }
+
+    #[test]
+    fn sync_tracking_no_lost_changes() {
+        let settings = Arc::new(Settings::new());
+
+        let writer = {
+            let settings = settings.clone();
+            thread::spawn(move || {
+                for i in 0..10_000 {
+                    settings.set_limit(i);
+                }
+            })
+        };
+
+        // Whatever value is seen after marking the struct clean, a later change must make it dirty again.
+        let mut seen = settings.limit();
+        while !writer.is_finished() {
+            settings.mark_clean();
+            seen = settings.limit();
+        }
+        writer.join().unwrap();
+
+        if settings.limit() != seen {
+            assert!(settings.is_limit_dirty());
+        }
+    }
+
+    #[fxstruct(sync, track_changes, get)]
+    struct Counters {
+        #[fieldx(atomic)]
+        hits: u64,
+    }
+
+    #[test]
+    fn atomic_tracking() {
+        let counters = Counters::new();
+
+        counters.fetch_add_hits(2);
+        assert_eq!(counters.version_hits(), 1);
+        counters.swap_hits(5);
+        assert_eq!(counters.version_hits(), 2);
+
+        counters.mark_clean();
+        assert_eq!(counters.compare_exchange_hits(1, 7), Err(5));
+        assert!(!counters.is_hits_dirty());
+        assert_eq!(counters.compare_exchange_hits(5, 7), Ok(5));
+        assert!(counters.is_hits_dirty());
+        assert_eq!(counters.hits(), 7);
+    }
+
+    #[cfg(feature = "arc-swap")]
+    #[fxstruct(sync, track_changes, get)]
+    struct Routes {
+        #[fieldx(swap, set)]
+        table: Vec<u16>,
+    }
+
+    #[cfg(feature = "arc-swap")]
+    #[test]
+    fn swap_tracking() {
+        let routes = Routes::new();
+
+        routes.set_table(vec![80]);
+        routes.update_table(|table| table.iter().copied().chain([443]).collect());
+        assert_eq!(*routes.table(), vec![80, 443]);
+        assert_eq!(routes.version_table(), 2);
+    }
+}
+
+#[cfg(feature = "async")]
+mod asyncish {
+    use fieldx::fxstruct;
+
+    #[fxstruct(r#async, track_changes, get(copy))]
+    struct Session {
+        #[fieldx(lock, set)]
+        token: u64,
+        #[fieldx(lock, writer)]
+        hits:  u32,
+    }
+
+    #[tokio::test]
+    async fn async_tracking() {
+        let session = Session::new();
+
+        session.set_token(17).await;
+        *session.write_hits().await += 1;
+        assert_eq!(session.token().await, 17);
+        assert_eq!(session.hits().await, 1);
+        assert_eq!(session.dirty_fields(), vec!["token", "hits"]);
+
+        session.mark_clean();
+        assert!(!session.is_token_dirty());
+        assert_eq!(session.version_hits(), 1);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_tracking {
+    use fieldx::fxstruct;
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    #[fxstruct(track_changes, get, set, serde(default))]
+    #[derive(Clone)]
+    struct Profile {
+        #[fieldx(default("anonymous".to_string()))]
+        user: String,
+        #[fieldx(get(copy))]
+        age:  u8,
+    }
+
+    #[test]
+    fn serde_tracking() {
+        let mut profile = Profile::new();
+        profile.set_age(42);
+
+        let json = serde_json::to_string(&profile).unwrap();
+        assert_eq!(json, r#"{"user":"anonymous","age":42}"#);
+
+        // Deserialized objects start clean.
+        let profile = serde_json::from_str::<Profile>(r#"{"user":"bob"}"#).unwrap();
+        assert_eq!(profile.user(), "bob");
+        assert!(profile.dirty_fields().is_empty());
+    }
+}
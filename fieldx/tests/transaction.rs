@@ -0,0 +1,137 @@
+#![cfg(feature = "sync")]
+use fieldx::fxstruct;
+use std::sync::Arc;
+use std::thread;
+
+#[fxstruct(sync, get(copy), transaction)]
+struct Account {
+    #[fieldx(lock, default(100))]
+    balance: i64,
+
+    #[fieldx(lock(mutex), default(0))]
+    reserved: i64,
+
+    #[fieldx(lock, optional, get(clone))]
+    note: String,
+
+    #[fieldx(lock, lazy)]
+    lazy_total: i64,
+
+    #[fieldx(get(off))]
+    owner: String,
+}
+
+impl Account {
+    fn build_lazy_total(&self) -> i64 {
+        self.balance() + self.reserved()
+    }
+
+    fn reserve(&self, amount: i64) -> bool {
+        self.transaction(|tx| {
+            if *tx.balance() < amount {
+                return false;
+            }
+            *tx.balance_mut() -= amount;
+            let reserved = *tx.reserved();
+            tx.set_reserved(reserved + amount);
+            true
+        })
+    }
+}
+
+#[test]
+fn transaction_basic() {
+    let account = Account::new();
+
+    assert!(account.reserve(30));
+    assert_eq!(account.balance(), 70);
+    assert_eq!(*account.lock_reserved(), 30);
+    assert!(!account.reserve(100));
+    assert_eq!(account.balance(), 70);
+
+    let old = account.transaction(|tx| {
+        assert_eq!(*tx.note(), None);
+        tx.set_note("first".to_string());
+        tx.set_note("second".to_string())
+    });
+    assert_eq!(old, Some("first".to_string()));
+    assert_eq!(account.note(), Some("second".to_string()));
+
+    // Lazy fields are not part of a transaction.
+    assert_eq!(account.lazy_total(), 100);
+    assert!(account.owner.is_empty());
+}
+
+#[test]
+fn transaction_consistency() {
+    let account = Arc::new(Account::new());
+
+    let handles = (0..4)
+        .map(|_| {
+            let account = Arc::clone(&account);
+            thread::spawn(move || {
+                for _ in 0..100 {
+                    account.reserve(1);
+                    account.transaction(|tx| assert_eq!(*tx.balance() + *tx.reserved(), 100));
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    assert_eq!(account.balance(), 0);
+    assert_eq!(account.reserved(), 100);
+}
+
+#[fxstruct(sync, transaction("atomically", vis(pub(crate))))]
+struct Pair<T: Clone + Default + Send + Sync + 'static> {
+    #[fieldx(lock, get(clone))]
+    left:  T,
+    #[fieldx(lock, get(clone))]
+    right: T,
+}
+
+#[test]
+fn transaction_generic() {
+    let pair = Pair::<String>::new();
+
+    pair.atomically(|tx: &mut PairTransaction<'_, String>| {
+        tx.set_left("l".to_string());
+        tx.set_right("r".to_string());
+    });
+    assert_eq!(pair.left(), "l");
+    assert_eq!(pair.right(), "r");
+}
+
+#[cfg(feature = "async")]
+mod asyncish {
+    use fieldx::fxstruct;
+
+    #[fxstruct(r#async, get(copy), transaction)]
+    struct Counters {
+        #[fieldx(lock, default(1))]
+        a: u32,
+        #[fieldx(lock, default(2))]
+        b: u32,
+    }
+
+    #[tokio::test]
+    async fn async_transaction() {
+        let counters = Counters::new();
+
+        let sum = counters
+            .transaction(|tx| {
+                let a = tx.set_a(10);
+                let b = tx.set_b(20);
+                a + b
+            })
+            .await;
+
+        assert_eq!(sum, 3);
+        assert_eq!(counters.a().await, 10);
+        assert_eq!(counters.b().await, 20);
+    }
+}
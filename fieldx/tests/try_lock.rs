@@ -0,0 +1,173 @@
+#![cfg(feature = "sync")]
+use fieldx::fxstruct;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+#[fxstruct(sync, get(copy))]
+struct Foo {
+    #[fieldx(reader, writer, default(1))]
+    counter: u32,
+
+    #[fieldx(optional, reader("peek_label"), writer, get(clone))]
+    label: String,
+}
+
+#[test]
+fn try_lock_helpers() {
+    let foo = Foo::new();
+
+    assert_eq!(foo.try_read_counter().map(|g| *g), Some(1));
+    {
+        let _rguard = foo.read_counter();
+        assert!(foo.try_read_counter().is_some());
+        assert!(foo.try_write_counter().is_none());
+        assert!(foo.try_write_counter_for(Duration::from_millis(10)).is_none());
+    }
+    {
+        let mut wguard = foo.try_write_counter_for(Duration::from_millis(10)).unwrap();
+        *wguard = 2;
+        assert!(foo.try_read_counter().is_none());
+        assert!(foo.try_read_counter_for(Duration::from_millis(10)).is_none());
+    }
+    assert_eq!(foo.counter(), 2);
+
+    *foo.try_write_label().unwrap() = Some("lbl".to_string());
+    assert_eq!(foo.try_peek_label().unwrap().as_deref(), Some("lbl"));
+    assert_eq!(foo.try_peek_label_for(Duration::from_millis(1)).unwrap().as_deref(), Some("lbl"));
+    assert_eq!(foo.label(), Some("lbl".to_string()));
+}
+
+#[test]
+fn timed_wait() {
+    let foo = Arc::new(Foo::new());
+    let wguard = foo.write_counter();
+
+    let foo_clone = foo.clone();
+    let handle = thread::spawn(move || {
+        let start = Instant::now();
+        let value = foo_clone.try_read_counter_for(Duration::from_secs(10)).map(|g| *g);
+        (value, start.elapsed())
+    });
+
+    thread::sleep(Duration::from_millis(50));
+    drop(wguard);
+
+    let (value, elapsed) = handle.join().unwrap();
+    assert_eq!(value, Some(1));
+    assert!(elapsed < Duration::from_secs(10));
+}
+
+#[fxstruct(sync)]
+struct Lazy {
+    #[fieldx(lazy, reader, writer)]
+    name: String,
+
+    #[fieldx(lazy, fallible(error(String)), reader)]
+    checked: u32,
+}
+
+impl Lazy {
+    fn build_name(&self) -> String {
+        "lazy".to_string()
+    }
+
+    fn build_checked(&self) -> Result<u32, String> {
+        Err("not ready".to_string())
+    }
+}
+
+#[test]
+fn try_lock_lazy() {
+    let lazy = Lazy::new();
+
+    {
+        // The writer doesn't initialize the field.
+        let _writer = lazy.try_write_name().unwrap();
+        assert!(lazy.try_read_name().is_none());
+        assert!(lazy.try_read_name_for(Duration::from_millis(10)).is_none());
+        assert!(lazy.try_write_name_for(Duration::from_millis(10)).is_none());
+    }
+
+    assert_eq!(lazy.try_read_name().as_deref().map(String::as_str), Some("lazy"));
+    {
+        let _rguard = lazy.read_name();
+        assert_eq!(lazy.try_read_name().as_deref().map(String::as_str), Some("lazy"));
+        assert!(lazy.try_write_name_for(Duration::from_millis(10)).is_none());
+    }
+    assert_eq!(lazy.try_write_name().unwrap().store("stored".to_string()), Some("lazy".to_string()));
+    assert_eq!(
+        lazy.try_read_name_for(Duration::from_millis(10)).as_deref().map(String::as_str),
+        Some("stored")
+    );
+
+    assert_eq!(lazy.try_read_checked().err(), Some("not ready".to_string()));
+    assert_eq!(
+        lazy.try_read_checked_for(Duration::from_millis(10)).err(),
+        Some("not ready".to_string())
+    );
+}
+
+#[cfg(feature = "async")]
+mod asyncish {
+    use fieldx::fxstruct;
+    use std::time::Duration;
+
+    #[fxstruct(r#async, get(copy))]
+    struct FooAsync {
+        #[fieldx(reader, writer, default(1))]
+        counter: u32,
+    }
+
+    #[tokio::test]
+    async fn async_try_lock() {
+        let foo = FooAsync::new();
+
+        assert_eq!(foo.try_read_counter().map(|g| *g), Some(1));
+        {
+            let _rguard = foo.read_counter().await;
+            assert!(foo.try_write_counter().is_none());
+            assert!(foo.try_write_counter_for(Duration::from_millis(10)).await.is_none());
+        }
+        *foo.try_write_counter_for(Duration::from_millis(10)).await.unwrap() = 2;
+        assert_eq!(foo.counter().await, 2);
+
+        let _ = fieldx::r#async::set_sleep_hook(|d| Box::pin(tokio::time::sleep(d)));
+        let _wguard = foo.write_counter().await;
+        assert!(foo.try_read_counter_for(Duration::from_millis(10)).await.is_none());
+    }
+
+    #[fxstruct(r#async)]
+    struct LazyAsync {
+        #[fieldx(lazy, reader, writer)]
+        name: String,
+    }
+
+    impl LazyAsync {
+        async fn build_name(&self) -> String {
+            "lazy".to_string()
+        }
+    }
+
+    #[tokio::test]
+    async fn async_try_lock_lazy() {
+        let _ = fieldx::r#async::set_sleep_hook(|d| Box::pin(tokio::time::sleep(d)));
+        let lazy = LazyAsync::new();
+
+        {
+            let _writer = lazy.try_write_name().await.unwrap();
+            assert!(lazy.try_read_name().await.is_none());
+            assert!(lazy.try_read_name_for(Duration::from_millis(10)).await.is_none());
+        }
+        assert_eq!(lazy.try_read_name().await.as_deref().map(String::as_str), Some("lazy"));
+        {
+            let _rguard = lazy.read_name().await;
+            assert!(lazy.try_write_name_for(Duration::from_millis(10)).await.is_none());
+        }
+        assert_eq!(
+            lazy.try_write_name_for(Duration::from_millis(10)).await.unwrap().clear(),
+            Some("lazy".to_string())
+        );
+    }
+}
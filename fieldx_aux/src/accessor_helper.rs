@@ -1,6 +1,7 @@
 //! Implementation of accessor helper (`get` argument of `fxstruct`/`fieldx` attributes).
 use crate::FXAttributes;
 use crate::FXBool;
+use crate::FXNestingAttr;
 use crate::FXOrig;
 use crate::FXProp;
 use crate::FXPropBool;
@@ -14,8 +15,12 @@ use darling::FromMeta;
 use fieldx_derive_support::fxhelper;
 use getset::Getters;
 use proc_macro2::TokenStream;
+use quote::quote;
 use quote::ToTokens;
+use syn::parse::Parse;
+use syn::parse::ParseStream;
 use syn::Lit;
+use syn::Meta;
 
 /// Accessor mode defines the type it returns.
 #[derive(FromMeta, Debug, Clone, Copy, Default, PartialEq)]
@@ -26,6 +31,9 @@ pub enum FXAccessorMode {
     Clone,
     /// apply `.as_ref()` method to optional fields. I.e. return `Option<&ValueType>`.
     AsRef,
+    /// Return a reference to the dereferenced value, i.e. `&<ValueType as Deref>::Target`. For example, `&str` for
+    /// `String` fields, or `&[T]` for `Vec<T>`.
+    Deref,
     #[default]
     #[darling(skip)]
     None,
@@ -51,6 +59,11 @@ impl FXAccessorMode {
     pub fn is_as_ref(&self) -> bool {
         matches!(self, Self::AsRef)
     }
+
+    #[inline]
+    pub fn is_deref(&self) -> bool {
+        matches!(self, Self::Deref)
+    }
 }
 
 impl FXSetState for FXAccessorMode {
@@ -59,6 +72,77 @@ impl FXSetState for FXAccessorMode {
     }
 }
 
+/// Projection function of an accessor, as in `map = path::to::fn`, and, optionally, the type it returns, as in
+/// `map(path::to::fn, R)`.
+#[derive(Debug, Clone)]
+pub struct FXAccessorMapArg {
+    func:     syn::Path,
+    ret_type: Option<syn::Type>,
+}
+
+impl FXAccessorMapArg {
+    #[inline]
+    pub fn func(&self) -> &syn::Path {
+        &self.func
+    }
+
+    #[inline]
+    pub fn ret_type(&self) -> Option<&syn::Type> {
+        self.ret_type.as_ref()
+    }
+}
+
+impl Parse for FXAccessorMapArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let func = input.parse()?;
+        let ret_type = if input.is_empty() {
+            None
+        }
+        else {
+            input.parse::<syn::Token![,]>()?;
+            Some(input.parse()?)
+        };
+        Ok(Self { func, ret_type })
+    }
+}
+
+impl FromMeta for FXAccessorMapArg {
+    fn from_meta(item: &Meta) -> darling::Result<Self> {
+        match item {
+            Meta::List(list) => Ok(syn::parse2(list.tokens.clone())?),
+            Meta::NameValue(nv) => match &nv.value {
+                syn::Expr::Path(expr) if expr.qself.is_none() => Ok(Self {
+                    func:     expr.path.clone(),
+                    ret_type: None,
+                }),
+                _ => Err(darling::Error::custom("Expected a path to the projection function").with_span(&nv.value)),
+            },
+            _ => Err(darling::Error::unsupported_format("keyword").with_span(item)),
+        }
+    }
+}
+
+impl FromNestAttr<false> for FXAccessorMapArg {}
+
+impl FXSetState for FXAccessorMapArg {
+    fn is_set(&self) -> FXProp<bool> {
+        FXProp::new(true, None)
+    }
+}
+
+impl ToTokens for FXAccessorMapArg {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let func = &self.func;
+        tokens.extend(quote! { #func });
+        if let Some(ref ret_type) = self.ret_type {
+            tokens.extend(quote! { , #ret_type });
+        }
+    }
+}
+
+/// Projection argument of accessor helpers.
+pub type FXAccessorMap = FXNestingAttr<FXAccessorMapArg, false>;
+
 /// Implement support for accessor attribute argument.
 #[fxhelper(to_tokens)]
 #[derive(Default, Debug)]
@@ -71,6 +155,13 @@ pub struct FXAccessorHelper<const BOOL_ONLY: bool = false> {
     copy:   Option<FXBool>,
     #[fxhelper(exclusive = "accessor mode")]
     as_ref: Option<FXBool>,
+    #[fxhelper(exclusive = "accessor mode")]
+    deref:  Option<FXBool>,
+    /// Projection function. The accessor returns the result of the function applied to a reference to the field
+    /// value.
+    #[fxhelper(exclusive = "accessor mode")]
+    #[getset(get = "pub")]
+    map:    Option<FXAccessorMap>,
 }
 
 impl<const BOOL_ONLY: bool> FXAccessorHelper<BOOL_ONLY> {
@@ -79,6 +170,7 @@ impl<const BOOL_ONLY: bool> FXAccessorHelper<BOOL_ONLY> {
             (self.clone.as_ref(), FXAccessorMode::Clone),
             (self.copy.as_ref(), FXAccessorMode::Copy),
             (self.as_ref.as_ref(), FXAccessorMode::AsRef),
+            (self.deref.as_ref(), FXAccessorMode::Deref),
         ] {
             if let Some(v) = mode.0 {
                 return Some(FXProp::new(
@@ -88,7 +180,10 @@ impl<const BOOL_ONLY: bool> FXAccessorHelper<BOOL_ONLY> {
             }
         }
 
-        None
+        // Projections are applied to the value reference, thus no mode inherited from upper levels must be in effect.
+        self.map
+            .as_ref()
+            .map(|map| FXProp::new(FXAccessorMode::None, map.orig_span()))
     }
 }
 
@@ -0,0 +1,45 @@
+//! Implementation of mutable accessor helper (`get_mut` argument of `fxstruct`/`fieldx` attributes).
+use crate::set_literals;
+use crate::FXAccessorMap;
+use crate::FXAttributes;
+use crate::FXBool;
+use crate::FXOrig;
+use crate::FXProp;
+use crate::FXPropBool;
+use crate::FXSetState;
+use crate::FXString;
+use crate::FXTryInto;
+use crate::FromNestAttr;
+
+use darling::util::Flag;
+use darling::FromMeta;
+use fieldx_derive_support::fxhelper;
+use getset::Getters;
+use proc_macro2::TokenStream;
+use syn::Lit;
+
+#[fxhelper(to_tokens)]
+#[derive(Default, Debug)]
+pub struct FXAccessorMutHelper<const BOOL_ONLY: bool = false> {
+    /// Projection function. The mutable accessor returns the result of the function applied to a mutable reference to
+    /// the field value.
+    #[getset(get = "pub")]
+    map: Option<FXAccessorMap>,
+}
+
+impl<const BOOL_ONLY: bool> FXAccessorMutHelper<BOOL_ONLY> {
+    fn allowed_literals(&self, literals: &[Lit]) -> darling::Result<()> {
+        if BOOL_ONLY {
+            return Err(self.no_literals(literals).unwrap_err());
+        }
+        Ok(())
+    }
+}
+
+impl<const BOOL_ONLY: bool> FromNestAttr for FXAccessorMutHelper<BOOL_ONLY> {
+    set_literals! { accessor_mut, ..1 => name; pre_validate => allowed_literals }
+
+    fn for_keyword(_path: &syn::Path) -> darling::Result<Self> {
+        Ok(Self::default())
+    }
+}
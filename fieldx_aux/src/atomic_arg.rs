@@ -0,0 +1,55 @@
+//! Argument that requests storing a field in an atomic container.
+use super::FromNestAttr;
+use crate::FXProp;
+use crate::FXPropBool;
+use crate::FXSetState;
+use crate::FXSynValue;
+use darling::util::Flag;
+use darling::FromMeta;
+use quote::quote;
+use quote::quote_spanned;
+use quote::ToTokens;
+
+/// Atomic argument. The only sub-argument besides `off` is `ordering` which takes one of the
+/// [`Ordering`](std::sync::atomic::Ordering) variant names.
+#[derive(Debug, Clone, FromMeta)]
+pub struct FXAtomicArg {
+    off:      Flag,
+    ordering: Option<FXSynValue<syn::Ident>>,
+}
+
+impl FXAtomicArg {
+    /// Memory ordering name, if specified.
+    pub fn ordering(&self) -> Option<&syn::Ident> {
+        self.ordering.as_ref().map(|o| o.value())
+    }
+}
+
+impl FXSetState for FXAtomicArg {
+    fn is_set(&self) -> FXProp<bool> {
+        FXProp::from(self.off).not()
+    }
+}
+
+impl FromNestAttr for FXAtomicArg {
+    fn for_keyword(_path: &syn::Path) -> darling::Result<Self> {
+        Ok(Self {
+            off:      Flag::default(),
+            ordering: None,
+        })
+    }
+}
+
+impl ToTokens for FXAtomicArg {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let mut toks = vec![];
+        if self.off.is_present() {
+            let span = self.off.span();
+            toks.push(quote_spanned! {span=> off});
+        }
+        if let Some(ref ordering) = self.ordering {
+            toks.push(ordering.to_token_stream());
+        }
+        tokens.extend(quote! { #(#toks),* });
+    }
+}
@@ -1,15 +1,20 @@
 //! Parameters of builder pattern and builder object.
+use crate::join_token_list;
 use crate::set_literals;
+use crate::to_tokens_vec;
 use crate::validate_no_subarg_at_level;
 use crate::FXAttributes;
 use crate::FXBool;
 use crate::FXDoc;
+use crate::FXMultiple;
+use crate::FXNestingAttr;
 use crate::FXOrig;
 use crate::FXProp;
 use crate::FXPropBool;
 use crate::FXPunctuated;
 use crate::FXSetState;
 use crate::FXString;
+use crate::FXSynTuple;
 use crate::FXSynValue;
 use crate::FXTryInto;
 use crate::FromNestAttr;
@@ -19,8 +24,47 @@ use darling::FromMeta;
 use fieldx_derive_support::fxhelper;
 use getset::Getters;
 use proc_macro2::TokenStream;
+use quote::quote_spanned;
+use quote::ToTokens;
 use syn::Token;
 
+/// Conversion of builder setter arguments with [`TryInto`]. With `defer` conversion errors are not returned by the
+/// setters but are kept by the builder until `build()` reports the first of them.
+#[derive(Default, Debug, FromMeta, Clone)]
+pub struct FXBuilderTryInto {
+    off:   Flag,
+    defer: Option<FXBool>,
+}
+
+impl FXBuilderTryInto {
+    pub fn defer(&self) -> Option<FXProp<bool>> {
+        self.defer.as_ref().map(|d| d.is_set())
+    }
+}
+
+impl FromNestAttr<false> for FXBuilderTryInto {
+    fn for_keyword(_path: &syn::Path) -> darling::Result<Self> {
+        Ok(Self::default())
+    }
+}
+
+impl FXSetState for FXBuilderTryInto {
+    fn is_set(&self) -> FXProp<bool> {
+        FXProp::from(&self.off).not()
+    }
+}
+
+impl ToTokens for FXBuilderTryInto {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let mut toks = vec![];
+        if self.off.is_present() {
+            toks.push(quote_spanned![self.off.span()=> off]);
+        }
+        toks.extend(to_tokens_vec!(self: defer));
+        tokens.extend(join_token_list!(toks));
+    }
+}
+
 // TODO try to issue warnings with `diagnostics` for sub-arguments which are not supported at struct or field level.
 /// Implementation of builder argument.
 #[fxhelper(validate = Self::validate, to_tokens)]
@@ -46,6 +90,11 @@ pub struct FXBuilderHelper<const STRUCT: bool = false> {
     #[getset(get = "pub")]
     into: Option<FXBool>,
 
+    /// Same as `into` but uses the [`TryInto`] trait. Builder setter methods return a [`Result`] with the conversion
+    /// error, unless `try_into(defer)` is used.
+    #[getset(get = "pub")]
+    try_into: Option<FXNestingAttr<FXBuilderTryInto, false>>,
+
     /// Wether builder is required or optional. In `fieldx` it means that for `required` optional fields user must
     /// anyway always provide a value:
     ///
@@ -78,6 +127,17 @@ pub struct FXBuilderHelper<const STRUCT: bool = false> {
     /// Prefix for the builder setter methods.
     prefix: Option<FXString>,
 
+    /// Generate `from_str_map` and `try_set_by_name` methods that set builder fields from string key/value pairs by
+    /// parsing them with [`FromStr`](std::str::FromStr). At field level `from_map(off)` excludes the field, e.g. when
+    /// its type doesn't implement `FromStr`.
+    from_map: Option<FXBool>,
+
+    /// Validation rules to be checked by the `build()` method on the newly constructed object. Each rule is either
+    /// a method name or an expression, followed by the message to report when the rule fails.
+    #[darling(multiple, default)]
+    #[getset(get = "pub")]
+    check: FXMultiple<FXSynTuple<(syn::Expr, syn::LitStr)>>,
+
     /// Documentation for the builder method.
     method_doc: Option<FXDoc>,
 }
@@ -97,6 +157,18 @@ impl<const STRUCT: bool> FXBuilderHelper<STRUCT> {
         self.into.as_ref().map(|i| i.is_set())
     }
 
+    /// Shortcut to the `try_into` parameter.
+    #[inline]
+    pub fn is_try_into(&self) -> Option<FXProp<bool>> {
+        self.try_into.as_ref().map(|i| i.is_set())
+    }
+
+    /// Shortcut to the `defer` sub-argument of `try_into`.
+    #[inline]
+    pub fn is_try_into_defer(&self) -> Option<FXProp<bool>> {
+        self.try_into.as_ref().and_then(|i| i.defer())
+    }
+
     /// Shortcut to the `required` parameter.
     ///
     /// Since it makes sense at both struct and field level `Option` is returned to know exactly if it is set or not.
@@ -155,11 +227,22 @@ impl<const STRUCT: bool> FXBuilderHelper<STRUCT> {
         self.opt_in.as_ref()
     }
 
+    #[inline]
+    pub fn from_map(&self) -> Option<&FXBool> {
+        self.from_map.as_ref()
+    }
+
     #[doc(hidden)]
     pub fn validate(&self) -> darling::Result<()> {
         let mut acc = darling::Error::accumulator();
         if !STRUCT {
             validate_no_subarg_at_level!( self, "builder", "field", acc: error, post_build, opt_in, prefix );
+            if let Some(check) = self.check.first() {
+                acc.push(
+                    darling::Error::custom("builder subargument 'check' is not supported at field level")
+                        .with_span(&check.final_span()),
+                );
+            }
         }
         acc.finish()?;
         Ok(())
@@ -196,11 +279,15 @@ mod tests {
                 attributes_impl( deny(unused) ),
                 default,
                 into,
+                try_into(defer),
                 required,
                 opt_in,
                 post_build( adjust_struct ),
                 error( std::io::Error, std::io::ErrorKind::Other ),
                 prefix( "set_" ),
+                from_map,
+                check(self.is_valid(), "must be valid"),
+                check(validate_all, "validation"),
                 vis(pub(crate)),
                 doc("# Builder", "", "Test doc."),
                 method_doc("# Builder Method", "", "Lorem ipsum.")
@@ -220,11 +307,15 @@ mod tests {
                 attributes_impl( deny(unused) ),
                 default(),
                 into(),
+                try_into(defer()),
                 required(),
                 opt_in(),
                 post_build( adjust_struct ),
                 error( std::io::Error, std::io::ErrorKind::Other ),
                 prefix( "set_" ),
+                from_map(),
+                check(self.is_valid(), "must be valid"),
+                check(validate_all, "validation"),
                 method_doc("# Builder Method", "", "Lorem ipsum.")
             )
         };
@@ -61,17 +61,22 @@
 //! [`darling`]: https://docs.rs/darling
 
 pub mod accessor_helper;
+pub mod accessor_mut_helper;
+pub mod atomic_arg;
 pub mod attributes;
 pub mod base_helper;
 pub mod builder_helper;
 pub mod default_arg;
 pub mod doc_arg;
 pub mod fallible;
+pub mod lock_arg;
+pub mod multiple;
 pub mod nesting_attr;
 pub mod property;
 pub mod serde_helper;
 pub mod setter_helper;
 pub mod syn_value;
+pub mod sync_arg;
 pub mod traits;
 #[doc(hidden)]
 pub mod util;
@@ -79,21 +84,32 @@ pub mod value;
 pub mod with_origin;
 
 pub use crate::accessor_helper::FXAccessorHelper;
+pub use crate::accessor_helper::FXAccessorMap;
+pub use crate::accessor_helper::FXAccessorMapArg;
 pub use crate::accessor_helper::FXAccessorMode;
+pub use crate::accessor_mut_helper::FXAccessorMutHelper;
+pub use crate::atomic_arg::FXAtomicArg;
 pub use crate::attributes::FXAttribute;
 pub use crate::base_helper::FXBaseHelper;
 pub use crate::builder_helper::FXBuilderHelper;
+pub use crate::builder_helper::FXBuilderTryInto;
 pub use crate::default_arg::FXDefault;
 pub use crate::doc_arg::FXDocArg;
 pub use crate::fallible::FXFallible;
+pub use crate::lock_arg::FXLockArg;
+pub use crate::multiple::FXMultiple;
 pub use crate::nesting_attr::FXNestingAttr;
 pub use crate::nesting_attr::FromNestAttr;
 pub use crate::property::*;
 pub use crate::serde_helper::FXSerdeHelper;
+pub use crate::serde_helper::FXSerdeLazy;
+pub use crate::serde_helper::FXSerdeMigration;
+pub use crate::serde_helper::FXSerdeUpdate;
 pub use crate::setter_helper::FXSetterHelper;
 pub use crate::syn_value::FXPunctuated;
 pub use crate::syn_value::FXSynTupleArg;
 pub use crate::syn_value::FXSynValueArg;
+pub use crate::sync_arg::FXSyncArg;
 pub use crate::traits::*;
 pub use crate::value::FXValueArg;
 pub use crate::with_origin::FXOrig;
@@ -202,6 +218,8 @@ pub type FXString = FXNestingAttr<FXValueArg<String>>;
 pub type FXBool = FXNestingAttr<FXValueArg<FXEmpty, true>>;
 /// Accessor helper
 pub type FXAccessor<const BOOL_ONLY: bool = false> = FXNestingAttr<FXAccessorHelper<BOOL_ONLY>>;
+/// Mutable accessor helper
+pub type FXAccessorMut<const BOOL_ONLY: bool = false> = FXNestingAttr<FXAccessorMutHelper<BOOL_ONLY>>;
 /// Setter helper
 pub type FXSetter<const BOOL_ONLY: bool = false> = FXNestingAttr<FXSetterHelper<BOOL_ONLY>>;
 /// Builder helper
@@ -0,0 +1,66 @@
+//! Argument that requests lock-protection of a field.
+use super::FromNestAttr;
+use crate::FXBool;
+use crate::FXProp;
+use crate::FXPropBool;
+use crate::FXSetState;
+use crate::FXString;
+use darling::util::Flag;
+use darling::FromMeta;
+use quote::quote;
+use quote::quote_spanned;
+use quote::ToTokens;
+
+/// Lock argument. By default fields are protected with a read-write lock; `mutex` sub-argument switches to a mutex.
+/// Fields with the same `group` name share a single lock.
+#[derive(Debug, Clone, FromMeta)]
+pub struct FXLockArg {
+    off:   Flag,
+    mutex: Option<FXBool>,
+    group: Option<FXString>,
+}
+
+impl FXLockArg {
+    /// Returns `Some` property if `mutex` sub-argument is used.
+    pub fn is_mutex(&self) -> Option<FXProp<bool>> {
+        self.mutex.as_ref().map(|m| m.is_set())
+    }
+
+    /// Returns the name of the lock group if `group` sub-argument is used.
+    pub fn group(&self) -> Option<&FXString> {
+        self.group.as_ref()
+    }
+}
+
+impl FXSetState for FXLockArg {
+    fn is_set(&self) -> FXProp<bool> {
+        FXProp::from(self.off).not()
+    }
+}
+
+impl FromNestAttr for FXLockArg {
+    fn for_keyword(_path: &syn::Path) -> darling::Result<Self> {
+        Ok(Self {
+            off:   Flag::default(),
+            mutex: None,
+            group: None,
+        })
+    }
+}
+
+impl ToTokens for FXLockArg {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let mut toks = vec![];
+        if self.off.is_present() {
+            let span = self.off.span();
+            toks.push(quote_spanned! {span=> off});
+        }
+        if let Some(ref mutex) = self.mutex {
+            toks.push(mutex.to_token_stream());
+        }
+        if let Some(ref group) = self.group {
+            toks.push(group.to_token_stream());
+        }
+        tokens.extend(quote! { #(#toks),* });
+    }
+}
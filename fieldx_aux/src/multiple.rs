@@ -0,0 +1,54 @@
+//! Support for arguments that can be repeated.
+use quote::quote;
+use quote::ToTokens;
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+/// Container for an argument that can be used more than once, like `check` in `builder(check(...), check(...))`.
+///
+/// Must be used with darling's `multiple` attribute:
+///
+/// ```ignore
+///     #[darling(multiple)]
+///     foo: FXMultiple<FXSynTuple<(syn::Expr, syn::LitStr)>>,
+/// ```
+#[derive(Debug, Clone)]
+pub struct FXMultiple<T> {
+    items: Vec<T>,
+}
+
+impl<T> FXMultiple<T> {
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter()
+    }
+}
+
+impl<T> Default for FXMultiple<T> {
+    fn default() -> Self {
+        Self { items: vec![] }
+    }
+}
+
+impl<T> Deref for FXMultiple<T> {
+    type Target = Vec<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+impl<T> DerefMut for FXMultiple<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.items
+    }
+}
+
+impl<T: ToTokens> ToTokens for FXMultiple<T> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let items = &self.items;
+        tokens.extend(quote! { #( #items ),* });
+    }
+}
@@ -160,6 +160,10 @@ impl<T: FromNestAttr<WITH_LITERALS>, const WITH_LITERALS: bool> FromMeta for FXN
                 path:  None,
                 orig:  item.clone(),
             },
+            // `arg = "literal"` is a shortcut for `arg("literal")`.
+            Meta::NameValue(nv) if T::with_literals() && matches!(nv.value, syn::Expr::Lit(_)) => {
+                Self::from_path_tokens(nv.path.clone(), &nv.value)?
+            }
             _ => Self {
                 inner: T::from_meta(item)?,
                 path:  None,
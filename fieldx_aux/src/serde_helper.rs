@@ -19,6 +19,7 @@ use crate::FXSynValue;
 use crate::FXTrigger;
 use crate::FXTryFrom;
 use crate::FXTryInto;
+use crate::FXValue;
 use crate::FromNestAttr;
 
 use darling::util::Flag;
@@ -26,6 +27,8 @@ use darling::FromMeta;
 use getset::Getters;
 use quote::quote_spanned;
 use quote::ToTokens;
+use syn::parse::Parse;
+use syn::parse::ParseStream;
 use syn::Lit;
 
 #[derive(Default, Debug, FromMeta, Clone)]
@@ -98,6 +101,127 @@ impl ToTokens for FXSerdeRename {
     }
 }
 
+/// How serialization treats lazy fields: `force` initializes them first, `skip_unset` leaves uninitialized ones out.
+#[derive(Default, Debug, FromMeta, Clone)]
+#[darling(and_then = Self::validate)]
+pub struct FXSerdeLazy {
+    force:      Option<FXBool>,
+    skip_unset: Option<FXBool>,
+}
+
+impl FXSerdeLazy {
+    fn validate(self) -> darling::Result<Self> {
+        if let (Some(force), Some(skip_unset)) = (&self.force, &self.skip_unset) {
+            if *force.is_set() && *skip_unset.is_set() {
+                return Err(
+                    darling::Error::custom("Conflicting arguments 'force' and 'skip_unset' cannot be used together")
+                        .with_span(&skip_unset.final_span()),
+                );
+            }
+        }
+        Ok(self)
+    }
+
+    /// `Some(true)` if uninitialized lazy fields are not to be serialized, `Some(false)` if they are to be initialized
+    /// first. `None` if neither mode is set explicitly.
+    pub fn skip_unset(&self) -> Option<FXProp<bool>> {
+        self.skip_unset
+            .as_ref()
+            .map(|s| s.is_set())
+            .filter(|s| **s)
+            .or_else(|| self.force.as_ref().map(|f| f.is_set().not()))
+    }
+}
+
+impl FromNestAttr<false> for FXSerdeLazy {}
+
+impl ToTokens for FXSerdeLazy {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let toks = to_tokens_vec!(self: force, skip_unset);
+        tokens.extend(join_token_list!(toks));
+    }
+}
+
+/// Generation of `update_from_deserializer` method. With `clear_lazy` lazy fields missing in the input are cleared.
+#[derive(Default, Debug, FromMeta, Clone)]
+pub struct FXSerdeUpdate {
+    off:        Flag,
+    clear_lazy: Option<FXBool>,
+}
+
+impl FXSerdeUpdate {
+    pub fn clear_lazy(&self) -> FXProp<bool> {
+        self.clear_lazy
+            .as_ref()
+            .map_or_else(|| FXProp::new(false, None), |c| c.is_set())
+    }
+}
+
+impl FromNestAttr<false> for FXSerdeUpdate {
+    fn for_keyword(_path: &syn::Path) -> darling::Result<Self> {
+        Ok(Self::default())
+    }
+}
+
+impl FXSetState for FXSerdeUpdate {
+    fn is_set(&self) -> FXProp<bool> {
+        FXProp::from(&self.off).not()
+    }
+}
+
+impl ToTokens for FXSerdeUpdate {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let mut toks = vec![];
+        if self.off.is_present() {
+            toks.push(quote_spanned![self.off.span()=> off]);
+        }
+        toks.extend(to_tokens_vec!(self: clear_lazy));
+        tokens.extend(join_token_list!(toks));
+    }
+}
+
+/// A `<version> = <migration function>` entry of `migrate_from`.
+#[derive(Debug, Clone)]
+pub struct FXSerdeMigration {
+    version_lit: syn::LitInt,
+    version:     u32,
+    eq_token:    syn::Token![=],
+    migrate:     syn::Path,
+}
+
+impl FXSerdeMigration {
+    /// The layout version the function migrates from.
+    pub fn version(&self) -> FXProp<u32> {
+        FXProp::new(self.version, Some(self.version_lit.span()))
+    }
+
+    /// Path to the function that takes data of the layout version and returns data of the next supported layout.
+    pub fn migrate(&self) -> &syn::Path {
+        &self.migrate
+    }
+}
+
+impl Parse for FXSerdeMigration {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let version_lit: syn::LitInt = input.parse()?;
+        let version = version_lit.base10_parse()?;
+        Ok(Self {
+            version_lit,
+            version,
+            eq_token: input.parse()?,
+            migrate: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for FXSerdeMigration {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.version_lit.to_tokens(tokens);
+        self.eq_token.to_tokens(tokens);
+        self.migrate.to_tokens(tokens);
+    }
+}
+
 #[derive(Default, Debug, Getters, FromMeta, Clone)]
 #[getset(get = "pub")]
 #[darling(and_then = Self::validate)]
@@ -122,6 +246,20 @@ pub struct FXSerdeHelper<const STRUCT: bool = false> {
     rename:        Option<FXNestingAttr<FXSerdeRename>>,
     #[getset(skip)]
     doc:           Option<FXDoc>,
+    // Serialization mode of lazy fields.
+    lazy:          Option<FXNestingAttr<FXSerdeLazy, false>>,
+    // Layout version of the serialized struct.
+    version:       Option<FXValue<u32>>,
+    // Functions upgrading older layout versions.
+    migrate_from:  Option<FXSynValue<FXPunctuated<FXSerdeMigration, syn::Token![,]>>>,
+    // In-place update of an existing instance.
+    update:        Option<FXNestingAttr<FXSerdeUpdate, false>>,
+    // Lock all fields at once for serialization.
+    snapshot:      Option<FXBool>,
+    // Implement JSON Schema for the struct, with the `schemars` feature.
+    schema:        Option<FXBool>,
+    // Omit unset optional fields from the output.
+    skip_none:     Option<FXBool>,
 }
 
 impl<const STRUCT: bool> FromNestAttr for FXSerdeHelper<STRUCT> {
@@ -151,8 +289,50 @@ impl<const STRUCT: bool> FXSerdeHelper<STRUCT> {
     fn validate(self) -> darling::Result<Self> {
         let mut acc = darling::Error::accumulator();
         if !STRUCT {
-            validate_no_subarg_at_level!(self, "serde", "field", acc: visibility, private, shadow_name);
+            validate_no_subarg_at_level!(
+                self, "serde", "field", acc: visibility, private, shadow_name, version, migrate_from, update, snapshot,
+                schema
+            );
+        }
+        else if let Some(ref migrate_from) = self.migrate_from {
+            let version = self.version.as_ref().and_then(|v| v.value().copied());
+            let mut seen = HashSet::new();
+            if let Some(version) = version {
+                for migration in migrate_from.iter() {
+                    let from = migration.version();
+                    if *from >= version {
+                        acc.push(
+                            darling::Error::custom(format!(
+                                "Can only migrate from versions older than the current one, {version}"
+                            ))
+                            .with_span(&from.final_span()),
+                        );
+                    }
+                    else if !seen.insert(*from) {
+                        acc.push(
+                            darling::Error::custom(format!("Duplicate migration from version {}", *from))
+                                .with_span(&from.final_span()),
+                        );
+                    }
+                }
+            }
+            else {
+                acc.push(
+                    darling::Error::custom("'migrate_from' requires 'version' to be set")
+                        .with_span(&migrate_from.final_span()),
+                );
+            }
+        }
+
+        if let (Some(update), Some(version)) = (&self.update, &self.version) {
+            if *update.is_set() {
+                acc.push(
+                    darling::Error::custom("'update' cannot be used with versioned structs")
+                        .with_span(&version.final_span()),
+                );
+            }
         }
+
         acc.finish()?;
         Ok(self)
     }
@@ -253,6 +433,16 @@ impl<const STRUCT: bool> FXSerdeHelper<STRUCT> {
     pub fn private(&self) -> Option<&FXBool> {
         self.private.as_ref()
     }
+
+    /// Migrations from older layout versions, in the order of ascending versions.
+    pub fn migrations(&self) -> Vec<&FXSerdeMigration> {
+        let mut migrations = self
+            .migrate_from
+            .as_ref()
+            .map_or_else(Vec::new, |mf| mf.iter().collect::<Vec<_>>());
+        migrations.sort_by_key(|m| m.version);
+        migrations
+    }
 }
 
 impl<const STRUCT: bool> ToTokens for FXSerdeHelper<STRUCT> {
@@ -268,9 +458,16 @@ impl<const STRUCT: bool> ToTokens for FXSerdeHelper<STRUCT> {
             deserialize,
             doc,
             forward_attrs,
+            lazy,
+            migrate_from,
             private,
             rename,
+            schema,
             shadow_name,
+            skip_none,
+            snapshot,
+            update,
+            version,
             visibility
         ));
 
@@ -302,7 +499,14 @@ mod tests {
                 shadow_name("FooDup"),
                 forward_attrs(a1, a2, allow),
                 rename("new_name"),
-                doc("line1", "line2")
+                doc("line1", "line2"),
+                lazy(skip_unset),
+                version = 3,
+                migrate_from(1 = Self::from_v1, 2 = crate::from_v2),
+                update(off, clear_lazy),
+                snapshot,
+                schema(off),
+                skip_none
             )
         })
         .unwrap();
@@ -313,8 +517,15 @@ mod tests {
             deserialize(),
             doc("line1", "line2"),
             forward_attrs(a1, a2, allow),
+            lazy(skip_unset()),
+            migrate_from(1 = Self::from_v1, 2 = crate::from_v2),
             rename(serialize("new_name"), deserialize("new_name")),
+            schema(off),
             shadow_name("FooDup"),
+            skip_none(),
+            snapshot(),
+            update(off, clear_lazy()),
+            version(3),
             vis(pub(crate)),
             default(Self::serde_default())
         };
@@ -20,7 +20,13 @@ use syn::Lit;
 #[derive(Default, Debug)]
 pub struct FXSetterHelper<const BOOL_ONLY: bool = false> {
     #[getset(get = "pub")]
-    into: Option<FXBool>,
+    into:     Option<FXBool>,
+    /// Makes the setter accept anything implementing [`TryInto`] for the field type and return a [`Result`].
+    #[getset(get = "pub")]
+    try_into: Option<FXBool>,
+    /// Makes the setter return a reference to `self` instead of the old value, allowing for call chaining.
+    #[getset(get = "pub")]
+    chain:    Option<FXBool>,
 }
 
 impl<const BOOL_ONLY: bool> FXSetterHelper<BOOL_ONLY> {
@@ -35,6 +41,16 @@ impl<const BOOL_ONLY: bool> FXSetterHelper<BOOL_ONLY> {
     pub fn is_into(&self) -> Option<FXProp<bool>> {
         self.into.as_ref().map(|into| into.is_set())
     }
+
+    #[inline]
+    pub fn is_try_into(&self) -> Option<FXProp<bool>> {
+        self.try_into.as_ref().map(|try_into| try_into.is_set())
+    }
+
+    #[inline]
+    pub fn is_chain(&self) -> Option<FXProp<bool>> {
+        self.chain.as_ref().map(|chain| chain.is_set())
+    }
 }
 
 impl<const BOOL_ONLY: bool> FromNestAttr for FXSetterHelper<BOOL_ONLY> {
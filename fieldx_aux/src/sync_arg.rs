@@ -0,0 +1,55 @@
+//! Argument that enables the sync mode of a struct.
+use super::FromNestAttr;
+use crate::FXBool;
+use crate::FXProp;
+use crate::FXPropBool;
+use crate::FXSetState;
+use darling::util::Flag;
+use darling::FromMeta;
+use quote::quote;
+use quote::quote_spanned;
+use quote::ToTokens;
+
+/// Struct-level sync mode argument. With `single_lock` sub-argument all lock-protected fields of the struct share a
+/// single lock.
+#[derive(Debug, Clone, FromMeta)]
+pub struct FXSyncArg {
+    off:         Flag,
+    single_lock: Option<FXBool>,
+}
+
+impl FXSyncArg {
+    /// Returns `Some` property if `single_lock` sub-argument is used.
+    pub fn is_single_lock(&self) -> Option<FXProp<bool>> {
+        self.single_lock.as_ref().map(|s| s.is_set())
+    }
+}
+
+impl FXSetState for FXSyncArg {
+    fn is_set(&self) -> FXProp<bool> {
+        FXProp::from(self.off).not()
+    }
+}
+
+impl FromNestAttr for FXSyncArg {
+    fn for_keyword(_path: &syn::Path) -> darling::Result<Self> {
+        Ok(Self {
+            off:         Flag::default(),
+            single_lock: None,
+        })
+    }
+}
+
+impl ToTokens for FXSyncArg {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let mut toks = vec![];
+        if self.off.is_present() {
+            let span = self.off.span();
+            toks.push(quote_spanned! {span=> off});
+        }
+        if let Some(ref single_lock) = self.single_lock {
+            toks.push(single_lock.to_token_stream());
+        }
+        tokens.extend(quote! { #(#toks),* });
+    }
+}
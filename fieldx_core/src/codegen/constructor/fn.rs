@@ -136,6 +136,10 @@ impl FXFnConstructor {
         self
     }
 
+    pub fn body_mut(&mut self) -> &mut Vec<TokenStream> {
+        &mut self.body
+    }
+
     #[allow(dead_code)]
     pub fn maybe_add_generic(&mut self, generic: Option<TokenStream>) -> &mut Self {
         if let Some(generic) = generic {
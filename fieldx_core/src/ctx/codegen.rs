@@ -10,6 +10,7 @@ use crate::struct_receiver::FXStructReceiver;
 use crate::types::impl_details::impl_async::FXAsyncImplementor;
 use crate::types::impl_details::impl_plain::FXPlainImplementor;
 use crate::types::impl_details::impl_sync::FXSyncImplementor;
+use crate::types::impl_details::impl_sync_std::FXSyncStdImplementor;
 use crate::types::impl_details::FXImplDetails;
 use delegate::delegate;
 use fieldx_aux::FXProp;
@@ -316,7 +317,7 @@ where
     /// The implementation details for the struct are determined by the following rules:
     /// 1. Use [`FXAsyncImplementor`] if the struct-level `async` mode is enabled explicitly.
     /// 2. Use [`FXSyncImplementor`] if the struct-level `sync` mode is enabled explicitly, or if any of the fields is
-    ///    marked as either `sync` or `async`.
+    ///    marked as either `sync` or `async`. With the `sync-std` feature [`FXSyncStdImplementor`] is used instead.
     /// 3. Otherwise, use [`FXPlainImplementor`].
     pub fn impl_details(&self) -> &dyn FXImplDetails<ImplCtx> {
         self.impl_details
@@ -327,7 +328,12 @@ where
                     Box::new(FXAsyncImplementor)
                 }
                 else if arg_props.mode_sync().is_some_and(|p| *p) || *arg_props.syncish() {
-                    Box::new(FXSyncImplementor)
+                    if cfg!(all(feature = "sync-std", not(feature = "sync"))) {
+                        Box::new(FXSyncStdImplementor)
+                    }
+                    else {
+                        Box::new(FXSyncImplementor)
+                    }
                 }
                 else {
                     Box::new(FXPlainImplementor)
@@ -28,6 +28,7 @@ use crate::types::helper::FXHelperKind;
 use crate::types::impl_details::impl_async::FXAsyncImplementor;
 use crate::types::impl_details::impl_plain::FXPlainImplementor;
 use crate::types::impl_details::impl_sync::FXSyncImplementor;
+use crate::types::impl_details::impl_sync_std::FXSyncStdImplementor;
 use crate::types::impl_details::FXImplDetails;
 use crate::types::meta::FXToksMeta;
 use crate::types::FXInlining;
@@ -78,23 +79,35 @@ where
             pub fn accessor_mut_ident(&self) -> &syn::Ident;
             pub fn accessor_mut_visibility(&self) -> &syn::Visibility;
             pub fn accessor_visibility(&self) -> &syn::Visibility;
+            pub fn atomic(&self) -> FXProp<bool>;
+            pub fn atomic_ordering(&self) -> &syn::Ident;
             pub fn builder_ident(&self) -> &syn::Ident;
             pub fn builder(&self) -> FXProp<bool>;
             pub fn builder_required(&self) -> FXProp<bool>;
             pub fn builder_into(&self) -> FXProp<bool>;
+            pub fn builder_try_into(&self) -> FXProp<bool>;
+            pub fn builder_try_into_defer(&self) -> FXProp<bool>;
             pub fn builder_method_visibility(&self) -> &syn::Visibility;
             pub fn builder_method_optional(&self) -> FXProp<bool>;
             pub fn clearer(&self) -> FXProp<bool>;
             pub fn clearer_ident(&self) -> &syn::Ident;
             pub fn clearer_visibility(&self) -> &syn::Visibility;
+            pub fn compare_exchange_ident(&self) -> &syn::Ident;
+            pub fn compare_exchange_visibility(&self) -> &syn::Visibility;
             pub fn default_value(&self) -> Option<&syn::Expr>;
             pub fn fallible(&self) -> FXProp<bool>;
             pub fn fallible_error(&self) -> Option<&syn::Path>;
+            pub fn fetch_add_ident(&self) -> &syn::Ident;
+            pub fn fetch_add_visibility(&self) -> &syn::Visibility;
             pub fn forced_builder(&self) -> FXProp<bool>;
             pub fn inner_mut(&self) -> FXProp<bool>;
             pub fn lazy(&self) -> FXProp<bool>;
             pub fn lazy_ident(&self) -> &syn::Ident;
             pub fn lock(&self) -> FXProp<bool>;
+            pub fn lock_group(&self) -> Option<&syn::Ident>;
+            pub fn lock_ident(&self) -> &syn::Ident;
+            pub fn lock_mutex(&self) -> FXProp<bool>;
+            pub fn lock_visibility(&self) -> &syn::Visibility;
             pub fn mode_async(&self) -> FXProp<bool>;
             pub fn mode_plain(&self) -> FXProp<bool>;
             pub fn mode_sync(&self) -> FXProp<bool>;
@@ -107,8 +120,18 @@ where
             pub fn reader_visibility(&self) -> &syn::Visibility;
             pub fn setter(&self) -> FXProp<bool>;
             pub fn setter_ident(&self) -> &syn::Ident;
+            pub fn setter_chain(&self) -> FXProp<bool>;
             pub fn setter_into(&self) -> FXProp<bool>;
+            pub fn setter_try_into(&self) -> FXProp<bool>;
             pub fn setter_visibility(&self) -> &syn::Visibility;
+            pub fn swap(&self) -> FXProp<bool>;
+            pub fn swap_ident(&self) -> &syn::Ident;
+            pub fn swap_visibility(&self) -> &syn::Visibility;
+            pub fn update_ident(&self) -> &syn::Ident;
+            pub fn update_visibility(&self) -> &syn::Visibility;
+            pub fn with(&self) -> FXProp<bool>;
+            pub fn with_ident(&self) -> &syn::Ident;
+            pub fn with_visibility(&self) -> &syn::Visibility;
             pub fn writer(&self) -> FXProp<bool>;
             pub fn writer_ident(&self) -> &syn::Ident;
             pub fn writer_visibility(&self) -> &syn::Visibility;
@@ -124,6 +147,10 @@ where
             #[cfg(feature = "serde")]
             pub fn serde_default_value(&self) -> Option<&FXDefault>;
             #[cfg(feature = "serde")]
+            pub fn serde_skip_unset(&self) -> FXProp<bool>;
+            #[cfg(feature = "serde")]
+            pub fn serde_skip_none(&self) -> FXProp<bool>;
+            #[cfg(feature = "serde")]
             pub fn serde_attributes(&self) -> Option<&FXAttributes>;
             #[cfg(feature = "serde")]
             pub fn serde_rename_serialize(&self) -> Option<&FXProp<String>>;
@@ -147,7 +174,12 @@ where
             Box::new(FXAsyncImplementor)
         }
         else if *props.mode_sync() {
-            Box::new(FXSyncImplementor)
+            if cfg!(all(feature = "sync-std", not(feature = "sync"))) {
+                Box::new(FXSyncStdImplementor)
+            }
+            else {
+                Box::new(FXSyncImplementor)
+            }
         }
         else {
             Box::new(FXPlainImplementor)
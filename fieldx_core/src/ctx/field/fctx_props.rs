@@ -59,63 +59,88 @@ where
 
     // --- Final helper properties
     // Accessor helper standard properties
-    accessor:                  OnceCell<FXProp<bool>>,
-    accessor_visibility:       OnceCell<syn::Visibility>,
-    accessor_ident:            OnceCell<syn::Ident>,
+    accessor:                    OnceCell<FXProp<bool>>,
+    accessor_visibility:         OnceCell<syn::Visibility>,
+    accessor_ident:              OnceCell<syn::Ident>,
     // Accessor helper specific properties
-    accessor_mode:             OnceCell<FXProp<FXAccessorMode>>,
+    accessor_mode:               OnceCell<FXProp<FXAccessorMode>>,
     // Mutable accessor helper standard properties
-    accessor_mut:              OnceCell<FXProp<bool>>,
-    accessor_mut_visibility:   OnceCell<syn::Visibility>,
-    accessor_mut_ident:        OnceCell<syn::Ident>,
+    accessor_mut:                OnceCell<FXProp<bool>>,
+    accessor_mut_visibility:     OnceCell<syn::Visibility>,
+    accessor_mut_ident:          OnceCell<syn::Ident>,
     // Builder helper standard properties
-    builder:                   OnceCell<FXProp<bool>>,
+    builder:                     OnceCell<FXProp<bool>>,
     /// Visibility of the builder method for this field on the builder object.
-    builder_method_visibility: OnceCell<syn::Visibility>,
-    builder_ident:             OnceCell<syn::Ident>,
+    builder_method_visibility:   OnceCell<syn::Visibility>,
+    builder_ident:               OnceCell<syn::Ident>,
     // Builder helper specific properties
-    builder_into:              OnceCell<FXProp<bool>>,
-    builder_required:          OnceCell<FXProp<bool>>,
+    builder_into:                OnceCell<FXProp<bool>>,
+    builder_try_into:            OnceCell<FXProp<bool>>,
+    builder_try_into_defer:      OnceCell<FXProp<bool>>,
+    builder_required:            OnceCell<FXProp<bool>>,
     // If the field can obtain its value from sources other than the builder, or if it is optional, then calling its
     // builder method is optional.
-    builder_method_optional:   OnceCell<FXProp<bool>>,
+    builder_method_optional:     OnceCell<FXProp<bool>>,
     // Clearer helper standard properties
-    clearer:                   OnceCell<FXProp<bool>>,
-    clearer_visibility:        OnceCell<syn::Visibility>,
-    clearer_ident:             OnceCell<syn::Ident>,
+    clearer:                     OnceCell<FXProp<bool>>,
+    clearer_visibility:          OnceCell<syn::Visibility>,
+    clearer_ident:               OnceCell<syn::Ident>,
     // Predicate helper standard properties
-    predicate:                 OnceCell<FXProp<bool>>,
-    predicate_visibility:      OnceCell<syn::Visibility>,
-    predicate_ident:           OnceCell<syn::Ident>,
+    predicate:                   OnceCell<FXProp<bool>>,
+    predicate_visibility:        OnceCell<syn::Visibility>,
+    predicate_ident:             OnceCell<syn::Ident>,
     // Reader helper standard properties
-    reader:                    OnceCell<FXProp<bool>>,
-    reader_visibility:         OnceCell<syn::Visibility>,
-    reader_ident:              OnceCell<syn::Ident>,
+    reader:                      OnceCell<FXProp<bool>>,
+    reader_visibility:           OnceCell<syn::Visibility>,
+    reader_ident:                OnceCell<syn::Ident>,
     // Setter helper standard properties
-    setter:                    OnceCell<FXProp<bool>>,
-    setter_visibility:         OnceCell<syn::Visibility>,
-    setter_ident:              OnceCell<syn::Ident>,
+    setter:                      OnceCell<FXProp<bool>>,
+    setter_visibility:           OnceCell<syn::Visibility>,
+    setter_ident:                OnceCell<syn::Ident>,
     // Setter helper specific properties
-    setter_into:               OnceCell<FXProp<bool>>,
+    setter_into:                 OnceCell<FXProp<bool>>,
+    setter_try_into:             OnceCell<FXProp<bool>>,
+    setter_chain:                OnceCell<FXProp<bool>>,
+    // With helper standard properties
+    with:                        OnceCell<FXProp<bool>>,
+    with_visibility:             OnceCell<syn::Visibility>,
+    with_ident:                  OnceCell<syn::Ident>,
     // Writer helper standard properties
-    writer:                    OnceCell<FXProp<bool>>,
-    writer_visibility:         OnceCell<syn::Visibility>,
-    writer_ident:              OnceCell<syn::Ident>,
+    writer:                      OnceCell<FXProp<bool>>,
+    writer_visibility:           OnceCell<syn::Visibility>,
+    writer_ident:                OnceCell<syn::Ident>,
     // Lazy helper standard properties
-    lazy:                      OnceCell<FXProp<bool>>,
-    lazy_ident:                OnceCell<syn::Ident>,
+    lazy:                        OnceCell<FXProp<bool>>,
+    lazy_ident:                  OnceCell<syn::Ident>,
+    // Lock helper standard properties
+    lock_visibility:             OnceCell<syn::Visibility>,
+    lock_ident:                  OnceCell<syn::Ident>,
+    // Atomic helpers standard properties
+    compare_exchange_visibility: OnceCell<syn::Visibility>,
+    compare_exchange_ident:      OnceCell<syn::Ident>,
+    fetch_add_visibility:        OnceCell<syn::Visibility>,
+    fetch_add_ident:             OnceCell<syn::Ident>,
+    swap_visibility:             OnceCell<syn::Visibility>,
+    swap_ident:                  OnceCell<syn::Ident>,
+    // Swap field helper standard properties
+    update_visibility:           OnceCell<syn::Visibility>,
+    update_ident:                OnceCell<syn::Ident>,
     // --- Other properties
     // The final base name of the field. Will be used in method name generation.
-    base_name:                 OnceCell<syn::Ident>,
-    fallible:                  OnceCell<FXProp<bool>>,
-    fallible_error:            OnceCell<Option<syn::Path>>,
-    forced_builder:            OnceCell<FXProp<bool>>,
-    inner_mut:                 OnceCell<FXProp<bool>>,
-    lock:                      OnceCell<FXProp<bool>>,
-    mode_async:                OnceCell<FXProp<bool>>,
-    mode_plain:                OnceCell<FXProp<bool>>,
-    mode_sync:                 OnceCell<FXProp<bool>>,
-    optional:                  OnceCell<FXProp<bool>>,
+    atomic:                      OnceCell<FXProp<bool>>,
+    atomic_ordering:             OnceCell<syn::Ident>,
+    base_name:                   OnceCell<syn::Ident>,
+    fallible:                    OnceCell<FXProp<bool>>,
+    fallible_error:              OnceCell<Option<syn::Path>>,
+    forced_builder:              OnceCell<FXProp<bool>>,
+    inner_mut:                   OnceCell<FXProp<bool>>,
+    lock:                        OnceCell<FXProp<bool>>,
+    lock_mutex:                  OnceCell<FXProp<bool>>,
+    mode_async:                  OnceCell<FXProp<bool>>,
+    mode_plain:                  OnceCell<FXProp<bool>>,
+    mode_sync:                   OnceCell<FXProp<bool>>,
+    optional:                    OnceCell<FXProp<bool>>,
+    swap:                        OnceCell<FXProp<bool>>,
 
     #[cfg(feature = "serde")]
     serde:                    OnceCell<FXProp<bool>>,
@@ -127,6 +152,10 @@ where
     /// Field is an Option in the shadow struct if it is optional or lazy and has no default value
     serde_optional:           OnceCell<FXProp<bool>>,
     #[cfg(feature = "serde")]
+    serde_skip_unset:         OnceCell<FXProp<bool>>,
+    #[cfg(feature = "serde")]
+    serde_skip_none:          OnceCell<FXProp<bool>>,
+    #[cfg(feature = "serde")]
     serde_rename_serialize:   OnceCell<Option<FXProp<String>>>,
     #[cfg(feature = "serde")]
     serde_rename_deserialize: OnceCell<Option<FXProp<String>>>,
@@ -168,7 +197,7 @@ where
         lock, FXProp<bool>, default {
             self.reader().or(self.writer()).or(
                 if *self.mode_sync() || *self.mode_async() {
-                    self.inner_mut()
+                    self.inner_mut().or(self.single_lock_member())
                 }
                 else {
                     FXProp::new(false, *self.field_props.field().fieldx_attr_span())
@@ -178,6 +207,8 @@ where
 
         accessor_mut, false;
         builder_into, false;
+        builder_try_into, false;
+        builder_try_into_defer, false;
         builder_required, false;
         clearer, false;
         inner_mut, false;
@@ -186,6 +217,9 @@ where
         reader, false;
         setter, false;
         setter_into, false;
+        setter_try_into, false;
+        setter_chain, false;
+        with, false;
         writer, false;
     }
 
@@ -194,9 +228,15 @@ where
         serde_forward_attrs, Option<&HashSet<syn::Path>>, cloned, as_ref;
     }
 
-    helper_ident_method! { accessor, accessor_mut, clearer, lazy, predicate, reader, setter, writer }
+    helper_ident_method! {
+        accessor, accessor_mut, clearer, compare_exchange, fetch_add, lazy, lock, predicate, reader, setter, swap,
+        update, with, writer
+    }
 
-    helper_visibility_method! { accessor, accessor_mut, clearer, predicate, reader, setter, writer }
+    helper_visibility_method! {
+        accessor, accessor_mut, clearer, compare_exchange, fetch_add, lock, predicate, reader, setter, swap, update,
+        with, writer
+    }
 
     pub fn new(field: FXFieldProps, codegen_ctx: Rc<FXCodeGenCtx<EXTRA>>) -> Self {
         Self {
@@ -215,6 +255,8 @@ where
             builder_method_visibility: OnceCell::new(),
             builder_ident: OnceCell::new(),
             builder_into: OnceCell::new(),
+            builder_try_into: OnceCell::new(),
+            builder_try_into_defer: OnceCell::new(),
             builder_required: OnceCell::new(),
             builder_method_optional: OnceCell::new(),
             clearer: OnceCell::new(),
@@ -230,21 +272,40 @@ where
             setter_visibility: OnceCell::new(),
             setter_ident: OnceCell::new(),
             setter_into: OnceCell::new(),
+            setter_try_into: OnceCell::new(),
+            setter_chain: OnceCell::new(),
+            with: OnceCell::new(),
+            with_visibility: OnceCell::new(),
+            with_ident: OnceCell::new(),
             writer: OnceCell::new(),
             writer_visibility: OnceCell::new(),
             writer_ident: OnceCell::new(),
             lazy: OnceCell::new(),
             lazy_ident: OnceCell::new(),
+            lock_visibility: OnceCell::new(),
+            lock_ident: OnceCell::new(),
+            compare_exchange_visibility: OnceCell::new(),
+            compare_exchange_ident: OnceCell::new(),
+            fetch_add_visibility: OnceCell::new(),
+            fetch_add_ident: OnceCell::new(),
+            swap_visibility: OnceCell::new(),
+            swap_ident: OnceCell::new(),
+            update_visibility: OnceCell::new(),
+            update_ident: OnceCell::new(),
+            atomic: OnceCell::new(),
+            atomic_ordering: OnceCell::new(),
             base_name: OnceCell::new(),
             fallible: OnceCell::new(),
             fallible_error: OnceCell::new(),
             forced_builder: OnceCell::new(),
             inner_mut: OnceCell::new(),
             lock: OnceCell::new(),
+            lock_mutex: OnceCell::new(),
             mode_async: OnceCell::new(),
             mode_plain: OnceCell::new(),
             mode_sync: OnceCell::new(),
             optional: OnceCell::new(),
+            swap: OnceCell::new(),
 
             #[cfg(feature = "serde")]
             serde: OnceCell::new(),
@@ -255,6 +316,10 @@ where
             #[cfg(feature = "serde")]
             serde_optional: OnceCell::new(),
             #[cfg(feature = "serde")]
+            serde_skip_unset: OnceCell::new(),
+            #[cfg(feature = "serde")]
+            serde_skip_none: OnceCell::new(),
+            #[cfg(feature = "serde")]
             serde_rename_serialize: OnceCell::new(),
             #[cfg(feature = "serde")]
             serde_rename_deserialize: OnceCell::new(),
@@ -397,6 +462,77 @@ where
         })
     }
 
+    // Mutex is only meaningful for lock-protected fields. Field-level `lock(mutex)` takes precedence over the
+    // struct-level one. Lock groups are always protected by a read-write lock.
+    pub fn lock_mutex(&self) -> FXProp<bool> {
+        *self.lock_mutex.get_or_init(|| {
+            let lock = self.lock();
+            if let Some(group) = self.lock_group() {
+                FXProp::new(false, Some(group.span()))
+            }
+            else if *lock {
+                self.field_props()
+                    .lock_mutex()
+                    .or_else(|| self.arg_props().lock_mutex())
+                    .unwrap_or_else(|| FXProp::new(false, lock.orig_span()))
+            }
+            else {
+                lock
+            }
+        })
+    }
+
+    // Besides explicitly declared groups, lock-protected fields of a single-lock struct belong to its implicit group.
+    pub fn lock_group(&self) -> Option<&syn::Ident> {
+        self.field_props().lock_group().or_else(|| {
+            if *self.lock() && *self.single_lock_member() {
+                self.arg_props().single_lock_group()
+            }
+            else {
+                None
+            }
+        })
+    }
+
+    // Fields of a single-lock struct share the struct lock unless they are lock-free or skipped.
+    fn single_lock_member(&self) -> FXProp<bool> {
+        let single_lock = self.arg_props().single_lock();
+        if *single_lock && !*self.atomic() && !*self.swap() && !*self.field_props().skipped() {
+            single_lock
+        }
+        else {
+            FXProp::new(false, *self.field_props.field().fieldx_attr_span())
+        }
+    }
+
+    // Atomic mode is field-level only.
+    pub fn atomic(&self) -> FXProp<bool> {
+        *self.atomic.get_or_init(|| {
+            self.field_props()
+                .atomic()
+                .unwrap_or_else(|| FXProp::new(false, *self.field_props.field().fieldx_attr_span()))
+        })
+    }
+
+    // Memory ordering of atomic operations, `SeqCst` unless specified otherwise.
+    pub fn atomic_ordering(&self) -> &syn::Ident {
+        self.atomic_ordering.get_or_init(|| {
+            self.field_props()
+                .atomic_ordering()
+                .cloned()
+                .unwrap_or_else(|| syn::Ident::new("SeqCst", self.atomic().final_span()))
+        })
+    }
+
+    // Swap mode is field-level only.
+    pub fn swap(&self) -> FXProp<bool> {
+        *self.swap.get_or_init(|| {
+            self.field_props()
+                .swap()
+                .unwrap_or_else(|| FXProp::new(false, *self.field_props.field().fieldx_attr_span()))
+        })
+    }
+
     // Fallible is specific because it can be enabled on the field level, but the error type can be defined on the
     // struct level.
     pub fn fallible(&self) -> FXProp<bool> {
@@ -427,7 +563,7 @@ where
     // To determine the final sync mode of the field, we take into consideration:
     //
     // 1. The field-level arguments by checking the field receiver's syncish status. This will give us dependency on the
-    //    field's sync, async, !plain, lock, reader, and writer arguments directly.
+    //    field's sync, async, !plain, lock, atomic, swap, reader, and writer arguments directly.
     // 2. The same struct-level arguments. Note that we don't use the struct-level syncish because it relies on the sync
     //    modes of the struct's fields.
     pub fn mode_sync(&self) -> FXProp<bool> {
@@ -437,6 +573,8 @@ where
                 .mode_sync()
                 .or_else(|| field_props.mode_plain().not())
                 .or_else(|| field_props.lock())
+                .or_else(|| field_props.atomic())
+                .or_else(|| field_props.swap())
                 .or_else(|| field_props.reader().or(field_props.writer()))
                 .or_else(|| {
                     let arg_props = self.arg_props();
@@ -514,6 +652,40 @@ where
         self.field_props.serde_default_value()
     }
 
+    /// Uninitialized lazy field is not to be initialized for serialization and is omitted from the output.
+    #[cfg(feature = "serde")]
+    pub fn serde_skip_unset(&self) -> FXProp<bool> {
+        *self.serde_skip_unset.get_or_init(|| {
+            let lazy = self.lazy();
+            if *lazy {
+                self.field_props()
+                    .serde_lazy_skip_unset()
+                    .or_else(|| self.arg_props().serde_lazy_skip_unset())
+                    .unwrap_or_else(|| FXProp::new(false, None))
+            }
+            else {
+                lazy
+            }
+        })
+    }
+
+    /// Unset optional field is omitted from the output and defaults to `None` when missing from the input.
+    #[cfg(feature = "serde")]
+    pub fn serde_skip_none(&self) -> FXProp<bool> {
+        *self.serde_skip_none.get_or_init(|| {
+            let optional = self.optional();
+            if *optional && !*self.lazy() {
+                self.field_props()
+                    .serde_skip_none()
+                    .or_else(|| self.arg_props().serde_skip_none())
+                    .unwrap_or_else(|| FXProp::new(false, None))
+            }
+            else {
+                FXProp::new(false, None)
+            }
+        })
+    }
+
     #[cfg(feature = "serde")]
     #[inline(always)]
     pub fn serde_attributes(&self) -> Option<&FXAttributes> {
@@ -6,11 +6,14 @@ use darling::FromMeta;
 use fieldx_aux::to_tokens_vec;
 use fieldx_aux::validate_exclusives;
 use fieldx_aux::FXAccessor;
+use fieldx_aux::FXAccessorMut;
+use fieldx_aux::FXAtomicArg;
 use fieldx_aux::FXAttributes;
 use fieldx_aux::FXBool;
 use fieldx_aux::FXBuilder;
 use fieldx_aux::FXDefault;
 use fieldx_aux::FXFallible;
+use fieldx_aux::FXLockArg;
 use fieldx_aux::FXHelper;
 use fieldx_aux::FXNestingAttr;
 use fieldx_aux::FXOrig;
@@ -61,9 +64,10 @@ pub struct FXFieldReceiver {
     #[darling(rename = "get")]
     accessor:      Option<FXAccessor>,
     #[darling(rename = "get_mut")]
-    accessor_mut:  Option<FXHelper>,
+    accessor_mut:  Option<FXAccessorMut>,
     #[darling(rename = "set")]
     setter:        Option<FXSetter>,
+    with:          Option<FXHelper>,
     reader:        Option<FXHelper>,
     writer:        Option<FXHelper>,
     clearer:       Option<FXHelper>,
@@ -81,7 +85,9 @@ pub struct FXFieldReceiver {
     clone:         Option<FXBool>,
     #[getset(get = "pub with_prefix")]
     copy:          Option<FXBool>,
-    lock:          Option<FXBool>,
+    lock:          Option<FXNestingAttr<FXLockArg>>,
+    atomic:        Option<FXNestingAttr<FXAtomicArg>>,
+    swap:          Option<FXBool>,
     inner_mut:     Option<FXBool>,
     serde:         Option<FXSerde>,
 
@@ -167,6 +173,7 @@ impl FXFieldReceiver {
         "field mode":  lazy; optional;
         "concurrency mode": mode_sync as "sync"; mode_async as "async"; mode;
         "visibility": private; visibility as "vis";
+        "lock-free access": atomic; swap; lazy, optional, lock, inner_mut, reader, writer, clearer, predicate;
     }
 
     // Generate field-level needs_<helper> methods. The final decision of what's needed and what's not is done by
@@ -188,7 +195,20 @@ impl FXFieldReceiver {
             );
         }
 
-        #[cfg(not(feature = "sync"))]
+        if let Some(group) = self.lock.as_ref().and_then(|l| l.group()) {
+            let span = group.final_span();
+            if group.value().map_or(true, |g| syn::parse_str::<syn::Ident>(g).is_err()) {
+                acc.push(darling::Error::custom("Lock group name must be a valid identifier").with_span(&span));
+            }
+            if self.lock.as_ref().and_then(|l| l.is_mutex()).is_some_and(|m| *m) {
+                acc.push(darling::Error::custom("Lock groups are always protected by a read-write lock").with_span(&span));
+            }
+            if self.lazy.as_ref().is_some_and(|l| *l.is_set()) {
+                acc.push(darling::Error::custom("Lazy fields cannot be members of a lock group").with_span(&span));
+            }
+        }
+
+        #[cfg(not(any(feature = "sync", feature = "sync-std")))]
         if let Some(err) = crate::util::feature_required("sync", &self.mode_sync) {
             acc.push(err);
         }
@@ -203,6 +223,11 @@ impl FXFieldReceiver {
             acc.push(err);
         }
 
+        #[cfg(not(feature = "arc-swap"))]
+        if let Some(err) = crate::util::feature_required("arc-swap", &self.swap) {
+            acc.push(err);
+        }
+
         acc.finish()?;
 
         Ok(())
@@ -287,6 +312,7 @@ impl FXFieldReceiver {
             accessor,
             accessor_mut,
             setter,
+            with,
             reader,
             writer,
             clearer,
@@ -294,6 +320,8 @@ impl FXFieldReceiver {
             clone,
             copy,
             lock,
+            atomic,
+            swap,
             inner_mut,
             optional,
             visibility,
@@ -319,7 +347,7 @@ mod tests {
         let mode_arg = if cfg!(feature = "async") {
             quote! { r#async }
         }
-        else if cfg!(feature = "sync") {
+        else if cfg!(any(feature = "sync", feature = "sync-std")) {
             quote! { sync }
         }
         else {
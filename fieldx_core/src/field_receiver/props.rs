@@ -8,6 +8,7 @@ use crate::util::common_prop_impl;
 use crate::util::mode_async_prop;
 use crate::util::mode_plain_prop;
 use crate::util::mode_sync_prop;
+use fieldx_aux::FXAccessorMap;
 use fieldx_aux::FXAccessorMode;
 use fieldx_aux::FXAttributes;
 #[cfg(feature = "serde")]
@@ -47,6 +48,7 @@ pub struct FXFieldProps {
     builder_ident:           OnceCell<Option<syn::Ident>>,
     // Builder helper extended properties
     builder_into:            OnceCell<Option<FXProp<bool>>>,
+    builder_try_into:        OnceCell<Option<FXProp<bool>>>,
     builder_required:        OnceCell<Option<FXProp<bool>>>,
     builder_doc:             OnceCell<Option<FXProp<Vec<syn::LitStr>>>>,
     // Corresponding builder field attributes
@@ -75,7 +77,14 @@ pub struct FXFieldProps {
     setter_ident:            OnceCell<Option<syn::Ident>>,
     // Setter helper extended properties
     setter_into:             OnceCell<Option<FXProp<bool>>>,
+    setter_try_into:         OnceCell<Option<FXProp<bool>>>,
+    setter_chain:            OnceCell<Option<FXProp<bool>>>,
     setter_doc:              OnceCell<Option<FXProp<Vec<syn::LitStr>>>>,
+    // With helper standard properties
+    with:                    OnceCell<Option<FXProp<bool>>>,
+    with_visibility:         OnceCell<Option<syn::Visibility>>,
+    with_ident:              OnceCell<Option<syn::Ident>>,
+    with_doc:                OnceCell<Option<FXProp<Vec<syn::LitStr>>>>,
     // Writer helper standard properties
     writer:                  OnceCell<Option<FXProp<bool>>>,
     writer_visibility:       OnceCell<Option<syn::Visibility>>,
@@ -88,11 +97,16 @@ pub struct FXFieldProps {
     lazy_ident:              OnceCell<Option<syn::Ident>>,
     // --- Other properties
     // Base name of the field. Normally would be the same as the field name.
+    atomic:                  OnceCell<Option<FXProp<bool>>>,
+    atomic_ordering:         OnceCell<Option<syn::Ident>>,
+    swap:                    OnceCell<Option<FXProp<bool>>>,
     base_name:               OnceCell<Option<syn::Ident>>,
     fallible:                OnceCell<Option<FXProp<FXFallible>>>,
     inner_mut:               OnceCell<Option<FXProp<bool>>>,
     into:                    OnceCell<Option<FXProp<bool>>>,
     lock:                    OnceCell<Option<FXProp<bool>>>,
+    lock_mutex:              OnceCell<Option<FXProp<bool>>>,
+    lock_group:              OnceCell<Option<syn::Ident>>,
     mode_async:              OnceCell<Option<FXProp<bool>>>,
     mode_plain:              OnceCell<Option<FXProp<bool>>>,
     mode_sync:               OnceCell<Option<FXProp<bool>>>,
@@ -122,7 +136,7 @@ pub struct FXFieldProps {
 
 impl FXFieldProps {
     common_prop_impl! {
-        accessor, accessor_mut, builder, setter, clearer, predicate, reader, writer, lazy
+        accessor, accessor_mut, builder, setter, with, clearer, predicate, reader, writer, lazy
     }
 
     doc_props! {
@@ -133,6 +147,7 @@ impl FXFieldProps {
         predicate_doc from predicate.doc;
         reader_doc from reader.doc;
         setter_doc from setter.doc;
+        with_doc from with.doc;
         writer_doc from writer.doc;
     }
 
@@ -158,6 +173,7 @@ impl FXFieldProps {
             builder_visibility:      OnceCell::new(),
             builder_ident:           OnceCell::new(),
             builder_into:            OnceCell::new(),
+            builder_try_into:        OnceCell::new(),
             builder_required:        OnceCell::new(),
             builder_doc:             OnceCell::new(),
             clearer:                 OnceCell::new(),
@@ -176,7 +192,13 @@ impl FXFieldProps {
             setter_visibility:       OnceCell::new(),
             setter_ident:            OnceCell::new(),
             setter_into:             OnceCell::new(),
+            setter_try_into:         OnceCell::new(),
+            setter_chain:            OnceCell::new(),
             setter_doc:              OnceCell::new(),
+            with:                    OnceCell::new(),
+            with_visibility:         OnceCell::new(),
+            with_ident:              OnceCell::new(),
+            with_doc:                OnceCell::new(),
             writer:                  OnceCell::new(),
             writer_visibility:       OnceCell::new(),
             writer_ident:            OnceCell::new(),
@@ -184,11 +206,16 @@ impl FXFieldProps {
             lazy:                    OnceCell::new(),
             lazy_visibility:         OnceCell::new(),
             lazy_ident:              OnceCell::new(),
+            atomic:                  OnceCell::new(),
+            atomic_ordering:         OnceCell::new(),
+            swap:                    OnceCell::new(),
             base_name:               OnceCell::new(),
             fallible:                OnceCell::new(),
             inner_mut:               OnceCell::new(),
             into:                    OnceCell::new(),
             lock:                    OnceCell::new(),
+            lock_mutex:              OnceCell::new(),
+            lock_group:              OnceCell::new(),
             mode_async:              OnceCell::new(),
             mode_plain:              OnceCell::new(),
             mode_sync:               OnceCell::new(),
@@ -222,7 +249,7 @@ impl FXFieldProps {
         &self.source
     }
 
-    // Returns a true FXProp only if either `lock`, `writer`, or `reader` is set.
+    // Returns a true FXProp only if either `lock`, `atomic`, `swap`, `writer`, or `reader` is set.
     // Otherwise, returns `None`.
     //
     // Note that this is a weak mode, meaning that it is inferred from the field's arguments only and doesn't take into
@@ -233,6 +260,8 @@ impl FXFieldProps {
                 .or(self.mode_async())
                 .or(self.mode_plain().not())
                 .or(self.lock())
+                .or(self.atomic())
+                .or(self.swap())
                 .or(self.reader())
                 .or(self.writer())
                 .unwrap_or(FXProp::new(false, None))
@@ -246,9 +275,15 @@ impl FXFieldProps {
             FXHelperKind::Builder => self.builder_ident(),
             FXHelperKind::Clearer => self.clearer_ident(),
             FXHelperKind::Lazy => self.lazy_ident(),
+            FXHelperKind::Lock
+            | FXHelperKind::CompareExchange
+            | FXHelperKind::FetchAdd
+            | FXHelperKind::Swap
+            | FXHelperKind::Update => None,
             FXHelperKind::Predicate => self.predicate_ident(),
             FXHelperKind::Reader => self.reader_ident(),
             FXHelperKind::Setter => self.setter_ident(),
+            FXHelperKind::With => self.with_ident(),
             FXHelperKind::Writer => self.writer_ident(),
         }
     }
@@ -268,6 +303,55 @@ impl FXFieldProps {
             .as_ref()
     }
 
+    pub fn atomic(&self) -> Option<FXProp<bool>> {
+        *self
+            .atomic
+            .get_or_init(|| self.source.atomic().as_ref().map(|a| a.is_set()))
+    }
+
+    pub fn atomic_ordering(&self) -> Option<&syn::Ident> {
+        self.atomic_ordering
+            .get_or_init(|| self.source.atomic().as_ref().and_then(|a| a.ordering()).cloned())
+            .as_ref()
+    }
+
+    pub fn swap(&self) -> Option<FXProp<bool>> {
+        *self.swap.get_or_init(|| self.source.swap().as_ref().map(|s| s.is_set()))
+    }
+
+    // Lock groups are field-level only.
+    pub fn lock_group(&self) -> Option<&syn::Ident> {
+        self.lock_group
+            .get_or_init(|| {
+                self.source.lock().as_ref().and_then(|l| l.group()).and_then(|g| {
+                    g.value()
+                        .and_then(|name| syn::parse_str::<syn::Ident>(name).ok())
+                        .map(|mut ident| {
+                            ident.set_span(g.final_span());
+                            ident
+                        })
+                })
+            })
+            .as_ref()
+    }
+
+    pub fn accessor_map(&self) -> Option<&FXAccessorMap> {
+        self.source.accessor().as_ref().and_then(|a| a.map().as_ref())
+    }
+
+    pub fn accessor_mut_map(&self) -> Option<&FXAccessorMap> {
+        self.source.accessor_mut().as_ref().and_then(|a| a.map().as_ref())
+    }
+
+    // Struct-level `from_map` enables the feature, field-level can only opt out of it.
+    pub fn builder_from_map(&self) -> Option<FXProp<bool>> {
+        self.source
+            .builder()
+            .as_ref()
+            .and_then(|b| b.from_map())
+            .map(|fm| fm.is_set())
+    }
+
     pub fn builder_attributes(&self) -> Option<&FXAttributes> {
         self.builder_attributes
             .get_or_init(|| self.source.builder().as_ref().and_then(|b| b.attributes()).cloned())
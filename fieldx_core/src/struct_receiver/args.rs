@@ -6,10 +6,12 @@ use fieldx_aux::to_tokens_vec;
 use fieldx_aux::validate_exclusives;
 use fieldx_aux::validate_no_subarg_at_level;
 use fieldx_aux::FXAccessor;
+use fieldx_aux::FXAccessorMut;
 use fieldx_aux::FXAttributes;
 use fieldx_aux::FXBool;
 use fieldx_aux::FXBuilder;
 use fieldx_aux::FXFallible;
+use fieldx_aux::FXLockArg;
 use fieldx_aux::FXHelper;
 use fieldx_aux::FXHelperTrait;
 use fieldx_aux::FXNestingAttr;
@@ -18,6 +20,7 @@ use fieldx_aux::FXSerde;
 use fieldx_aux::FXSetState;
 use fieldx_aux::FXSetter;
 use fieldx_aux::FXSynValue;
+use fieldx_aux::FXSyncArg;
 use fieldx_aux::FXSyncMode;
 use getset::Getters;
 use proc_macro2::TokenStream;
@@ -33,19 +36,25 @@ pub struct FXStructArgs {
     mode:       Option<FXSynValue<FXSyncMode>>,
     #[getset(skip)]
     #[darling(rename = "sync")]
-    mode_sync:  Option<FXBool>,
+    mode_sync:  Option<FXNestingAttr<FXSyncArg>>,
     #[getset(skip)]
     #[darling(rename = "r#async")]
     mode_async: Option<FXBool>,
 
-    builder: Option<FXBuilder<true>>,
-    into:    Option<FXBool>,
+    builder:       Option<FXBuilder<true>>,
+    into:          Option<FXBool>,
 
-    no_new:  Option<FXBool>,
-    new:     Option<FXHelper>,
-    default: Option<FXBool>,
+    no_new:        Option<FXBool>,
+    new:           Option<FXHelper>,
+    default:       Option<FXBool>,
     // Produce reference counted object; i.e. Rc<Self> or Arc<Self>.
-    rc:      Option<FXHelper>,
+    rc:            Option<FXHelper>,
+    // Generate a method to update lock-protected fields under a single set of locks.
+    transaction:   Option<FXHelper>,
+    // Track per-field versions and dirty state.
+    track_changes: Option<FXBool>,
+    // Generate a patch struct and a method to apply it.
+    patch:         Option<FXHelper>,
 
     attributes:      Option<FXAttributes>,
     attributes_fn:   Option<FXAttributes>,
@@ -57,9 +66,10 @@ pub struct FXStructArgs {
     #[darling(rename = "get")]
     accessor:     Option<FXAccessor>,
     #[darling(rename = "get_mut")]
-    accessor_mut: Option<FXHelper>,
+    accessor_mut: Option<FXAccessorMut>,
     #[darling(rename = "set")]
     setter:       Option<FXSetter>,
+    with:         Option<FXHelper>,
     reader:       Option<FXHelper>,
     writer:       Option<FXHelper>,
     clearer:      Option<FXHelper>,
@@ -72,7 +82,7 @@ pub struct FXStructArgs {
     clone:        Option<FXBool>,
     #[getset(get = "pub with_prefix")]
     copy:         Option<FXBool>,
-    lock:         Option<FXBool>,
+    lock:         Option<FXNestingAttr<FXLockArg>>,
     inner_mut:    Option<FXBool>,
     serde:        Option<FXSerde<true>>,
 }
@@ -82,7 +92,6 @@ impl FXStructArgs {
         "accessor mode": copy; clone;
         "concurrency mode": mode_sync as "sync", mode_async as "r#async"; mode;
         "field mode": lazy; optional;
-        "serde/ref.counting": serde; rc;
         "visibility": private; visibility as "vis";
     );
 
@@ -97,20 +106,39 @@ impl FXStructArgs {
         validate_no_subarg_at_level! {
             self, "struct", acc:
                 accessor as get.doc,
+                accessor as get.map,
                 accessor_mut as get_mut.doc,
+                accessor_mut as get_mut.map,
                 clearer.doc,
                 predicate.doc,
                 reader.doc,
                 setter.doc,
+                with.doc,
                 writer.doc,
                 lazy.doc
         }
 
-        #[cfg(not(feature = "sync"))]
+        if let Some(group) = self.lock.as_ref().and_then(|l| l.group()) {
+            acc.push(
+                darling::Error::custom("Lock groups can only be declared on fields").with_span(&group.final_span()),
+            );
+        }
+
+        #[cfg(not(any(feature = "sync", feature = "sync-std")))]
         if let Some(err) = crate::util::feature_required("sync", &self.mode_sync) {
             acc.push(err);
         }
 
+        #[cfg(all(feature = "sync-std", not(feature = "sync")))]
+        if let Some(single_lock) = self.mode_sync.as_ref().and_then(|s| s.is_single_lock()) {
+            if *single_lock {
+                acc.push(
+                    darling::Error::custom("single_lock is not supported by the sync-std backend")
+                        .with_span(&single_lock.final_span()),
+                );
+            }
+        }
+
         #[cfg(not(feature = "async"))]
         if let Some(err) = crate::util::feature_required("async", &self.mode_async) {
             acc.push(err);
@@ -121,6 +149,15 @@ impl FXStructArgs {
             acc.push(err);
         }
 
+        #[cfg(all(feature = "serde", not(feature = "schemars")))]
+        if let Some(err) = self
+            .serde
+            .as_ref()
+            .and_then(|serde| crate::util::feature_required("schemars", serde.schema()))
+        {
+            acc.push(err);
+        }
+
         acc.finish()?;
 
         Ok(self)
@@ -137,7 +174,13 @@ impl FXStructArgs {
             let is_mode_sync = self.mode_sync.is_set();
             let is_mode_async = self.mode_async.is_set();
             if *is_mode_sync {
-                toks.push(quote_spanned! {is_mode_sync.final_span()=> mode(sync)});
+                // `mode(sync)` has no room for sub-arguments.
+                if self.mode_sync.as_ref().and_then(|s| s.is_single_lock()).is_some() {
+                    toks.push(self.mode_sync.to_token_stream());
+                }
+                else {
+                    toks.push(quote_spanned! {is_mode_sync.final_span()=> mode(sync)});
+                }
             }
             else if *is_mode_async {
                 toks.push(quote_spanned! {is_mode_async.final_span()=> mode(async)});
@@ -156,10 +199,10 @@ impl FXStructArgs {
 
         toks.extend(to_tokens_vec!(self:
             builder,
-            into, default, rc,
+            into, default, rc, transaction, track_changes, patch,
             attributes, attributes_fn, attributes_impl,
             fallible, lazy, accessor, accessor_mut,
-            setter, reader, writer, clearer,
+            setter, with, reader, writer, clearer,
             predicate, optional, visibility,
             private, clone, copy, lock,
             inner_mut, serde
@@ -195,6 +238,9 @@ mod tests {
                 into,
                 default(off),
                 rc,
+                transaction("tx"),
+                track_changes,
+                patch("update"),
                 attributes( third_party(1,2,3) ),
                 attributes_fn( deny(unused) ),
                 attributes_impl( deny(unused) ),
@@ -202,7 +248,8 @@ mod tests {
                 lazy(off),
                 get("get_"),
                 get_mut,
-                set,
+                set(chain, try_into),
+                with(off),
                 reader(off),
                 writer(off),
                 clearer(off),
@@ -228,6 +275,9 @@ mod tests {
                 into(),
                 default(off),
                 rc(),
+                transaction(name("tx")),
+                track_changes(),
+                patch(name("update")),
                 attributes(third_party(1, 2, 3)),
                 attributes_fn(deny(unused)),
                 attributes_impl(deny(unused)),
@@ -235,7 +285,8 @@ mod tests {
                 lazy(off),
                 get(name("get_")),
                 get_mut(),
-                set(),
+                set(try_into(), chain()),
+                with(off),
                 reader(off),
                 writer(off),
                 clearer(off),
@@ -252,4 +303,21 @@ mod tests {
 
         assert_eq!(args.to_token_stream().to_string(), expected.to_string());
     }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_single_lock_roundtrip() {
+        let input: syn::Meta = syn::parse2(quote! {
+            fxstruct(sync(single_lock), get)
+        })
+        .unwrap();
+
+        let args = FXStructArgs::from_meta(&input).unwrap();
+
+        let expected = quote! {
+            fxstruct(sync(single_lock()), get())
+        };
+
+        assert_eq!(args.to_token_stream().to_string(), expected.to_string());
+    }
 }
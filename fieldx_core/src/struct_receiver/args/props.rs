@@ -20,13 +20,17 @@ use fieldx_aux::FXAccessorMode;
 use fieldx_aux::FXAttributes;
 #[cfg(feature = "serde")]
 use fieldx_aux::FXDefault;
+#[cfg(feature = "serde")]
+use fieldx_aux::FXSerdeMigration;
 use fieldx_aux::FXFallible;
 use fieldx_aux::FXHelperTrait;
+use fieldx_aux::FXMultiple;
 use fieldx_aux::FXOrig;
 use fieldx_aux::FXProp;
 use fieldx_aux::FXPropBool;
 use fieldx_aux::FXSetState;
 use fieldx_aux::FXSpaned;
+use fieldx_aux::FXSynTuple;
 use fieldx_aux::FXTrigger;
 use once_cell::unsync::OnceCell;
 use quote::format_ident;
@@ -59,8 +63,10 @@ where
     builder_ident:                  OnceCell<syn::Ident>,
     // Builder helper extended properties
     builder_default:                OnceCell<FXProp<bool>>,
+    builder_from_map:               OnceCell<FXProp<bool>>,
     builder_doc:                    OnceCell<Option<FXProp<Vec<syn::LitStr>>>>,
     builder_into:                   OnceCell<Option<FXProp<bool>>>,
+    builder_try_into:               OnceCell<Option<FXProp<bool>>>,
     builder_method_doc:             OnceCell<Option<FXProp<Vec<syn::LitStr>>>>,
     builder_opt_in:                 OnceCell<FXProp<bool>>,
     builder_prefix:                 OnceCell<Option<syn::Ident>>,
@@ -86,6 +92,9 @@ where
     setter:                         OnceCell<Option<FXProp<bool>>>,
     setter_visibility:              OnceCell<Option<syn::Visibility>>,
     setter_ident:                   OnceCell<Option<syn::Ident>>,
+    with:                           OnceCell<Option<FXProp<bool>>>,
+    with_visibility:                OnceCell<Option<syn::Visibility>>,
+    with_ident:                     OnceCell<Option<syn::Ident>>,
     // Writer helper standard properties
     writer:                         OnceCell<Option<FXProp<bool>>>,
     writer_visibility:              OnceCell<Option<syn::Visibility>>,
@@ -98,6 +107,20 @@ where
     rc:                             OnceCell<FXProp<bool>>,
     rc_visibility:                  OnceCell<Option<syn::Visibility>>,
     rc_doc:                         OnceCell<Option<FXProp<Vec<syn::LitStr>>>>,
+    // Transaction helper properties
+    transaction:                    OnceCell<FXProp<bool>>,
+    transaction_visibility:         OnceCell<syn::Visibility>,
+    transaction_ident:              OnceCell<Option<syn::Ident>>,
+    transaction_struct_ident:       OnceCell<syn::Ident>,
+    transaction_doc:                OnceCell<Option<FXProp<Vec<syn::LitStr>>>>,
+    // Change tracking properties
+    track_changes:                  OnceCell<FXProp<bool>>,
+    // Patch helper properties
+    patch:                          OnceCell<FXProp<bool>>,
+    patch_visibility:               OnceCell<syn::Visibility>,
+    patch_ident:                    OnceCell<Option<syn::Ident>>,
+    patch_struct_ident:             OnceCell<syn::Ident>,
+    patch_doc:                      OnceCell<Option<FXProp<Vec<syn::LitStr>>>>,
     // Constructor new properties
     needs_new:                      OnceCell<FXProp<bool>>,
     new_visibility:                 OnceCell<Option<syn::Visibility>>,
@@ -107,12 +130,17 @@ where
     inner_mut:                      OnceCell<Option<FXProp<bool>>>,
     into:                           OnceCell<Option<FXProp<bool>>>,
     lock:                           OnceCell<Option<FXProp<bool>>>,
+    lock_mutex:                     OnceCell<Option<FXProp<bool>>>,
     mode_async:                     OnceCell<Option<FXProp<bool>>>,
     mode_plain:                     OnceCell<Option<FXProp<bool>>>,
     mode_sync:                      OnceCell<Option<FXProp<bool>>>,
     needs_default:                  OnceCell<FXProp<bool>>,
     optional:                       OnceCell<Option<FXProp<bool>>>,
     setter_into:                    OnceCell<Option<FXProp<bool>>>,
+    setter_try_into:                OnceCell<Option<FXProp<bool>>>,
+    setter_chain:                   OnceCell<Option<FXProp<bool>>>,
+    single_lock:                    OnceCell<FXProp<bool>>,
+    single_lock_group:              OnceCell<Option<syn::Ident>>,
     syncish:                        OnceCell<FXProp<bool>>,
     visibility:                     OnceCell<Option<syn::Visibility>>,
     has_post_build:                 OnceCell<FXProp<bool>>,
@@ -153,13 +181,15 @@ where
     simple_bool_prop! {builder}
 
     common_prop_impl! {
-        accessor, accessor_mut, setter, clearer, predicate, reader, writer, lazy
+        accessor, accessor_mut, setter, with, clearer, predicate, reader, writer, lazy
     }
 
     doc_props! {
         builder_doc from builder.doc;
         builder_method_doc from builder.method_doc;
         rc_doc from rc.doc;
+        transaction_doc from transaction.doc;
+        patch_doc from patch.doc;
     }
 
     #[cfg(feature = "serde")]
@@ -185,7 +215,9 @@ where
             builder_doc: OnceCell::new(),
             builder_ident: OnceCell::new(),
             builder_default: OnceCell::new(),
+            builder_from_map: OnceCell::new(),
             builder_into: OnceCell::new(),
+            builder_try_into: OnceCell::new(),
             builder_method_doc: OnceCell::new(),
             builder_opt_in: OnceCell::new(),
             builder_prefix: OnceCell::new(),
@@ -207,6 +239,9 @@ where
             setter: OnceCell::new(),
             setter_visibility: OnceCell::new(),
             setter_ident: OnceCell::new(),
+            with: OnceCell::new(),
+            with_visibility: OnceCell::new(),
+            with_ident: OnceCell::new(),
             writer: OnceCell::new(),
             writer_visibility: OnceCell::new(),
             writer_ident: OnceCell::new(),
@@ -217,6 +252,7 @@ where
             inner_mut: OnceCell::new(),
             into: OnceCell::new(),
             lock: OnceCell::new(),
+            lock_mutex: OnceCell::new(),
             mode_async: OnceCell::new(),
             mode_plain: OnceCell::new(),
             mode_sync: OnceCell::new(),
@@ -226,6 +262,10 @@ where
             needs_default: OnceCell::new(),
             optional: OnceCell::new(),
             setter_into: OnceCell::new(),
+            setter_try_into: OnceCell::new(),
+            setter_chain: OnceCell::new(),
+            single_lock: OnceCell::new(),
+            single_lock_group: OnceCell::new(),
             syncish: OnceCell::new(),
             visibility: OnceCell::new(),
             has_post_build: OnceCell::new(),
@@ -233,6 +273,17 @@ where
             rc: OnceCell::new(),
             rc_visibility: OnceCell::new(),
             rc_doc: OnceCell::new(),
+            transaction: OnceCell::new(),
+            transaction_visibility: OnceCell::new(),
+            transaction_ident: OnceCell::new(),
+            transaction_struct_ident: OnceCell::new(),
+            transaction_doc: OnceCell::new(),
+            track_changes: OnceCell::new(),
+            patch: OnceCell::new(),
+            patch_visibility: OnceCell::new(),
+            patch_ident: OnceCell::new(),
+            patch_struct_ident: OnceCell::new(),
+            patch_doc: OnceCell::new(),
             myself_name: OnceCell::new(),
             myself_downgrade_name: OnceCell::new(),
             myself_field_ident: OnceCell::new(),
@@ -275,9 +326,15 @@ where
             FXHelperKind::Builder => self.builder_prefix(),
             FXHelperKind::Clearer => self.clearer_ident(),
             FXHelperKind::Lazy => self.lazy_ident(),
+            FXHelperKind::Lock
+            | FXHelperKind::CompareExchange
+            | FXHelperKind::FetchAdd
+            | FXHelperKind::Swap
+            | FXHelperKind::Update => None,
             FXHelperKind::Predicate => self.predicate_ident(),
             FXHelperKind::Reader => self.reader_ident(),
             FXHelperKind::Setter => self.setter_ident(),
+            FXHelperKind::With => self.with_ident(),
             FXHelperKind::Writer => self.writer_ident(),
         }
     }
@@ -366,6 +423,14 @@ where
         self.source.builder.as_ref().and_then(|h| h.attributes_fn())
     }
 
+    pub fn builder_checks(&self) -> Option<&FXMultiple<FXSynTuple<(syn::Expr, syn::LitStr)>>> {
+        self.source
+            .builder()
+            .as_ref()
+            .map(|b| b.check())
+            .filter(|checks| !checks.is_empty())
+    }
+
     pub fn builder_opt_in(&self) -> FXProp<bool> {
         *self.builder_opt_in.get_or_init(|| {
             self.source
@@ -396,6 +461,16 @@ where
         })
     }
 
+    pub fn builder_from_map(&self) -> FXProp<bool> {
+        *self.builder_from_map.get_or_init(|| {
+            self.source
+                .builder()
+                .as_ref()
+                .and_then(|b| b.from_map())
+                .map_or(FXProp::new(false, None), |fm| fm.is_set())
+        })
+    }
+
     pub fn builder_struct(&self) -> FXProp<bool> {
         *self.builder_struct.get_or_init(|| {
             self.builder().unwrap_or_else(|| -> FXProp<bool> {
@@ -554,6 +629,147 @@ where
             .as_ref()
     }
 
+    pub fn transaction(&self) -> FXProp<bool> {
+        *self.transaction.get_or_init(|| {
+            self.source
+                .transaction()
+                .as_ref()
+                .map_or_else(|| false.into(), |tx| tx.is_set())
+        })
+    }
+
+    // Both the transaction method and the transaction struct share this visibility.
+    pub fn transaction_visibility(&self) -> &syn::Visibility {
+        self.transaction_visibility.get_or_init(|| {
+            self.source
+                .transaction()
+                .as_ref()
+                .and_then(|tx| tx.visibility())
+                .cloned()
+                .unwrap_or_else(|| self.codegen_ctx().input().vis().clone())
+        })
+    }
+
+    pub fn transaction_ident(&self) -> Option<&syn::Ident> {
+        self.transaction_ident
+            .get_or_init(|| {
+                self.source.transaction().as_ref().map(|tx| {
+                    tx.name().map_or_else(
+                        || format_ident!("transaction", span = tx.final_span()),
+                        |name| format_ident!("{}", name.value(), span = name.final_span()),
+                    )
+                })
+            })
+            .as_ref()
+    }
+
+    pub fn transaction_struct_ident(&self) -> &syn::Ident {
+        self.transaction_struct_ident.get_or_init(|| {
+            let codegen_ctx = self.codegen_ctx();
+            let input_ident = codegen_ctx.input().ident();
+            format_ident!("{}Transaction", input_ident, span = input_ident.span())
+        })
+    }
+
+    pub fn track_changes(&self) -> FXProp<bool> {
+        *self.track_changes.get_or_init(|| {
+            self.source
+                .track_changes()
+                .as_ref()
+                .map_or_else(|| false.into(), |tc| tc.is_set())
+        })
+    }
+
+    pub fn patch(&self) -> FXProp<bool> {
+        *self
+            .patch
+            .get_or_init(|| self.source.patch().as_ref().map_or_else(|| false.into(), |p| p.is_set()))
+    }
+
+    // Both the apply method and the patch struct share this visibility.
+    pub fn patch_visibility(&self) -> &syn::Visibility {
+        self.patch_visibility.get_or_init(|| {
+            self.source
+                .patch()
+                .as_ref()
+                .and_then(|p| p.visibility())
+                .cloned()
+                .unwrap_or_else(|| self.codegen_ctx().input().vis().clone())
+        })
+    }
+
+    pub fn patch_ident(&self) -> Option<&syn::Ident> {
+        self.patch_ident
+            .get_or_init(|| {
+                self.source.patch().as_ref().map(|p| {
+                    p.name().map_or_else(
+                        || format_ident!("apply_patch", span = p.final_span()),
+                        |name| format_ident!("{}", name.value(), span = name.final_span()),
+                    )
+                })
+            })
+            .as_ref()
+    }
+
+    pub fn patch_struct_ident(&self) -> &syn::Ident {
+        self.patch_struct_ident.get_or_init(|| {
+            let codegen_ctx = self.codegen_ctx();
+            let input_ident = codegen_ctx.input().ident();
+            format_ident!("{}Patch", input_ident, span = input_ident.span())
+        })
+    }
+
+    // Name of the struct field holding the change tracker.
+    pub fn changes_field_ident(&self) -> syn::Ident {
+        format_ident!("__fx_changes", span = self.track_changes().final_span())
+    }
+
+    // Name of the struct field holding the lock of a field group.
+    pub fn lock_group_field_ident(&self, group: &syn::Ident) -> syn::Ident {
+        format_ident!("__fx_lock_group_{}", group, span = group.span())
+    }
+
+    // Name of the struct holding values of a field group: `net` group of `Server` is stored in `ServerNetGroup`.
+    pub fn lock_group_struct_ident(&self, group: &syn::Ident) -> syn::Ident {
+        let codegen_ctx = self.codegen_ctx();
+        let group_name = group
+            .to_string()
+            .split('_')
+            .map(|part| {
+                let mut chars = part.chars();
+                chars
+                    .next()
+                    .map_or_else(String::new, |c| c.to_uppercase().chain(chars).collect())
+            })
+            .collect::<String>();
+        format_ident!("{}{}Group", codegen_ctx.input().ident(), group_name, span = group.span())
+    }
+
+    pub fn single_lock(&self) -> FXProp<bool> {
+        *self.single_lock.get_or_init(|| {
+            self.source
+                .mode_sync
+                .as_ref()
+                .and_then(|s| s.is_single_lock())
+                .unwrap_or_else(|| false.into())
+        })
+    }
+
+    // In single-lock mode all lock-protected fields are members of the implicit `state` lock group.
+    pub fn single_lock_group(&self) -> Option<&syn::Ident> {
+        self.single_lock_group
+            .get_or_init(|| {
+                let single_lock = self.single_lock();
+                if *single_lock {
+                    Some(format_ident!("state", span = single_lock.final_span()))
+                }
+                else {
+                    None
+                }
+            })
+            .as_ref()
+    }
+
     pub fn myself_name(&self) -> Option<&syn::Ident> {
         self.myself_name
             .get_or_init(|| {
@@ -723,6 +939,62 @@ where
             .as_ref()
     }
 
+    /// Layout version of the serialized struct if versioning is enabled.
+    #[cfg(feature = "serde")]
+    pub fn serde_version(&self) -> Option<FXProp<u32>> {
+        self.source
+            .serde()
+            .as_ref()
+            .and_then(|s| s.version().as_ref())
+            .and_then(|v| v.value().map(|version| FXProp::new(*version, Some(v.final_span()))))
+    }
+
+    /// In-place update from a deserializer is requested.
+    #[cfg(feature = "serde")]
+    pub fn serde_update(&self) -> FXProp<bool> {
+        self.source
+            .serde()
+            .as_ref()
+            .and_then(|s| s.update().as_ref())
+            .map_or_else(|| FXProp::new(false, None), |u| u.is_set())
+    }
+
+    /// Lazy fields missing in the input of an in-place update are to be cleared.
+    #[cfg(feature = "serde")]
+    pub fn serde_update_clear_lazy(&self) -> FXProp<bool> {
+        self.source
+            .serde()
+            .as_ref()
+            .and_then(|s| s.update().as_ref())
+            .map_or_else(|| FXProp::new(false, None), |u| u.clear_lazy())
+    }
+
+    /// JSON Schema is to be implemented for the struct.
+    #[cfg(feature = "serde")]
+    pub fn serde_schema(&self) -> FXProp<bool> {
+        self.source
+            .serde()
+            .as_ref()
+            .and_then(|s| s.schema().as_ref())
+            .map_or_else(|| FXProp::new(false, None), |s| s.is_set())
+    }
+
+    /// All fields are to be locked before serialization starts.
+    #[cfg(feature = "serde")]
+    pub fn serde_snapshot(&self) -> FXProp<bool> {
+        self.source
+            .serde()
+            .as_ref()
+            .and_then(|s| s.snapshot().as_ref())
+            .map_or_else(|| FXProp::new(false, None), |s| s.is_set())
+    }
+
+    /// Migrations from older layout versions, ordered by version.
+    #[cfg(feature = "serde")]
+    pub fn serde_migrations(&self) -> Vec<&FXSerdeMigration> {
+        self.source.serde().as_ref().map_or_else(Vec::new, |s| s.migrations())
+    }
+
     #[allow(dead_code)]
     pub fn base_name(&self) -> Option<syn::Ident> {
         Some(self.codegen_ctx().input().ident().clone())
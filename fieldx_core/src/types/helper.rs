@@ -6,10 +6,16 @@ pub enum FXHelperKind {
     AccessorMut,
     Builder,
     Clearer,
+    CompareExchange,
+    FetchAdd,
     Lazy,
+    Lock,
     Predicate,
     Reader,
     Setter,
+    Swap,
+    Update,
+    With,
     Writer,
 }
 
@@ -23,10 +29,16 @@ impl Display for FXHelperKind {
                 FXHelperKind::AccessorMut => "accessor_mut",
                 FXHelperKind::Builder => "builder setter",
                 FXHelperKind::Clearer => "clearer",
+                FXHelperKind::CompareExchange => "compare_exchange",
+                FXHelperKind::FetchAdd => "fetch_add",
                 FXHelperKind::Lazy => "lazy builder",
+                FXHelperKind::Lock => "lock",
                 FXHelperKind::Predicate => "predicate",
                 FXHelperKind::Reader => "reader",
                 FXHelperKind::Setter => "setter",
+                FXHelperKind::Swap => "swap",
+                FXHelperKind::Update => "update",
+                FXHelperKind::With => "with",
                 FXHelperKind::Writer => "writer",
             }
         )
@@ -41,10 +53,16 @@ impl FXHelperKind {
             FXHelperKind::Accessor => "",
             FXHelperKind::Builder => "",
             FXHelperKind::Clearer => "clear_",
+            FXHelperKind::CompareExchange => "compare_exchange_",
+            FXHelperKind::FetchAdd => "fetch_add_",
             FXHelperKind::Lazy => "build_",
+            FXHelperKind::Lock => "lock_",
             FXHelperKind::Predicate => "has_",
             FXHelperKind::Reader => "read_",
             FXHelperKind::Setter => "set_",
+            FXHelperKind::Swap => "swap_",
+            FXHelperKind::Update => "update_",
+            FXHelperKind::With => "with_",
             FXHelperKind::Writer => "write_",
         }
     }
@@ -56,10 +74,16 @@ impl FXHelperKind {
             FXHelperKind::Accessor => "",
             FXHelperKind::Builder => "",
             FXHelperKind::Clearer => "",
+            FXHelperKind::CompareExchange => "",
+            FXHelperKind::FetchAdd => "",
             FXHelperKind::Lazy => "",
+            FXHelperKind::Lock => "",
             FXHelperKind::Predicate => "",
             FXHelperKind::Reader => "",
             FXHelperKind::Setter => "",
+            FXHelperKind::Swap => "",
+            FXHelperKind::Update => "",
+            FXHelperKind::With => "",
             FXHelperKind::Writer => "",
         }
     }
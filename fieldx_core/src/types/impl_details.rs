@@ -1,6 +1,7 @@
 pub mod impl_async;
 pub mod impl_plain;
 pub mod impl_sync;
+pub mod impl_sync_std;
 
 use std::fmt::Debug;
 
@@ -20,6 +21,8 @@ where
     fn await_call(&self, span: Span) -> TokenStream;
     fn ref_count_strong(&self, span: Span) -> TokenStream;
     fn ref_count_weak(&self, span: Span) -> TokenStream;
+    // Type of the change tracker of `track_changes` structs.
+    fn change_tracker(&self, span: Span) -> TokenStream;
     fn field_simple_proxy_type(&self, span: Span) -> TokenStream;
     fn field_lock_proxy_type(&self, span: Span) -> Result<TokenStream>;
     fn fx_mapped_write_guard(&self, span: Span) -> Result<TokenStream>;
@@ -27,11 +30,33 @@ where
     fn fx_infallible_builder_wrapper(&self, span: Span) -> Result<TokenStream>;
     fn lazy_builder(&self, fctx: &FXFieldCtx<ImplCtx>) -> TokenStream;
     fn lazy_wrapper_fn(&self, fctx: &FXFieldCtx<ImplCtx>) -> Result<Option<FXFnConstructor>>;
+    fn mutex(&self, span: Span) -> Result<TokenStream>;
+    fn mutex_guard(&self, span: Span) -> Result<TokenStream>;
     fn rwlock(&self, span: Span) -> Result<TokenStream>;
     fn rwlock_mapped_read_guard(&self, span: Span) -> Result<TokenStream>;
     fn rwlock_mapped_write_guard(&self, span: Span) -> Result<TokenStream>;
     fn rwlock_read_guard(&self, span: Span) -> Result<TokenStream>;
     fn rwlock_write_guard(&self, span: Span) -> Result<TokenStream>;
+    // Type of the guard returned by `get(deref)` accessors of lock-protected fields. `guard` is the full type of the
+    // read guard the value is obtained with, `target` is the type the value dereferences to.
+    fn rwlock_deref_read_guard(
+        &self,
+        guard: &TokenStream,
+        lifetime: &TokenStream,
+        target: &TokenStream,
+        span: Span,
+    ) -> Result<TokenStream>;
+    // Convert `guard` expression into the deref guard. `lazy` tells that the guard is a lazy field proxy guard.
+    fn rwlock_deref_read_map(&self, guard: &TokenStream, lazy: bool, span: Span) -> Result<TokenStream>;
+    // Expression that attempts to lock `lock` for reading without blocking or, if `timeout` expression is given, by
+    // waiting no longer than the specified duration. Must evaluate into an `Option` of the read guard.
+    fn rwlock_try_read(&self, lock: &TokenStream, timeout: Option<&TokenStream>, span: Span) -> Result<TokenStream>;
+    // Same as `rwlock_try_read` but for write locks.
+    fn rwlock_try_write(&self, lock: &TokenStream, timeout: Option<&TokenStream>, span: Span) -> Result<TokenStream>;
+    // Turn write `guard` expression into an upgradable guard which lets readers in but keeps writers out.
+    fn rwlock_downgrade_upgradable(&self, guard: &TokenStream, span: Span) -> Result<TokenStream>;
+    // Turn upgradable `guard` expression back into a write guard.
+    fn rwlock_upgrade(&self, guard: &TokenStream, span: Span) -> Result<TokenStream>;
 }
 
 impl<ImplCtx> FXImplDetails<ImplCtx> for Box<dyn FXImplDetails<ImplCtx>>
@@ -54,6 +79,10 @@ where
         self.as_ref().ref_count_weak(span)
     }
 
+    fn change_tracker(&self, span: Span) -> TokenStream {
+        self.as_ref().change_tracker(span)
+    }
+
     fn field_simple_proxy_type(&self, span: Span) -> TokenStream {
         self.as_ref().field_simple_proxy_type(span)
     }
@@ -82,6 +111,14 @@ where
         self.as_ref().lazy_wrapper_fn(fctx)
     }
 
+    fn mutex(&self, span: Span) -> Result<TokenStream> {
+        self.as_ref().mutex(span)
+    }
+
+    fn mutex_guard(&self, span: Span) -> Result<TokenStream> {
+        self.as_ref().mutex_guard(span)
+    }
+
     fn rwlock(&self, span: Span) -> Result<TokenStream> {
         self.as_ref().rwlock(span)
     }
@@ -101,4 +138,34 @@ where
     fn rwlock_write_guard(&self, span: Span) -> Result<TokenStream> {
         self.as_ref().rwlock_write_guard(span)
     }
+
+    fn rwlock_deref_read_guard(
+        &self,
+        guard: &TokenStream,
+        lifetime: &TokenStream,
+        target: &TokenStream,
+        span: Span,
+    ) -> Result<TokenStream> {
+        self.as_ref().rwlock_deref_read_guard(guard, lifetime, target, span)
+    }
+
+    fn rwlock_deref_read_map(&self, guard: &TokenStream, lazy: bool, span: Span) -> Result<TokenStream> {
+        self.as_ref().rwlock_deref_read_map(guard, lazy, span)
+    }
+
+    fn rwlock_try_read(&self, lock: &TokenStream, timeout: Option<&TokenStream>, span: Span) -> Result<TokenStream> {
+        self.as_ref().rwlock_try_read(lock, timeout, span)
+    }
+
+    fn rwlock_try_write(&self, lock: &TokenStream, timeout: Option<&TokenStream>, span: Span) -> Result<TokenStream> {
+        self.as_ref().rwlock_try_write(lock, timeout, span)
+    }
+
+    fn rwlock_downgrade_upgradable(&self, guard: &TokenStream, span: Span) -> Result<TokenStream> {
+        self.as_ref().rwlock_downgrade_upgradable(guard, span)
+    }
+
+    fn rwlock_upgrade(&self, guard: &TokenStream, span: Span) -> Result<TokenStream> {
+        self.as_ref().rwlock_upgrade(guard, span)
+    }
 }
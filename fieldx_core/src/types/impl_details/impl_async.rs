@@ -48,6 +48,10 @@ where
         quote_spanned![span=> ::std::sync::Weak]
     }
 
+    fn change_tracker(&self, span: Span) -> TokenStream {
+        quote_spanned![span=> ::fieldx::changes::FXSyncChanges]
+    }
+
     fn fx_mapped_write_guard(&self, span: Span) -> Result<TokenStream> {
         Ok(quote_spanned![span=> ::fieldx::r#async::FXWriter])
     }
@@ -87,6 +91,14 @@ where
         quote_spanned![span=> .await]
     }
 
+    fn mutex(&self, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::r#async::Mutex])
+    }
+
+    fn mutex_guard(&self, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::r#async::MutexGuard])
+    }
+
     #[cfg(feature = "clonable-lock")]
     fn rwlock(&self, span: Span) -> Result<TokenStream> {
         Ok(quote_spanned![span=> ::fieldx::r#async::FXRwLock])
@@ -112,4 +124,48 @@ where
     fn rwlock_mapped_write_guard(&self, span: Span) -> Result<TokenStream> {
         Ok(quote_spanned![span=> ::fieldx::r#async::FXProxyWriteGuard])
     }
+
+    // Not every async lock backend has mapped guards. Therefore we use our own wrapper.
+    fn rwlock_deref_read_guard(
+        &self,
+        guard: &TokenStream,
+        _lifetime: &TokenStream,
+        _target: &TokenStream,
+        span: Span,
+    ) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::r#async::FXDerefReadGuard<#guard>])
+    }
+
+    fn rwlock_deref_read_map(&self, guard: &TokenStream, _lazy: bool, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::r#async::FXDerefReadGuard::new(#guard)])
+    }
+
+    // Backends differ in their non-blocking locking APIs, and timeouts require a timer. Both are taken care of by the
+    // runtime helpers.
+    fn rwlock_try_read(&self, lock: &TokenStream, timeout: Option<&TokenStream>, span: Span) -> Result<TokenStream> {
+        Ok(if let Some(timeout) = timeout {
+            quote_spanned![span=> ::fieldx::r#async::try_read_for(&#lock, #timeout).await]
+        }
+        else {
+            quote_spanned![span=> ::fieldx::r#async::try_read(&#lock)]
+        })
+    }
+
+    fn rwlock_try_write(&self, lock: &TokenStream, timeout: Option<&TokenStream>, span: Span) -> Result<TokenStream> {
+        Ok(if let Some(timeout) = timeout {
+            quote_spanned![span=> ::fieldx::r#async::try_write_for(&#lock, #timeout).await]
+        }
+        else {
+            quote_spanned![span=> ::fieldx::r#async::try_write(&#lock)]
+        })
+    }
+
+    // Only the async-lock backend has upgradable guards; it is up to the caller to check for it.
+    fn rwlock_downgrade_upgradable(&self, guard: &TokenStream, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::r#async::RwLockWriteGuard::downgrade_to_upgradable(#guard)])
+    }
+
+    fn rwlock_upgrade(&self, guard: &TokenStream, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::r#async::RwLockUpgradableReadGuard::upgrade(#guard).await])
+    }
 }
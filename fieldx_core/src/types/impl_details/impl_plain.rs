@@ -39,6 +39,10 @@ where
         quote_spanned![span=> ::std::rc::Weak]
     }
 
+    fn change_tracker(&self, span: Span) -> TokenStream {
+        quote_spanned![span=> ::fieldx::changes::FXChanges]
+    }
+
     fn fx_mapped_write_guard(&self, span: Span) -> Result<TokenStream> {
         Err(darling::Error::custom("Write guard type is not supported for plain fields").with_span(&span))
     }
@@ -63,6 +67,14 @@ where
         quote![]
     }
 
+    fn mutex(&self, span: Span) -> Result<TokenStream> {
+        Err(darling::Error::custom("Mutex is not supported for plain fields").with_span(&span))
+    }
+
+    fn mutex_guard(&self, span: Span) -> Result<TokenStream> {
+        Err(darling::Error::custom("Mutex guard is not supported for plain fields").with_span(&span))
+    }
+
     fn rwlock(&self, span: Span) -> Result<TokenStream> {
         Err(darling::Error::custom("RW lock is not supported for plain fields").with_span(&span))
     }
@@ -82,4 +94,34 @@ where
     fn rwlock_mapped_write_guard(&self, span: Span) -> Result<TokenStream> {
         Err(darling::Error::custom("RW lock mapped write guard is not supported for plain fields").with_span(&span))
     }
+
+    fn rwlock_deref_read_guard(
+        &self,
+        _guard: &TokenStream,
+        _lifetime: &TokenStream,
+        _target: &TokenStream,
+        span: Span,
+    ) -> Result<TokenStream> {
+        Err(darling::Error::custom("RW lock deref read guard is not supported for plain fields").with_span(&span))
+    }
+
+    fn rwlock_deref_read_map(&self, _guard: &TokenStream, _lazy: bool, span: Span) -> Result<TokenStream> {
+        Err(darling::Error::custom("RW lock deref read guard is not supported for plain fields").with_span(&span))
+    }
+
+    fn rwlock_try_read(&self, _lock: &TokenStream, _timeout: Option<&TokenStream>, span: Span) -> Result<TokenStream> {
+        Err(darling::Error::custom("RW lock try-read is not supported for plain fields").with_span(&span))
+    }
+
+    fn rwlock_try_write(&self, _lock: &TokenStream, _timeout: Option<&TokenStream>, span: Span) -> Result<TokenStream> {
+        Err(darling::Error::custom("RW lock try-write is not supported for plain fields").with_span(&span))
+    }
+
+    fn rwlock_downgrade_upgradable(&self, _guard: &TokenStream, span: Span) -> Result<TokenStream> {
+        Err(darling::Error::custom("RW lock upgradable guard is not supported for plain fields").with_span(&span))
+    }
+
+    fn rwlock_upgrade(&self, _guard: &TokenStream, span: Span) -> Result<TokenStream> {
+        Err(darling::Error::custom("RW lock upgradable guard is not supported for plain fields").with_span(&span))
+    }
 }
@@ -39,6 +39,10 @@ where
         quote_spanned![span=> ::std::sync::Weak]
     }
 
+    fn change_tracker(&self, span: Span) -> TokenStream {
+        quote_spanned![span=> ::fieldx::changes::FXSyncChanges]
+    }
+
     fn fx_mapped_write_guard(&self, span: Span) -> Result<TokenStream> {
         Ok(quote_spanned![span=> ::fieldx::sync::FXWriter])
     }
@@ -67,6 +71,14 @@ where
         quote![]
     }
 
+    fn mutex(&self, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::sync::Mutex])
+    }
+
+    fn mutex_guard(&self, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::sync::MutexGuard])
+    }
+
     #[cfg(feature = "clonable-lock")]
     fn rwlock(&self, span: Span) -> Result<TokenStream> {
         Ok(quote_spanned![span=> ::fieldx::sync::FXRwLock])
@@ -92,4 +104,55 @@ where
     fn rwlock_mapped_write_guard(&self, span: Span) -> Result<TokenStream> {
         Ok(quote_spanned![span=> ::fieldx::sync::FXProxyWriteGuard])
     }
+
+    fn rwlock_deref_read_guard(
+        &self,
+        _guard: &TokenStream,
+        lifetime: &TokenStream,
+        target: &TokenStream,
+        span: Span,
+    ) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::sync::MappedRwLockReadGuard<#lifetime, #target>])
+    }
+
+    fn rwlock_deref_read_map(&self, guard: &TokenStream, lazy: bool, span: Span) -> Result<TokenStream> {
+        Ok(if lazy {
+            // Lazy fields are read via a proxy guard over Option<T> which is always initialized at this point.
+            quote_spanned![span=>
+                ::fieldx::sync::RwLockReadGuard::map(
+                    #guard.into_inner(),
+                    |v| ::std::ops::Deref::deref(v.as_ref().unwrap())
+                )
+            ]
+        }
+        else {
+            quote_spanned![span=> ::fieldx::sync::RwLockReadGuard::map(#guard, |v| ::std::ops::Deref::deref(v))]
+        })
+    }
+
+    fn rwlock_try_read(&self, lock: &TokenStream, timeout: Option<&TokenStream>, span: Span) -> Result<TokenStream> {
+        Ok(if let Some(timeout) = timeout {
+            quote_spanned![span=> #lock.try_read_for(#timeout)]
+        }
+        else {
+            quote_spanned![span=> #lock.try_read()]
+        })
+    }
+
+    fn rwlock_try_write(&self, lock: &TokenStream, timeout: Option<&TokenStream>, span: Span) -> Result<TokenStream> {
+        Ok(if let Some(timeout) = timeout {
+            quote_spanned![span=> #lock.try_write_for(#timeout)]
+        }
+        else {
+            quote_spanned![span=> #lock.try_write()]
+        })
+    }
+
+    fn rwlock_downgrade_upgradable(&self, guard: &TokenStream, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::sync::RwLockWriteGuard::downgrade_to_upgradable(#guard)])
+    }
+
+    fn rwlock_upgrade(&self, guard: &TokenStream, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::sync::RwLockUpgradableReadGuard::upgrade(#guard)])
+    }
 }
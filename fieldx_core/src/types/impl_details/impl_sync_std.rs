@@ -0,0 +1,150 @@
+use crate::codegen::constructor::FXFnConstructor;
+use crate::ctx::codegen::FXImplementationContext;
+use crate::ctx::FXFieldCtx;
+
+use darling::Result;
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use quote::quote;
+use quote::quote_spanned;
+
+use super::FXImplDetails;
+
+/// Sync mode implementor for the `sync-std` backend. Produces code that only relies upon synchronization primitives
+/// from the standard library.
+#[derive(Debug)]
+pub struct FXSyncStdImplementor;
+
+impl<ImplCtx> FXImplDetails<ImplCtx> for FXSyncStdImplementor
+where
+    ImplCtx: FXImplementationContext,
+{
+    fn fieldx_impl_mod(&self, span: Span) -> TokenStream {
+        quote_spanned! {span=>
+            ::fieldx::sync
+        }
+    }
+
+    fn field_simple_proxy_type(&self, span: Span) -> TokenStream {
+        quote_spanned![span=> ::fieldx::sync::OnceCell]
+    }
+
+    fn field_lock_proxy_type(&self, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::sync::FXProxy])
+    }
+
+    fn ref_count_strong(&self, span: Span) -> TokenStream {
+        quote_spanned![span=> ::std::sync::Arc]
+    }
+
+    fn ref_count_weak(&self, span: Span) -> TokenStream {
+        quote_spanned![span=> ::std::sync::Weak]
+    }
+
+    fn change_tracker(&self, span: Span) -> TokenStream {
+        quote_spanned![span=> ::fieldx::changes::FXSyncChanges]
+    }
+
+    fn fx_mapped_write_guard(&self, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::sync::FXWriter])
+    }
+
+    fn fx_fallible_builder_wrapper(&self, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::sync::FXBuilderFallible])
+    }
+
+    fn fx_infallible_builder_wrapper(&self, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::sync::FXBuilderInfallible])
+    }
+
+    fn lazy_wrapper_fn(&self, _: &FXFieldCtx<ImplCtx>) -> Result<Option<FXFnConstructor>> {
+        Ok(None)
+    }
+
+    fn lazy_builder(&self, fctx: &FXFieldCtx<ImplCtx>) -> TokenStream {
+        let ctx = fctx.codegen_ctx();
+        let input_type = ctx.struct_type_toks();
+        let lazy_builder_name = fctx.lazy_ident();
+        let span = fctx.lazy().final_span();
+        quote_spanned![span=> <#input_type>::#lazy_builder_name]
+    }
+
+    fn await_call(&self, _span: Span) -> TokenStream {
+        quote![]
+    }
+
+    fn mutex(&self, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::sync::Mutex])
+    }
+
+    fn mutex_guard(&self, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::std::sync::MutexGuard])
+    }
+
+    #[cfg(feature = "clonable-lock")]
+    fn rwlock(&self, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::sync::FXRwLock])
+    }
+
+    #[cfg(not(feature = "clonable-lock"))]
+    fn rwlock(&self, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::sync::RwLock])
+    }
+
+    fn rwlock_read_guard(&self, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::std::sync::RwLockReadGuard])
+    }
+
+    fn rwlock_write_guard(&self, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::std::sync::RwLockWriteGuard])
+    }
+
+    fn rwlock_mapped_read_guard(&self, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::sync::FXProxyReadGuard])
+    }
+
+    fn rwlock_mapped_write_guard(&self, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::sync::FXProxyWriteGuard])
+    }
+
+    // Standard library doesn't have stable mapped guards, hence the wrapper.
+    fn rwlock_deref_read_guard(
+        &self,
+        guard: &TokenStream,
+        _lifetime: &TokenStream,
+        _target: &TokenStream,
+        span: Span,
+    ) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::sync::FXDerefReadGuard<#guard>])
+    }
+
+    fn rwlock_deref_read_map(&self, guard: &TokenStream, _lazy: bool, span: Span) -> Result<TokenStream> {
+        Ok(quote_spanned![span=> ::fieldx::sync::FXDerefReadGuard::new(#guard)])
+    }
+
+    fn rwlock_try_read(&self, lock: &TokenStream, timeout: Option<&TokenStream>, span: Span) -> Result<TokenStream> {
+        Ok(if let Some(timeout) = timeout {
+            quote_spanned![span=> #lock.try_read_for(#timeout)]
+        }
+        else {
+            quote_spanned![span=> #lock.try_read()]
+        })
+    }
+
+    fn rwlock_try_write(&self, lock: &TokenStream, timeout: Option<&TokenStream>, span: Span) -> Result<TokenStream> {
+        Ok(if let Some(timeout) = timeout {
+            quote_spanned![span=> #lock.try_write_for(#timeout)]
+        }
+        else {
+            quote_spanned![span=> #lock.try_write()]
+        })
+    }
+
+    fn rwlock_downgrade_upgradable(&self, _guard: &TokenStream, span: Span) -> Result<TokenStream> {
+        Err(darling::Error::custom("std locks have no upgradable guards; use the `sync` feature").with_span(&span))
+    }
+
+    fn rwlock_upgrade(&self, _guard: &TokenStream, span: Span) -> Result<TokenStream> {
+        Err(darling::Error::custom("std locks have no upgradable guards; use the `sync` feature").with_span(&span))
+    }
+}
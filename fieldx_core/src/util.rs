@@ -163,6 +163,16 @@ macro_rules! common_prop_impl {
             })
         }
 
+        pub fn builder_try_into(&self) -> Option<FXProp<bool>> {
+            *self
+                .builder_try_into
+                .get_or_init(|| self.source.builder.as_ref().and_then(|b| b.is_try_into()))
+        }
+
+        pub fn builder_try_into_defer(&self) -> Option<FXProp<bool>> {
+            self.source.builder.as_ref().and_then(|b| b.is_try_into_defer())
+        }
+
         pub fn builder_required(&self) -> Option<FXProp<bool>> {
             *self
                 .builder_required
@@ -179,6 +189,18 @@ macro_rules! common_prop_impl {
             })
         }
 
+        pub fn setter_try_into(&self) -> Option<FXProp<bool>> {
+            *self
+                .setter_try_into
+                .get_or_init(|| self.source.setter.as_ref().and_then(|s| s.is_try_into()))
+        }
+
+        pub fn setter_chain(&self) -> Option<FXProp<bool>> {
+            *self
+                .setter_chain
+                .get_or_init(|| self.source.setter.as_ref().and_then(|s| s.is_chain()))
+        }
+
         pub fn mode_sync(&self) -> Option<FXProp<bool>> {
             *self
                 .mode_sync
@@ -210,6 +232,12 @@ macro_rules! common_prop_impl {
             })
         }
 
+        pub fn lock_mutex(&self) -> Option<FXProp<bool>> {
+            *self
+                .lock_mutex
+                .get_or_init(|| self.source.lock.as_ref().and_then(|l| l.is_mutex()))
+        }
+
         pub fn optional(&self) -> Option<FXProp<bool>> {
             *self.optional.get_or_init(|| {
                 self.source.optional.as_ref().map(|o| o.is_set()).or_else(|| {
@@ -233,9 +261,16 @@ macro_rules! common_prop_impl {
                 FXHelperKind::Builder => self.builder_visibility(),
                 FXHelperKind::Clearer => self.clearer_visibility(),
                 FXHelperKind::Lazy => self.lazy_visibility(),
+                // Lock, atomic, and swap helpers have no own arguments, hence only the default visibility applies.
+                FXHelperKind::Lock
+                | FXHelperKind::CompareExchange
+                | FXHelperKind::FetchAdd
+                | FXHelperKind::Swap
+                | FXHelperKind::Update => self.visibility(),
                 FXHelperKind::Predicate => self.predicate_visibility(),
                 FXHelperKind::Reader => self.reader_visibility(),
                 FXHelperKind::Setter => self.setter_visibility(),
+                FXHelperKind::With => self.with_visibility(),
                 FXHelperKind::Writer => self.writer_visibility(),
             }
         }
@@ -247,9 +282,15 @@ macro_rules! common_prop_impl {
                 FXHelperKind::Builder => self.builder_attributes_fn(),
                 FXHelperKind::Clearer => self.clearer_attributes_fn(),
                 FXHelperKind::Lazy => self.lazy_attributes_fn(),
+                FXHelperKind::Lock
+                | FXHelperKind::CompareExchange
+                | FXHelperKind::FetchAdd
+                | FXHelperKind::Swap
+                | FXHelperKind::Update => None,
                 FXHelperKind::Predicate => self.predicate_attributes_fn(),
                 FXHelperKind::Reader => self.reader_attributes_fn(),
                 FXHelperKind::Setter => self.setter_attributes_fn(),
+                FXHelperKind::With => self.with_attributes_fn(),
                 FXHelperKind::Writer => self.writer_attributes_fn(),
             }
         }
@@ -311,6 +352,24 @@ macro_rules! common_prop_impl {
                 .as_ref()
         }
 
+        #[cfg(feature = "serde")]
+        pub fn serde_lazy_skip_unset(&self) -> Option<FXProp<bool>> {
+            self.source
+                .serde()
+                .as_ref()
+                .and_then(|s| s.lazy().as_ref())
+                .and_then(|l| l.skip_unset())
+        }
+
+        #[cfg(feature = "serde")]
+        pub fn serde_skip_none(&self) -> Option<FXProp<bool>> {
+            self.source
+                .serde()
+                .as_ref()
+                .and_then(|s| s.skip_none().as_ref())
+                .map(|s| s.is_set())
+        }
+
         #[cfg(feature = "serde")]
         pub fn serde_forward_attrs(&self) -> Option<&HashSet<syn::Path>> {
             self.serde_forward_attrs
@@ -363,7 +422,7 @@ where
     None
 }
 
-pub fn mode_sync_prop(mode_sync: &Option<FXBool>, mode: &Option<FXSynValue<FXSyncMode>>) -> Option<FXProp<bool>> {
+pub fn mode_sync_prop<T: FXSetState>(mode_sync: &Option<T>, mode: &Option<FXSynValue<FXSyncMode>>) -> Option<FXProp<bool>> {
     mode_sync
         .as_ref()
         .map(|th| th.is_set())
@@ -11,10 +11,14 @@ use darling::FromField;
 use derive_ctx::FXDeriveCodegenCtx;
 use derive_ctx::FXDeriveFieldCtx;
 use derive_ctx::FXDeriveMacroCtx;
+use fieldx_aux::FXOrig;
 use fieldx_aux::FXProp;
+use fieldx_aux::FXPropBool;
 use fieldx_core::codegen::constructor::FXConstructor;
+use fieldx_core::codegen::constructor::FXFieldConstructor;
 use fieldx_core::codegen::constructor::FXFnConstructor;
 use fieldx_core::codegen::constructor::FXImplConstructor;
+use fieldx_core::codegen::constructor::FXStructConstructor;
 use fieldx_core::field_receiver::FXField;
 use fieldx_core::struct_receiver::args::FXStructArgs;
 use fieldx_core::struct_receiver::FXStructReceiver;
@@ -30,10 +34,12 @@ use quote::ToTokens;
 use serde::FXRewriteSerde;
 use std::cell::OnceCell;
 use std::rc::Rc;
+use syn::ext::IdentExt;
 use syn::parse_quote_spanned;
 use syn::spanned::Spanned;
 pub(crate) use sync::FXCodeGenSync;
 
+use crate::util::deferred_errors_ident;
 #[allow(unused)]
 use crate::util::dump_tt;
 use crate::util::std_default_expr_toks;
@@ -92,6 +98,37 @@ impl<T> FXValueRepr<T> {
     }
 }
 
+// How a field of the user struct is represented in a partial struct.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum FXPartialField {
+    // `Option<T>`; `None` leaves the field intact.
+    Set,
+    // `Option<Option<T>>`; `Some(None)` clears the field, and so does `None` if `clear_missing` is set.
+    Clearable { clear_missing: bool },
+}
+
+pub(crate) struct FXPartialStruct {
+    pub(crate) struct_ctor:    FXStructConstructor,
+    // `ident: None` initializers of all fields.
+    pub(crate) field_defaults: Vec<TokenStream>,
+    // Statements applying the values of the partial struct.
+    pub(crate) statements:     Vec<TokenStream>,
+    pub(crate) is_async:       FXProp<bool>,
+    // Fields that can only be updated via a mutable reference.
+    pub(crate) mut_fields:     Vec<syn::Ident>,
+    // Per-field pieces of the statements for callers which apply the values in their own way.
+    pub(crate) fields:         Vec<FXPartialFieldParts>,
+}
+
+pub(crate) struct FXPartialFieldParts {
+    pub(crate) fctx:  Rc<FXDeriveFieldCtx>,
+    pub(crate) kind:  FXPartialField,
+    // Setter body without change tracking, evaluating into the previous value. Expects the new value in `value`.
+    pub(crate) set:   TokenStream,
+    // Same for the clearer, for optional and lazy fields.
+    pub(crate) clear: Option<TokenStream>,
+}
+
 // Methods that are related to the current context if first place.
 
 pub(crate) struct FXRewriter<'a> {
@@ -145,6 +182,62 @@ impl<'a> FXRewriter<'a> {
         }
     }
 
+    // Takes `ident: value` initializers of struct fields and moves those of lock group members into the initializer of
+    // their group lock. The group initializer takes the place of the first member. Initializers of the fields not
+    // declared by the user, except for the `rc` back reference, are appended.
+    pub(crate) fn field_inits(
+        &self,
+        inits: impl IntoIterator<Item = (Rc<FXDeriveFieldCtx>, TokenStream)>,
+    ) -> darling::Result<Vec<TokenStream>> {
+        let arg_props = self.ctx().arg_props();
+        let mut slots: Vec<(Option<Rc<FXDeriveFieldCtx>>, Vec<TokenStream>)> = vec![];
+
+        for (fctx, init) in inits {
+            if let Some(group) = fctx.lock_group() {
+                if let Some(slot) = slots
+                    .iter_mut()
+                    .find(|(member, _)| member.as_ref().and_then(|m| m.lock_group()) == Some(group))
+                {
+                    slot.1.push(init);
+                    continue;
+                }
+                slots.push((Some(fctx.clone()), vec![init]));
+            }
+            else {
+                slots.push((None, vec![init]));
+            }
+        }
+
+        let mut group_inits = vec![];
+        for (member, inits) in slots {
+            if let Some(member) = member {
+                let group = member.lock_group().unwrap();
+                let span = group.span();
+                let rwlock = member.impl_details().rwlock(span)?;
+                let group_field_ident = arg_props.lock_group_field_ident(group);
+                let group_struct_ident = arg_props.lock_group_struct_ident(group);
+                group_inits.push(quote_spanned! {span=>
+                    #group_field_ident: #rwlock::new(#group_struct_ident {
+                        #( #inits, )*
+                        __fx_phantom: ::std::marker::PhantomData
+                    })
+                });
+            }
+            else {
+                group_inits.extend(inits);
+            }
+        }
+
+        let track_changes = arg_props.track_changes();
+        if *track_changes {
+            let span = track_changes.final_span();
+            let changes_field = arg_props.changes_field_ident();
+            group_inits.push(quote_spanned! {span=> #changes_field: ::std::default::Default::default()});
+        }
+
+        Ok(group_inits)
+    }
+
     pub(crate) fn rewrite(&'a mut self) -> TokenStream {
         self.prepare_struct();
         self.rewrite_struct();
@@ -254,6 +347,7 @@ impl<'a> FXRewriter<'a> {
         let cgen = self.struct_codegen();
 
         self.myself_methods();
+        ctx.ok_or_record(self.change_tracking());
 
         let needs_new = ctx.needs_new();
 
@@ -263,18 +357,15 @@ impl<'a> FXRewriter<'a> {
             let new_name = arg_props.new_ident().expect("Constructor name not set");
 
             let mut mc = FXFnConstructor::new_associated(new_name.clone());
-            let defaults = ctx
-                .all_field_ctx()
-                .iter()
-                .filter_map(|fctx| {
-                    if !fctx.extra() {
-                        fctx.default_expr().clone()
-                    }
-                    else {
-                        None
-                    }
-                })
-                .collect::<Vec<_>>();
+            let defaults = self
+                .field_inits(ctx.all_field_ctx().into_iter().filter(|fctx| !fctx.extra()).filter_map(|fctx| {
+                    let default_expr = fctx.default_expr().clone()?;
+                    Some((fctx, default_expr.to_token_stream()))
+                }))
+                .unwrap_or_else(|err| {
+                    ctx.push_error(err);
+                    vec![]
+                });
 
             ctx.ok_or_record(
                 mc.set_span(span)
@@ -284,6 +375,7 @@ impl<'a> FXRewriter<'a> {
                         &quote_spanned![span=> Self],
                         &quote_spanned![span=> #( #defaults ),*],
                         None,
+                        None,
                     ))
                     .add_attribute_toks(quote_spanned! {span=> #[inline] }),
             );
@@ -330,6 +422,786 @@ impl<'a> FXRewriter<'a> {
         }
     }
 
+    // Type of the change tracker with a counter for every tracked field.
+    fn change_tracker_type(&'a self) -> darling::Result<TokenStream> {
+        let ctx = self.ctx();
+        let span = ctx.arg_props().track_changes().final_span();
+        let tracker = ctx.impl_details().change_tracker(span);
+        let count = ctx.impl_ctx().tracked_fields()?.len();
+        Ok(quote_spanned! {span=> #tracker<#count>})
+    }
+
+    fn change_tracking(&'a self) -> darling::Result<()> {
+        let ctx = self.ctx();
+        let arg_props = ctx.arg_props();
+        let track_changes = arg_props.track_changes();
+
+        if !*track_changes {
+            return Ok(());
+        }
+
+        let span = track_changes.final_span();
+        let vis = ctx.input().vis();
+        let changes_field = arg_props.changes_field_ident();
+        let impl_ctx = ctx.impl_ctx();
+        let mut names = vec![];
+
+        ctx.add_field_decl(FXFieldConstructor::new(
+            changes_field.clone(),
+            self.change_tracker_type()?,
+            span,
+        ));
+
+        for (idx, ident) in impl_ctx.tracked_fields()?.iter().enumerate() {
+            let fctx = ctx.ident_field_ctx(ident)?;
+            let base_name = fctx.props().base_name();
+            let fspan = base_name.span();
+            let name = ident.unraw().to_string();
+
+            let mut dirty_mc = FXFnConstructor::new(format_ident!("is_{}_dirty", base_name, span = fspan));
+            dirty_mc
+                .set_span(fspan)
+                .set_vis(vis)
+                .set_ret_type(quote_spanned! {fspan=> bool})
+                .set_ret_stmt(quote_spanned! {fspan=> self.#changes_field.is_dirty(#idx)})
+                .add_attribute_toks(quote_spanned! {fspan=> #[allow(dead_code)] #[inline]})?
+                .add_doc(&FXProp::new(
+                    vec![syn::LitStr::new(
+                        &format!("Tells if field `{name}` has changed since the last `mark_clean()`."),
+                        fspan,
+                    )],
+                    Some(fspan),
+                ))?;
+
+            let mut version_mc = FXFnConstructor::new(format_ident!("version_{}", base_name, span = fspan));
+            version_mc
+                .set_span(fspan)
+                .set_vis(vis)
+                .set_ret_type(quote_spanned! {fspan=> u64})
+                .set_ret_stmt(quote_spanned! {fspan=> self.#changes_field.version(#idx)})
+                .add_attribute_toks(quote_spanned! {fspan=> #[allow(dead_code)] #[inline]})?
+                .add_doc(&FXProp::new(
+                    vec![syn::LitStr::new(
+                        &format!("Number of times field `{name}` has been changed by its setter, writer, or clearer."),
+                        fspan,
+                    )],
+                    Some(fspan),
+                ))?;
+
+            ctx.add_method(dirty_mc);
+            ctx.add_method(version_mc);
+            names.push(name);
+        }
+
+        let mut dirty_fields_mc = FXFnConstructor::new(format_ident!("dirty_fields", span = span));
+        dirty_fields_mc
+            .set_span(span)
+            .set_vis(vis)
+            .set_ret_type(quote_spanned! {span=> ::std::vec::Vec<&'static str>})
+            .set_ret_stmt(quote_spanned! {span=> self.#changes_field.dirty_fields(&[ #( #names ),* ])})
+            .add_attribute_toks(quote_spanned! {span=> #[allow(dead_code)]})?
+            .add_doc(&FXProp::new(
+                vec![parse_quote_spanned! {span=> "Names of the fields changed since the last `mark_clean()`."}],
+                Some(span),
+            ))?;
+
+        let mut mark_clean_mc = FXFnConstructor::new(format_ident!("mark_clean", span = span));
+        mark_clean_mc
+            .set_span(span)
+            .set_vis(vis)
+            .set_ret_stmt(quote_spanned! {span=> self.#changes_field.mark_clean()})
+            .add_attribute_toks(quote_spanned! {span=> #[allow(dead_code)]})?
+            .add_doc(&FXProp::new(
+                vec![parse_quote_spanned! {span=> "Marks all fields as unchanged."}],
+                Some(span),
+            ))?;
+
+        ctx.add_method(dirty_fields_mc);
+        ctx.add_method(mark_clean_mc);
+
+        Ok(())
+    }
+
+    // The transaction struct holds write guards of all non-lazy lock-protected fields. The guards are acquired in
+    // the order the fields are declared in, hence concurrent transactions cannot deadlock each other.
+    fn transaction_struct(&'a self) -> darling::Result<TokenStream> {
+        let ctx = self.ctx();
+        let arg_props = ctx.arg_props();
+        let transaction = arg_props.transaction();
+
+        if !*transaction {
+            return Ok(quote![]);
+        }
+
+        let span = transaction.final_span();
+
+        if !*ctx.syncish() {
+            return Err(darling::Error::custom("transaction requires sync or async mode").with_span(&span));
+        }
+
+        let tx_ident = arg_props.transaction_struct_ident();
+        let vis = arg_props.transaction_visibility();
+        let lifetime = quote_spanned! {span=> 'fx_tx};
+        let input_ident = ctx.input_ident();
+        let struct_generic_params = ctx.struct_generic_params();
+
+        let mut tx_generics = ctx.input().generics().clone();
+        tx_generics.params.insert(0, parse_quote_spanned! {span=> #lifetime});
+        let tx_generic_params = tx_generics.split_for_impl().1.to_token_stream();
+
+        let mut tx_struct = FXStructConstructor::new(tx_ident.clone());
+        tx_struct
+            .set_span(span)
+            .set_vis(vis)
+            .set_generics(tx_generics.clone())
+            .add_doc(&FXProp::new(
+                vec![parse_quote_spanned! {span=> "Lock-protected fields of the struct, locked for writing."}],
+                Some(span),
+            ))?;
+
+        let mut is_async = FXProp::new(false, None);
+        let mut guards = vec![];
+        let mut locked_groups = vec![];
+
+        for fctx in ctx.all_field_ctx() {
+            if fctx.extra() || *fctx.skipped() || !*fctx.lock() || *fctx.lazy() {
+                continue;
+            }
+
+            let fspan = fctx.lock().final_span();
+            let ident = fctx.ident();
+            let implementor = fctx.impl_details();
+            let await_call = implementor.await_call(fspan);
+            let ty = fctx.ty();
+            let optional = fctx.optional();
+            let value_ty = if *optional {
+                quote_spanned! {fspan=> ::std::option::Option<#ty>}
+            }
+            else {
+                ty.to_token_stream()
+            };
+
+            is_async = is_async.or(fctx.mode_async());
+
+            // Members of a lock group are reached via the guard of the group which is only locked once.
+            let value_place = if let Some(group) = fctx.lock_group() {
+                let group_field_ident = arg_props.lock_group_field_ident(group);
+                if !locked_groups.contains(&group_field_ident) {
+                    let gspan = group.span();
+                    let guard_type = implementor.rwlock_write_guard(gspan)?;
+                    let group_struct_ident = arg_props.lock_group_struct_ident(group);
+                    tx_struct.add_field(FXFieldConstructor::new(
+                        group_field_ident.clone(),
+                        quote_spanned! {gspan=> #guard_type<#lifetime, #group_struct_ident #struct_generic_params>},
+                        gspan,
+                    ));
+                    guards.push(quote_spanned! {gspan=>
+                        #group_field_ident: self.#group_field_ident.write()#await_call
+                    });
+                    locked_groups.push(group_field_ident.clone());
+                }
+                quote_spanned! {fspan=> self.#group_field_ident.#ident}
+            }
+            else {
+                let (guard_type, lock_method) = if *fctx.lock_mutex() {
+                    (implementor.mutex_guard(fspan)?, format_ident!("lock", span = fspan))
+                }
+                else {
+                    (implementor.rwlock_write_guard(fspan)?, format_ident!("write", span = fspan))
+                };
+                tx_struct.add_field(FXFieldConstructor::new(
+                    ident.clone(),
+                    quote_spanned! {fspan=> #guard_type<#lifetime, #value_ty>},
+                    fspan,
+                ));
+                guards.push(quote_spanned! {fspan=> #ident: self.#ident.#lock_method()#await_call});
+                quote_spanned! {fspan=> *self.#ident}
+            };
+
+            let mut accessor = FXFnConstructor::new(fctx.accessor_ident().clone());
+            accessor
+                .set_span(fspan)
+                .set_vis(vis)
+                .set_ret_type(quote_spanned! {fspan=> &#value_ty})
+                .set_ret_stmt(quote_spanned! {fspan=> &#value_place})
+                .add_attribute_toks(quote_spanned! {fspan=> #[allow(dead_code)] #[inline(always)]})?;
+
+            let mut accessor_mut = FXFnConstructor::new(fctx.accessor_mut_ident().clone());
+            accessor_mut
+                .set_span(fspan)
+                .set_vis(vis)
+                .set_self_mut(true)
+                .set_ret_type(quote_spanned! {fspan=> &mut #value_ty})
+                .set_ret_stmt(quote_spanned! {fspan=> &mut #value_place})
+                .add_attribute_toks(quote_spanned! {fspan=> #[allow(dead_code)] #[inline(always)]})?;
+
+            let mut setter = FXFnConstructor::new(fctx.setter_ident().clone());
+            setter
+                .set_span(fspan)
+                .set_vis(vis)
+                .set_self_mut(true)
+                .add_param(quote_spanned! {fspan=> value: #ty})
+                .set_ret_type(value_ty.clone())
+                .set_ret_stmt(if *optional {
+                    quote_spanned! {fspan=> ::std::option::Option::replace(&mut #value_place, value)}
+                }
+                else {
+                    quote_spanned! {fspan=> ::std::mem::replace(&mut #value_place, value)}
+                })
+                .add_attribute_toks(quote_spanned! {fspan=> #[allow(dead_code)] #[inline(always)]})?;
+
+            self.field_codegen(&fctx)?.maybe_track_change(&fctx, &mut setter, false)?;
+
+            tx_struct
+                .struct_impl_mut()
+                .add_method(accessor)
+                .add_method(accessor_mut)
+                .add_method(setter);
+        }
+
+        if guards.is_empty() {
+            return Err(
+                darling::Error::custom("transaction requires at least one non-lazy lock-protected field")
+                    .with_span(&span),
+            );
+        }
+
+        if *arg_props.track_changes() {
+            let changes_field_type = self.change_tracker_type()?;
+            // Setters of the transaction object bump field versions the same way the struct setters do.
+            let changes_field = arg_props.changes_field_ident();
+            tx_struct.add_field(FXFieldConstructor::new(
+                changes_field.clone(),
+                quote_spanned! {span=> &#lifetime #changes_field_type},
+                span,
+            ));
+            guards.push(quote_spanned! {span=> #changes_field: &self.#changes_field});
+        }
+
+        // The phantom field binds the lifetime and generic parameters even if none of the field types use them.
+        tx_struct.add_field(FXFieldConstructor::new(
+            format_ident!("__fx_phantom", span = span),
+            quote_spanned! {span=> ::std::marker::PhantomData<&#lifetime #input_ident #struct_generic_params>},
+            span,
+        ));
+
+        let transaction_doc = arg_props.transaction_doc().cloned().or_else(|| {
+            Some(FXProp::new(
+                vec![parse_quote_spanned![span=>
+                    "Locks all lock-protected fields for writing and passes them to the callback as a single transaction."
+                ]],
+                Some(span),
+            ))
+        });
+
+        let mut mc = FXFnConstructor::new(arg_props.transaction_ident().cloned().unwrap());
+        mc.set_span(span)
+            .set_vis(vis)
+            .set_async(is_async)
+            .set_self_lifetime(lifetime.clone())
+            .maybe_add_generic(Some(quote_spanned! {span=> FXTxR}))
+            .add_param(quote_spanned! {span=> f: impl FnOnce(&mut #tx_ident #tx_generic_params) -> FXTxR})
+            .set_ret_type(quote_spanned! {span=> FXTxR})
+            .add_statement(quote_spanned! {span=>
+                let mut tx = #tx_ident {
+                    #( #guards, )*
+                    __fx_phantom: ::std::marker::PhantomData,
+                };
+            })
+            .set_ret_stmt(quote_spanned! {span=> f(&mut tx)})
+            .maybe_add_doc(transaction_doc.as_ref())?
+            .add_attribute_toks(quote_spanned! {span=> #[allow(dead_code)]})?;
+
+        ctx.add_method(mc);
+
+        Ok(tx_struct.to_token_stream())
+    }
+
+    // Builds a struct with the fields of the user struct wrapped into an `Option`, and statements applying values of
+    // its instance, named `source`, to the fields of `self`. The statements inline the bodies of field setters and
+    // clearers, hence locks, inner mutability and change tracking work the same way as when the helpers are called.
+    pub(crate) fn partial_struct(
+        &'a self,
+        ident: &syn::Ident,
+        vis: &syn::Visibility,
+        source: &syn::Ident,
+        derive_deserialize: bool,
+        field_kind: impl Fn(&FXDeriveFieldCtx) -> Option<FXPartialField>,
+    ) -> darling::Result<FXPartialStruct> {
+        let ctx = self.ctx();
+        let generics = ctx.input().generics();
+        let span = ident.span();
+
+        let mut partial = FXPartialStruct {
+            struct_ctor:    FXStructConstructor::new(ident.clone()),
+            field_defaults: vec![],
+            statements:     vec![],
+            is_async:       FXProp::new(false, None),
+            mut_fields:     vec![],
+            fields:         vec![],
+        };
+
+        partial.struct_ctor.set_span(span).set_vis(vis).set_generics(generics.clone());
+
+        #[cfg(feature = "serde")]
+        if derive_deserialize {
+            partial
+                .struct_ctor
+                .add_attribute_toks(quote_spanned! {span=> #[derive(Deserialize)]})?;
+        }
+        #[cfg(not(feature = "serde"))]
+        let _ = derive_deserialize;
+
+        for fctx in ctx.all_field_ctx() {
+            if fctx.extra() || *fctx.skipped() {
+                continue;
+            }
+
+            let Some(kind) = field_kind(&fctx)
+            else {
+                continue;
+            };
+
+            let fspan = fctx.span();
+            let ident = fctx.ident();
+            let ty = fctx.ty();
+            let cgen = self.field_codegen(&fctx)?;
+
+            let mut setter = FXFnConstructor::new(fctx.setter_ident().clone());
+            cgen.field_setter_body(&fctx, &mut setter, fspan)?;
+            let set_untracked = Self::partial_body_toks(&setter);
+            cgen.maybe_track_change(&fctx, &mut setter, false)?;
+            let set_body = setter.body();
+            let set_ret = setter.ret_stmt();
+            let set_toks = quote_spanned! {fspan=>
+                #( #set_body )*
+                let _ = #set_ret;
+            };
+
+            let mut is_async = setter.is_async();
+            let mut self_mut = *setter.self_mut();
+
+            let clear_untracked = if *fctx.optional() || *fctx.lazy() {
+                let mut clearer = FXFnConstructor::new(fctx.clearer_ident().clone());
+                cgen.field_clearer_body(&fctx, &mut clearer, fspan)?;
+                is_async |= clearer.is_async();
+                self_mut |= *clearer.self_mut();
+                Some(Self::partial_body_toks(&clearer))
+            }
+            else {
+                None
+            };
+
+            let mut pfield = if let FXPartialField::Clearable { clear_missing } = kind {
+                let mut clearer = FXFnConstructor::new(fctx.clearer_ident().clone());
+                cgen.field_clearer_body(&fctx, &mut clearer, fspan)?;
+                cgen.maybe_track_change(&fctx, &mut clearer, false)?;
+                let clear_body = clearer.body();
+                let clear_ret = clearer.ret_stmt();
+                let clear_toks = quote_spanned! {fspan=>
+                    #( #clear_body )*
+                    let _ = #clear_ret;
+                };
+                let missing_toks = if clear_missing {
+                    clear_toks.clone()
+                }
+                else {
+                    quote![]
+                };
+
+                is_async |= clearer.is_async();
+                self_mut |= *clearer.self_mut();
+
+                partial.statements.push(quote_spanned! {fspan=>
+                    match #source.#ident {
+                        ::std::option::Option::Some(::std::option::Option::Some(value)) => { #set_toks }
+                        ::std::option::Option::Some(::std::option::Option::None) => { #clear_toks }
+                        ::std::option::Option::None => { #missing_toks }
+                    }
+                });
+                FXFieldConstructor::new(
+                    ident.clone(),
+                    quote_spanned! {fspan=> ::std::option::Option<::std::option::Option<#ty>>},
+                    fspan,
+                )
+            }
+            else {
+                partial.statements.push(quote_spanned! {fspan=>
+                    if let ::std::option::Option::Some(value) = #source.#ident {
+                        #set_toks
+                    }
+                });
+                FXFieldConstructor::new(ident.clone(), quote_spanned! {fspan=> ::std::option::Option<#ty>}, fspan)
+            };
+
+            #[cfg(feature = "serde")]
+            if derive_deserialize {
+                let serde_span = fctx.serde().final_span();
+                if !*fctx.serde() || !*fctx.deserialize() {
+                    pfield.add_attribute_toks(quote_spanned! {serde_span=> #[serde(skip)]})?;
+                }
+                else {
+                    if matches!(kind, FXPartialField::Clearable { .. }) {
+                        // Tell a missing key from an explicit `null`.
+                        pfield.add_attribute_toks(quote_spanned! {serde_span=>
+                            #[serde(default, deserialize_with = "::fieldx::serde::deserialize_some")]
+                        })?;
+                    }
+                    if let Some(rename) = fctx.serde_rename_deserialize() {
+                        let rename = rename.to_string();
+                        pfield.add_attribute_toks(quote_spanned! {serde_span=> #[serde(rename = #rename)]})?;
+                    }
+                }
+            }
+
+            pfield
+                .set_vis(vis)
+                .add_attributes(fctx.props().field_props().doc().iter());
+            partial.struct_ctor.add_field(pfield);
+            partial
+                .field_defaults
+                .push(quote_spanned! {fspan=> #ident: ::std::option::Option::None});
+
+            partial.is_async = partial.is_async.or(FXProp::new(is_async, Some(fspan)));
+            if self_mut {
+                partial.mut_fields.push(ident.clone());
+            }
+            partial.fields.push(FXPartialFieldParts {
+                fctx: fctx.clone(),
+                kind,
+                set: set_untracked,
+                clear: clear_untracked,
+            });
+        }
+
+        if !generics.params.is_empty() {
+            let struct_type = ctx.struct_type_toks();
+            // Binds generic parameters of the user struct that are not used by the partial fields.
+            #[allow(unused_mut)]
+            let mut phantom = FXFieldConstructor::new(
+                format_ident!("__fx_phantom", span = span),
+                quote_spanned! {span=> ::std::marker::PhantomData<fn() -> #struct_type>},
+                span,
+            );
+            #[cfg(feature = "serde")]
+            if derive_deserialize {
+                phantom.add_attribute_toks(quote_spanned! {span=> #[serde(skip)]})?;
+            }
+            partial.struct_ctor.add_field(phantom);
+            partial
+                .field_defaults
+                .push(quote_spanned! {span=> __fx_phantom: ::std::marker::PhantomData});
+        }
+
+        Ok(partial)
+    }
+
+    // The patch struct mirrors the user struct with every field wrapped into an `Option`. Fields with a clearer get
+    // an `Option<Option<T>>` where `Some(None)` clears the field.
+    fn patch_struct(&'a self) -> darling::Result<TokenStream> {
+        let ctx = self.ctx();
+        let arg_props = ctx.arg_props();
+        let patch = arg_props.patch();
+
+        if !*patch {
+            return Ok(quote![]);
+        }
+
+        let span = patch.final_span();
+        let patch_ident = arg_props.patch_struct_ident();
+        let vis = arg_props.patch_visibility();
+        let generics = ctx.input().generics();
+        let struct_generic_params = ctx.struct_generic_params();
+        let patch_var = format_ident!("patch", span = span);
+
+        #[cfg(feature = "serde")]
+        let derive_deserialize = *arg_props.serde() && *arg_props.needs_deserialize();
+        #[cfg(not(feature = "serde"))]
+        let derive_deserialize = false;
+
+        let mut partial = self.partial_struct(patch_ident, vis, &patch_var, derive_deserialize, |fctx| {
+            Some(if *fctx.clearer() {
+                FXPartialField::Clearable { clear_missing: false }
+            }
+            else {
+                FXPartialField::Set
+            })
+        })?;
+
+        partial.struct_ctor.set_span(span).add_doc(&FXProp::new(
+            vec![parse_quote_spanned! {span=> "Partial update of the struct; `None` fields are left intact."}],
+            Some(span),
+        ))?;
+
+        // Deriving would require all generic parameters to implement `Default`.
+        let field_defaults = &partial.field_defaults;
+        let default_path: syn::Path = syn::parse2(quote_spanned! {span=> ::std::default::Default})?;
+        let mut default_impl = FXImplConstructor::new(default_path);
+        let mut default_fn = FXFnConstructor::new_associated(format_ident!("default", span = span));
+        default_fn
+            .set_span(span)
+            .set_ret_type(format_ident!("Self", span = span))
+            .set_ret_stmt(quote_spanned! {span=> Self { #( #field_defaults ),* } });
+        default_impl
+            .set_span(span)
+            .set_from_generics(Some(generics.clone()))
+            .set_for_ident(patch_ident)
+            .add_method(default_fn);
+        partial.struct_ctor.add_trait_impl(default_impl);
+
+        let patch_doc = arg_props.patch_doc().cloned().or_else(|| {
+            Some(FXProp::new(
+                vec![parse_quote_spanned![span=> "Updates the fields which are set in the patch."]],
+                Some(span),
+            ))
+        });
+
+        let mut mc = FXFnConstructor::new(arg_props.patch_ident().cloned().unwrap());
+        mc.set_span(span)
+            .set_vis(vis)
+            .set_async(partial.is_async)
+            .set_self_mut(!partial.mut_fields.is_empty())
+            .add_param(quote_spanned! {span=> #patch_var: #patch_ident #struct_generic_params})
+            .maybe_add_doc(patch_doc.as_ref())?
+            .add_attribute_toks(quote_spanned! {span=> #[allow(dead_code, clippy::useless_conversion)]})?;
+
+        if arg_props.builder_checks().is_some() {
+            self.checked_patch_body(&partial, &patch_var, &mut mc)?;
+        }
+        else {
+            let statements = &partial.statements;
+            mc.add_statement(quote_spanned! {span=> #( #statements )* });
+        }
+
+        ctx.add_method(mc);
+
+        Ok(partial.struct_ctor.to_token_stream())
+    }
+
+    // With builder checks a patch is applied to the merged state of the object: lock-protected fields are locked for
+    // writing in the order of their declaration, the patch is written through the guards, and the guards are then
+    // downgraded to upgradable ones to let the checks read the fields. If any check fails the guards are upgraded
+    // back and the previous values are restored. Writers are kept out all the way through.
+    fn checked_patch_body(
+        &'a self,
+        partial: &FXPartialStruct,
+        source: &syn::Ident,
+        mc: &mut FXFnConstructor,
+    ) -> darling::Result<()> {
+        let ctx = self.ctx();
+        let arg_props = ctx.arg_props();
+        let span = mc.span();
+        let checks_method = Self::builder_checks_ident(span);
+        let failed = format_ident!("__fx_failed", span = span);
+
+        let mut lock_stmts = vec![];
+        let mut downgrade_stmts = vec![];
+        let mut upgrade_stmts = vec![];
+        let mut apply_stmts = vec![];
+        let mut restore_stmts = vec![];
+        let mut bump_stmts = vec![];
+        let mut locked_groups: Vec<syn::Ident> = vec![];
+
+        for FXPartialFieldParts { fctx, kind, set, clear } in partial.fields.iter() {
+            let ident = fctx.ident();
+            let fspan = fctx.span();
+            let lock = fctx.lock();
+            let old = format_ident!("__fx_old_{}", ident.unraw(), span = fspan);
+            let value_src = quote_spanned! {fspan=> #source.#ident};
+
+            // Lazy fields with locks of their own are written via their proxies.
+            let place = if *ctx.syncish() && *lock && (!*fctx.lazy() || fctx.lock_group().is_some()) {
+                let lspan = lock.final_span();
+                let implementor = fctx.impl_details();
+                let await_call = implementor.await_call(lspan);
+
+                if *fctx.lock_mutex() {
+                    return Err(darling::Error::custom(
+                        "mutex-protected fields can't be patched with builder checks since mutexes can't be shared \
+                         with the checks",
+                    )
+                    .with_span(&fctx.lock_mutex().final_span()));
+                }
+                if *fctx.mode_async() && !cfg!(feature = "async-lock") {
+                    return Err(darling::Error::custom(
+                        "patching with builder checks requires upgradable locks of the `async-lock` backend",
+                    )
+                    .with_span(&lspan));
+                }
+
+                let (guard, lock_field, place) = if let Some(group) = fctx.lock_group() {
+                    let group_field = arg_props.lock_group_field_ident(group);
+                    let guard = format_ident!("__fx_guard_{}", group_field.unraw(), span = lspan);
+                    let place = quote_spanned! {fspan=> #guard.#ident};
+                    if locked_groups.contains(&group_field) {
+                        (None, group_field, place)
+                    }
+                    else {
+                        locked_groups.push(group_field.clone());
+                        (Some(guard), group_field, place)
+                    }
+                }
+                else {
+                    let guard = format_ident!("__fx_guard_{}", ident.unraw(), span = lspan);
+                    let place = quote_spanned! {fspan=> *#guard};
+                    (Some(guard), ident.clone(), place)
+                };
+
+                if let Some(guard) = guard {
+                    let guard_toks = guard.to_token_stream();
+                    let downgrade = implementor.rwlock_downgrade_upgradable(&guard_toks, lspan)?;
+                    let upgrade = implementor.rwlock_upgrade(&guard_toks, lspan)?;
+                    lock_stmts.push(quote_spanned! {lspan=> let mut #guard = self.#lock_field.write()#await_call;});
+                    downgrade_stmts.push(quote_spanned! {lspan=> let #guard = #downgrade;});
+                    upgrade_stmts.push(quote_spanned! {lspan=> let mut #guard = #upgrade;});
+                }
+
+                Some(place)
+            }
+            else {
+                None
+            };
+
+            if let Some(place) = place {
+                let set = if *fctx.optional() || *fctx.lazy() {
+                    quote_spanned! {fspan=> (#place).replace(value)}
+                }
+                else {
+                    quote_spanned! {fspan=> ::std::mem::replace(&mut #place, value)}
+                };
+                let clear = quote_spanned! {fspan=> (#place).take()};
+                apply_stmts.push(Self::checked_patch_apply(&old, &value_src, *kind, &set, Some(&clear), fspan));
+                restore_stmts.push(quote_spanned! {fspan=>
+                    if let ::std::option::Option::Some(old) = #old {
+                        #place = old;
+                    }
+                });
+            }
+            else {
+                apply_stmts.push(Self::checked_patch_apply(&old, &value_src, *kind, set, clear.as_ref(), fspan));
+                restore_stmts.push(if *fctx.swap() {
+                    quote_spanned! {fspan=>
+                        if let ::std::option::Option::Some(old) = #old {
+                            self.#ident.store(old);
+                        }
+                    }
+                }
+                else if let Some(clear) = clear {
+                    quote_spanned! {fspan=>
+                        match #old {
+                            ::std::option::Option::Some(::std::option::Option::Some(value)) => {
+                                let _ = #set;
+                            }
+                            ::std::option::Option::Some(::std::option::Option::None) => {
+                                let _ = #clear;
+                            }
+                            ::std::option::Option::None => (),
+                        }
+                    }
+                }
+                else {
+                    quote_spanned! {fspan=>
+                        if let ::std::option::Option::Some(value) = #old {
+                            let _ = #set;
+                        }
+                    }
+                });
+            }
+
+            if let Some(idx) = ctx.impl_ctx().change_index(ident)? {
+                let changes_field = arg_props.changes_field_ident();
+                bump_stmts.push(quote_spanned! {fspan=>
+                    if #old.is_some() {
+                        self.#changes_field.bump(#idx);
+                    }
+                });
+            }
+        }
+
+        mc.set_ret_type(quote_spanned! {span=> ::std::result::Result<(), ::fieldx::error::FieldXError>})
+            .add_statement(quote_spanned! {span=>
+                #( #lock_stmts )*
+                #( #apply_stmts )*
+                #( #downgrade_stmts )*
+                let #failed = self.#checks_method();
+                if !#failed.is_empty() {
+                    #( #upgrade_stmts )*
+                    #( #restore_stmts )*
+                    return ::std::result::Result::Err(::fieldx::error::FieldXError::validation(#failed));
+                }
+                #( #bump_stmts )*
+            })
+            .set_ret_stmt(quote_spanned! {span=> ::std::result::Result::Ok(())});
+
+        Ok(())
+    }
+
+    // Statement applying a field of a partial struct with `set` and `clear` expressions which evaluate into the
+    // previous value. The previous value is kept in the `old` variable, which is `None` if the field is left intact.
+    fn checked_patch_apply(
+        old: &syn::Ident,
+        value_src: &TokenStream,
+        kind: FXPartialField,
+        set: &TokenStream,
+        clear: Option<&TokenStream>,
+        span: Span,
+    ) -> TokenStream {
+        if let (FXPartialField::Clearable { clear_missing }, Some(clear)) = (kind, clear) {
+            let missing = if clear_missing {
+                quote_spanned! {span=> ::std::option::Option::Some(#clear)}
+            }
+            else {
+                quote_spanned! {span=> ::std::option::Option::None}
+            };
+            quote_spanned! {span=>
+                let #old = match #value_src {
+                    ::std::option::Option::Some(::std::option::Option::Some(value)) => {
+                        ::std::option::Option::Some(#set)
+                    }
+                    ::std::option::Option::Some(::std::option::Option::None) => ::std::option::Option::Some(#clear),
+                    ::std::option::Option::None => #missing,
+                };
+            }
+        }
+        else {
+            quote_spanned! {span=>
+                let #old = match #value_src {
+                    ::std::option::Option::Some(value) => ::std::option::Option::Some(#set),
+                    ::std::option::Option::None => ::std::option::Option::None,
+                };
+            }
+        }
+    }
+
+    fn partial_body_toks(mc: &FXFnConstructor) -> TokenStream {
+        let body = mc.body();
+        let ret_stmt = mc.ret_stmt();
+        quote! {{
+            #( #body )*
+            #ret_stmt
+        }}
+    }
+
+    fn lock_group_structs(&'a self) -> Vec<TokenStream> {
+        let ctx = self.ctx();
+        let struct_type = ctx.struct_type_toks();
+
+        ctx.impl_ctx()
+            .take_lock_groups()
+            .into_iter()
+            .map(|mut group_struct| {
+                let span = group_struct.span().unwrap_or_else(Span::call_site);
+                // Binds generic parameters of the user struct that are not used by the group members.
+                group_struct.add_field(FXFieldConstructor::new(
+                    format_ident!("__fx_phantom", span = span),
+                    quote_spanned! {span=> ::std::marker::PhantomData<fn() -> #struct_type>},
+                    span,
+                ));
+                group_struct.to_token_stream()
+            })
+            .collect()
+    }
+
     fn default_impl(&self) {
         let ctx = self.ctx();
 
@@ -349,9 +1221,10 @@ impl<'a> FXRewriter<'a> {
                 .default_expr()
                 .clone()
                 .unwrap_or_else(|| std_default_expr_toks(span));
-            // This is a standard default expression `Default::default()` when StdDefault is the only flag set.
-            all_std &= default_expr.flags == FXValueFlag::StdDefault as u8;
-            defaults.push(default_expr);
+            // This is a standard default expression `Default::default()` when StdDefault is the only flag set. Lock
+            // group members are not standalone fields and cannot be defaulted by a derived implementation.
+            all_std &= default_expr.flags == FXValueFlag::StdDefault as u8 && fctx.lock_group().is_none();
+            defaults.push((fctx, default_expr.to_token_stream()));
         }
 
         if all_std {
@@ -360,6 +1233,14 @@ impl<'a> FXRewriter<'a> {
             return;
         }
 
+        let defaults = match self.field_inits(defaults) {
+            Ok(defaults) => defaults,
+            Err(err) => {
+                ctx.push_error(err);
+                return;
+            }
+        };
+
         if !defaults.is_empty() {
             let ident_path: syn::Path = syn::parse2(quote_spanned! {span=> ::std::default::Default}).unwrap();
             let mut default_impl = FXImplConstructor::new(ident_path);
@@ -424,9 +1305,11 @@ impl<'a> FXRewriter<'a> {
         let input_ident = ctx.input_ident();
         let post_build_ident = arg_props.post_build_ident().cloned();
 
-        let mut field_setters = Vec::<TokenStream>::new();
+        let mut field_setters = Vec::<(Rc<FXDeriveFieldCtx>, TokenStream)>::new();
         let mut builder_checkers = vec![];
         let mut fields_new = vec![];
+        let mut by_name_fctxs = vec![];
+        let mut has_deferred = false;
 
         for fctx in self.builder_field_ctxs()? {
             if let Ok(fctx) = fctx {
@@ -444,7 +1327,7 @@ impl<'a> FXRewriter<'a> {
                 fgen.field_builder_value_required(&fctx);
 
                 match fgen.field_builder_setter(&fctx) {
-                    Ok(fsetter) => field_setters.push(fsetter),
+                    Ok(fsetter) => field_setters.push((fctx.clone(), fsetter)),
                     Err(err) => {
                         ctx.push_error(err);
                         continue;
@@ -454,36 +1337,111 @@ impl<'a> FXRewriter<'a> {
                 if let Some(bchecker) = fctx.builder_checker() {
                     builder_checkers.push(bchecker);
                 }
+
+                if *fctx.forced_builder().or(fctx.builder()) {
+                    has_deferred |= *fctx.builder_try_into() && *fctx.builder_try_into_defer();
+                    if fctx.props().field_props().builder_from_map().map_or(true, |fm| *fm) {
+                        by_name_fctxs.push(fctx);
+                    }
+                }
             }
             else {
                 ctx.push_error(fctx.unwrap_err());
             }
         }
 
+        if has_deferred {
+            let errors = deferred_errors_ident(span);
+            let mut fc = FXFieldConstructor::new(
+                errors.clone(),
+                quote_spanned! {span=> ::std::vec::Vec<(&'static str, ::std::string::String)>},
+                span,
+            );
+            fc.add_attribute_toks(quote_spanned! {span=> #[doc(hidden)]})?;
+            ctx.impl_ctx().add_builder_field(fc)?;
+            fields_new.push(quote_spanned! {span=> #errors: ::std::vec::Vec::new() });
+
+            let mut conversion_error = quote_spanned! {span=>
+                ::fieldx::error::FieldXError::field_conversion(*field, message)
+            };
+            if let Some(variant) = arg_props.builder_error_variant() {
+                conversion_error = quote_spanned! {span=>
+                    #variant(::std::string::ToString::to_string(&#conversion_error).into())
+                };
+            }
+            else if arg_props.builder_error_type().is_some() {
+                conversion_error = quote_spanned! {span=> ::std::convert::Into::into(#conversion_error)};
+            }
+            build_method.add_statement(quote_spanned! {span=>
+                if let ::std::option::Option::Some((field, message)) = self.#errors.first() {
+                    // The conversion is a no-op when the error variant takes a `String`.
+                    #[allow(clippy::useless_conversion)]
+                    let err = #conversion_error;
+                    return ::std::result::Result::Err(err);
+                }
+            });
+        }
+
         new_method.set_ret_stmt(quote_spanned! {span=>
             Self {
                 #( #fields_new ),*
             }
         });
 
+        let field_setters = self.field_inits(field_setters)?;
+        let checks_method = self.builder_checks_method()?;
+        let mut failed_checks = ctx.unique_ident_pfx("failed_checks");
+        failed_checks.set_span(span);
+
         let construction = cgen.maybe_ref_counted_create(
             &input_ident.to_token_stream(),
             &quote_spanned! {span=>
                     #(#field_setters,)*
             },
             post_build_ident,
+            checks_method.as_ref().map(|cm| (cm, &failed_checks)),
         );
 
         let mut obj_ident = ctx.unique_ident_pfx("obj");
         obj_ident.set_span(span);
 
         build_method.add_statement(quote_spanned! {span=> #( #builder_checkers );* });
-        // Some field setter expressions (the simpler ones) are semantically identical to unwrap_or. There is currently
-        // no efficient way to determine if an expression is simple. Therefore, we disable the manual_unwrap_or lint
-        // for the entire initialization.
-        build_method.add_statement(quote_spanned! {span=>
-            let #obj_ident: #builder_return_type = #construction;
-        });
+
+        if let Some(ref checks_method) = checks_method {
+            let cm_span = checks_method.span();
+            let mut validation_error = quote_spanned! {cm_span=>
+                ::fieldx::error::FieldXError::validation(#failed_checks)
+            };
+            if let Some(variant) = arg_props.builder_error_variant() {
+                // Like with uninitialized fields, the explicit variant receives the error message.
+                validation_error = quote_spanned! {cm_span=>
+                    #variant(::std::string::ToString::to_string(&#validation_error).into())
+                };
+            }
+            else if arg_props.builder_error_type().is_some() {
+                validation_error = quote_spanned! {cm_span=> ::std::convert::Into::into(#validation_error)};
+            }
+
+            build_method.add_statement(quote_spanned! {cm_span=>
+                let mut #failed_checks: ::std::vec::Vec<::std::string::String> = ::std::vec::Vec::new();
+            });
+            build_method.add_statement(quote_spanned! {span=>
+                let #obj_ident: #builder_return_type = #construction;
+            });
+            build_method.add_statement(quote_spanned! {cm_span=>
+                if !#failed_checks.is_empty() {
+                    // The conversion is a no-op when the error variant takes a `String`.
+                    #[allow(clippy::useless_conversion)]
+                    let err = #validation_error;
+                    return ::std::result::Result::Err(err);
+                }
+            });
+        }
+        else {
+            build_method.add_statement(quote_spanned! {span=>
+                let #obj_ident: #builder_return_type = #construction;
+            });
+        }
         build_method.set_ret_stmt(quote_spanned! {span=> Ok(#obj_ident) });
 
         let impl_ctx = ctx.impl_ctx();
@@ -492,9 +1450,132 @@ impl<'a> FXRewriter<'a> {
         bic.add_method(new_method);
         bic.add_method(build_method);
 
+        let from_map = arg_props.builder_from_map();
+        if *from_map {
+            for method in self.builder_from_map_methods(&by_name_fctxs, from_map.final_span())? {
+                bic.add_method(method);
+            }
+        }
+
         Ok(())
     }
 
+    fn builder_checks_ident(span: Span) -> syn::Ident {
+        format_ident!("__fieldx_builder_checks", span = span)
+    }
+
+    // Add a method to the user struct that evaluates builder `check` rules and returns messages of the failed ones.
+    fn builder_checks_method(&'a self) -> darling::Result<Option<syn::Ident>> {
+        let ctx = self.ctx();
+        let arg_props = ctx.arg_props();
+
+        let Some(checks) = arg_props.builder_checks()
+        else {
+            return Ok(None);
+        };
+
+        let span = checks.first().map_or_else(Span::call_site, |c| c.final_span());
+        let method_ident = Self::builder_checks_ident(span);
+        let mut mc = FXFnConstructor::new(method_ident.clone());
+
+        mc.add_statement(quote_spanned! {span=>
+            let mut failed = ::std::vec::Vec::new();
+        });
+
+        for check in checks.iter() {
+            let (rule, message) = check.value();
+            let check_span = check.final_span();
+            let rule = match rule {
+                // A bare identifier is a method name.
+                syn::Expr::Path(path) if path.qself.is_none() && path.path.get_ident().is_some() => {
+                    quote_spanned! {check_span=> self.#rule()}
+                }
+                _ => quote_spanned! {check_span=> (#rule)},
+            };
+            mc.add_statement(quote_spanned! {check_span=>
+                if !#rule {
+                    failed.push(::std::string::String::from(#message));
+                }
+            });
+        }
+
+        mc.set_span(span)
+            .set_ret_type(quote_spanned! {span=> ::std::vec::Vec<::std::string::String>})
+            .set_ret_stmt(quote_spanned! {span=> failed})
+            .add_attribute_toks(quote_spanned! {span=> #[doc(hidden)]})?;
+
+        ctx.add_method(mc);
+
+        Ok(Some(method_ident))
+    }
+
+    // Produce methods that allow to set builder fields from string key/value pairs.
+    fn builder_from_map_methods(
+        &'a self,
+        fctxs: &[Rc<FXDeriveFieldCtx>],
+        span: Span,
+    ) -> darling::Result<Vec<FXFnConstructor>> {
+        let ctx = self.ctx();
+        let arg_props = ctx.arg_props();
+        let vis = arg_props.builder_struct_visibility();
+        let mut by_name_method = FXFnConstructor::new(format_ident!("try_set_by_name", span = span));
+        let mut from_map_method = FXFnConstructor::new_associated(format_ident!("from_str_map", span = span));
+
+        let match_arms = fctxs.iter().map(|fctx| {
+            let ident = fctx.ident();
+            let ty = fctx.ty();
+            let name = fctx.props().base_name().to_string();
+            quote_spanned! {span=>
+                #name => {
+                    self.#ident = ::std::option::Option::Some(
+                        <#ty as ::std::str::FromStr>::from_str(value)
+                            .map_err(|err| ::fieldx::error::FieldXError::field_parse(#name, err))?,
+                    );
+                }
+            }
+        });
+
+        by_name_method
+            .set_span(span)
+            .set_vis(vis)
+            .set_self_mut(true)
+            .add_param(quote_spanned! {span=> name: &str})
+            .add_param(quote_spanned! {span=> value: &str})
+            .set_ret_type(quote_spanned! {span=> ::std::result::Result<&mut Self, ::fieldx::error::FieldXError>})
+            .add_statement(quote_spanned! {span=>
+                match name {
+                    #( #match_arms )*
+                    _ => return ::std::result::Result::Err(::fieldx::error::FieldXError::unknown_field(name)),
+                }
+            })
+            .set_ret_stmt(quote_spanned! {span=> ::std::result::Result::Ok(self)})
+            .add_doc(&FXProp::new(
+                vec![parse_quote_spanned! {span=> "Parses the value and sets the builder field with the given name."}],
+                Some(span),
+            ))?;
+
+        from_map_method
+            .set_span(span)
+            .set_vis(vis)
+            .add_param(quote_spanned! {span=>
+                map: &::std::collections::HashMap<::std::string::String, ::std::string::String>
+            })
+            .set_ret_type(quote_spanned! {span=> ::std::result::Result<Self, ::fieldx::error::FieldXError>})
+            .add_statement(quote_spanned! {span=>
+                let mut builder = Self::new();
+                for (name, value) in map {
+                    builder.try_set_by_name(name, value)?;
+                }
+            })
+            .set_ret_stmt(quote_spanned! {span=> ::std::result::Result::Ok(builder)})
+            .add_doc(&FXProp::new(
+                vec![parse_quote_spanned! {span=> "Creates a builder from a map of field names and string values."}],
+                Some(span),
+            ))?;
+
+        Ok(vec![by_name_method, from_map_method])
+    }
+
     fn builder_struct(&'a self) -> darling::Result<TokenStream> {
         let ctx = self.ctx();
         let arg_props = ctx.arg_props();
@@ -558,6 +1639,9 @@ impl<'a> FXRewriter<'a> {
         ctx.user_struct_mut().add_trait_impl(fxstruct_trait);
 
         let builder_struct = ctx.ok_or_empty(self.builder_struct());
+        let transaction_struct = ctx.ok_or_empty(self.transaction_struct());
+        let patch_struct = ctx.ok_or_empty(self.patch_struct());
+        let lock_group_structs = self.lock_group_structs();
         let user_struct = ctx.user_struct().to_token_stream();
         #[cfg(feature = "serde")]
         let shadow_struct = ctx.ok_or_empty(self.serde_finalize());
@@ -570,6 +1654,9 @@ impl<'a> FXRewriter<'a> {
 
             #user_struct
             #builder_struct
+            #transaction_struct
+            #patch_struct
+            #( #lock_group_structs )*
             #shadow_struct
         };
 
@@ -1,10 +1,12 @@
 use enum_dispatch::enum_dispatch;
 use fieldx_aux::FXAccessorMode;
+use fieldx_aux::FXOrig;
 use fieldx_aux::FXProp;
 use fieldx_aux::FXPropBool;
 use fieldx_core::codegen::constructor::FXConstructor;
 use fieldx_core::codegen::constructor::FXFieldConstructor;
 use fieldx_core::codegen::constructor::FXFnConstructor;
+use fieldx_core::codegen::constructor::FXStructConstructor;
 use fieldx_core::types::helper::FXHelperKind;
 use fieldx_core::types::meta::FXToksMeta;
 use fieldx_core::types::meta::FXValueFlag;
@@ -19,6 +21,7 @@ use quote::ToTokens;
 use std::rc::Rc;
 use syn::spanned::Spanned;
 
+use crate::util::deferred_errors_ident;
 use crate::util::std_default_expr_toks;
 
 use super::derive_ctx::FXDeriveCodegenCtx;
@@ -55,8 +58,23 @@ pub(crate) trait FXCodeGenContextual {
     fn field_builder_setter(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<TokenStream>;
     fn field_reader(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>>;
     fn field_writer(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>>;
+    fn field_lock(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>>;
+    // Non-blocking (or, with `timed`, time-limited) variants of the reader/writer helpers.
+    fn field_try_reader(&self, fctx: &FXDeriveFieldCtx, timed: bool) -> darling::Result<Option<FXFnConstructor>>;
+    fn field_try_writer(&self, fctx: &FXDeriveFieldCtx, timed: bool) -> darling::Result<Option<FXFnConstructor>>;
     fn field_setter(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>>;
+    // Helpers specific to atomic fields.
+    fn field_fetch_add(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>>;
+    fn field_swap(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>>;
+    fn field_compare_exchange(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>>;
+    // Compare-and-swap update of an arc-swap field.
+    fn field_update(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>>;
+    // Produces parameters, body, and return of a setter method. The method name, visibility, and attributes are up to
+    // the caller.
+    fn field_setter_body(&self, fctx: &FXDeriveFieldCtx, mc: &mut FXFnConstructor, span: Span) -> darling::Result<()>;
     fn field_clearer(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>>;
+    fn field_clearer_body(&self, fctx: &FXDeriveFieldCtx, mc: &mut FXFnConstructor, span: Span)
+        -> darling::Result<()>;
     fn field_predicate(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>>;
     fn field_lazy_builder_wrapper(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>>;
     fn field_value_wrap(&self, fctx: &FXDeriveFieldCtx, value: FXValueRepr<FXToksMeta>) -> darling::Result<FXToksMeta>;
@@ -71,6 +89,24 @@ pub(crate) trait FXCodeGenContextual {
     #[cfg(feature = "serde")]
     // How to move field from the struct itself
     fn field_from_struct(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<FXToksMeta>;
+    #[cfg(feature = "serde")]
+    // Type and value of the field in the borrowed shadow struct, used to serialize the struct without consuming it.
+    // Lazy fields are initialized by statements added to the serializing method. Members of lock groups are referenced
+    // via the locked group guard in a variable named after the group field.
+    fn field_serialize_ref(
+        &self,
+        fctx: &FXDeriveFieldCtx,
+        mc: &mut FXFnConstructor,
+        lifetime: &TokenStream,
+    ) -> darling::Result<(TokenStream, TokenStream)>;
+    #[cfg(feature = "serde")]
+    // Read guard of a field protected by a lock of its own, for `serde(snapshot)` to take before the shadow struct is
+    // constructed. Lazy fields are initialized first. `None` for fields without own lock.
+    fn field_serialize_guard(
+        &self,
+        fctx: &FXDeriveFieldCtx,
+        mc: &mut FXFnConstructor,
+    ) -> darling::Result<Option<TokenStream>>;
     fn type_tokens<'s>(&'s self, fctx: &'s FXDeriveFieldCtx) -> darling::Result<&'s TokenStream>;
 
     fn maybe_ref_counted<TT: ToTokens>(&self, ty: &TT) -> TokenStream {
@@ -90,6 +126,8 @@ pub(crate) trait FXCodeGenContextual {
         self_name: &NT,
         struct_init: &IT,
         post_build_ident: Option<syn::Ident>,
+        // Identifiers of the builder checks method and of the variable to collect failed checks into.
+        checks: Option<(&syn::Ident, &syn::Ident)>,
     ) -> TokenStream {
         let ctx = self.ctx();
         let arg_props = ctx.arg_props();
@@ -109,6 +147,37 @@ pub(crate) trait FXCodeGenContextual {
 
         let rc = arg_props.rc();
 
+        let construct = |struct_create: TokenStream| {
+            if let Some((checks_method, failed_checks)) = checks {
+                let span = checks_method.span();
+                let obj_ident = ctx.unique_ident_pfx("__fx_obj");
+                // post_build is only invoked if the object passes all checks.
+                let finalize = if post_construct.is_empty() {
+                    quote_spanned![span=> #obj_ident]
+                }
+                else {
+                    quote_spanned![span=>
+                        if #failed_checks.is_empty() {
+                            #obj_ident #post_construct
+                        }
+                        else {
+                            #obj_ident
+                        }
+                    ]
+                };
+                quote_spanned![span=>
+                    {
+                        let #obj_ident = #struct_create;
+                        #failed_checks.extend(#obj_ident.#checks_method());
+                        #finalize
+                    }
+                ]
+            }
+            else {
+                quote_spanned![struct_create.span()=> #struct_create #post_construct]
+            }
+        };
+
         if *rc {
             let rc_span = rc.final_span();
             let rc_type = ctx.impl_details().ref_count_strong(rc_span);
@@ -116,25 +185,26 @@ pub(crate) trait FXCodeGenContextual {
             // Never move the post_construct call outside the new_cyclic closure!  The primary purpose of post_build is
             // to allow tweaking of the struct once it has been created.  Invoking it on a reference-counted container
             // makes this task difficult or impossible, depending on the constraints applied to the fields of interest.
+            let construction = construct(quote_spanned![rc_span=>
+                #self_name {
+                    #myself_field: me.clone(),
+                    #struct_init
+                }
+            ]);
             quote_spanned![rc_span=>
                 #rc_type::new_cyclic(
                     |me| {
-                        #self_name {
-                            #myself_field: me.clone(),
-                            #struct_init
-                        }
-                        #post_construct
+                        #construction
                     }
                 )
             ]
         }
         else {
-            quote_spanned![self_name.span()=>
+            construct(quote_spanned![self_name.span()=>
                 #self_name {
                     #struct_init
                 }
-                #post_construct
-            ]
+            ])
         }
     }
 
@@ -155,6 +225,10 @@ pub(crate) trait FXCodeGenContextual {
 
         if !*fctx.skipped() {
             constructor.set_type(self.type_tokens(fctx)?.clone());
+
+            if let Some(group) = fctx.lock_group() {
+                return self.lock_group_field_decl(fctx, group, constructor);
+            }
         };
 
         self.ctx().add_field_decl(constructor);
@@ -162,6 +236,269 @@ pub(crate) trait FXCodeGenContextual {
         Ok(())
     }
 
+    // Members of a lock group are declared in the group struct. The group lock itself takes the place of the first
+    // member in the user struct.
+    fn lock_group_field_decl(
+        &self,
+        fctx: &FXDeriveFieldCtx,
+        group: &syn::Ident,
+        constructor: FXFieldConstructor,
+    ) -> darling::Result<()> {
+        let ctx = self.ctx();
+        let impl_ctx = ctx.impl_ctx();
+        let arg_props = ctx.arg_props();
+        let group_struct_ident = arg_props.lock_group_struct_ident(group);
+
+        if impl_ctx.lock_group_mut(&group_struct_ident).is_none() {
+            let span = group.span();
+            let group_field_ident = arg_props.lock_group_field_ident(group);
+            let generic_params = ctx.struct_generic_params();
+            let implementor = fctx.impl_details();
+            let rwlock = implementor.rwlock(span)?;
+            let await_call = implementor.await_call(span);
+            let vis = ctx.input().vis();
+            let lifetime = quote_spanned! {span=> 'fx_group_lifetime};
+            let group_type = quote_spanned! {span=> #group_struct_ident #generic_params};
+            let mut group_struct = FXStructConstructor::new(group_struct_ident.clone());
+
+            group_struct
+                .set_span(span)
+                .set_vis(vis)
+                .set_generics(ctx.input().generics().clone())
+                // The group struct must support the same traits the user struct derives.
+                .add_attributes(ctx.input().attrs().iter().filter(|a| a.path().is_ident("derive")))
+                .add_doc(&FXProp::new(
+                    vec![syn::LitStr::new(
+                        &format!("Fields of the `{group}` lock group of [`{}`].", ctx.input_ident()),
+                        span,
+                    )],
+                    Some(span),
+                ))?;
+
+            ctx.add_field_decl(FXFieldConstructor::new(
+                group_field_ident.clone(),
+                quote_spanned! {span=> #rwlock<#group_type>},
+                span,
+            ));
+
+            for (method, guard, access) in [
+                (format_ident!("read", span = span), implementor.rwlock_read_guard(span)?, "reading"),
+                (format_ident!("write", span = span), implementor.rwlock_write_guard(span)?, "writing"),
+            ] {
+                let mut mc = FXFnConstructor::new(format_ident!("{}_{}", method, group, span = span));
+                mc.set_span(span)
+                    .set_vis(vis)
+                    .set_async(fctx.mode_async())
+                    .set_self_lifetime(lifetime.clone())
+                    .set_ret_type(quote_spanned! {span=> #guard<#lifetime, #group_type>})
+                    .set_ret_stmt(quote_spanned! {span=> self.#group_field_ident.#method()#await_call})
+                    .add_attribute_toks(quote_spanned! {span=> #[allow(dead_code)] #[inline(always)]})?
+                    .add_doc(&FXProp::new(
+                        vec![syn::LitStr::new(&format!("Locks the `{group}` field group for {access}."), span)],
+                        Some(span),
+                    ))?;
+                ctx.add_method(mc);
+            }
+
+            impl_ctx.add_lock_group(group_struct);
+        }
+
+        impl_ctx
+            .lock_group_mut(&group_struct_ident)
+            .ok_or_else(|| darling::Error::custom(format!("Lock group '{group}' is not registered")))?
+            .add_field(constructor);
+
+        Ok(())
+    }
+
+    fn maybe_chain_setter(&self, fctx: &FXDeriveFieldCtx, mc: &mut FXFnConstructor) {
+        let chain = fctx.setter_chain();
+        if *chain {
+            let chain_span = chain.final_span();
+            let ret_type = if *mc.self_mut() {
+                quote_spanned! {chain_span=> &mut Self}
+            }
+            else {
+                quote_spanned! {chain_span=> &Self}
+            };
+            self.setter_returns_self(mc, ret_type, chain_span);
+        }
+    }
+
+    fn field_with(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>> {
+        let with = fctx.with();
+        Ok(if *with {
+            let span = with.final_span();
+            let mut mc = FXFnConstructor::new(fctx.with_ident().clone());
+
+            mc.set_span(span)
+                .set_vis(fctx.with_visibility())
+                .add_attribute_toks(fctx.helper_attributes_fn(FXHelperKind::With, FXInlining::Always, span))?;
+
+            self.field_setter_body(fctx, &mut mc, span)?;
+            mc.set_self_borrow(false);
+            self.setter_returns_self(&mut mc, quote_spanned! {span=> Self}, span);
+            self.maybe_try_into_result(fctx, &mut mc, fctx.setter_try_into());
+
+            Some(mc)
+        }
+        else {
+            None
+        })
+    }
+
+    // Bump the version of a tracked field once the method has modified it. The result is bound by a `match` because
+    // temporaries of its scrutinee, like lock guards, live until the end of it. This way the field is still locked when
+    // its version changes and a concurrent `mark_clean()` can't consider the new value clean before it is written.
+    // With `on_success` the result is a `Result` and only `Ok` counts as a change.
+    fn maybe_track_change(
+        &self,
+        fctx: &FXDeriveFieldCtx,
+        mc: &mut FXFnConstructor,
+        on_success: bool,
+    ) -> darling::Result<()> {
+        let ctx = self.ctx();
+        if let Some(idx) = ctx.impl_ctx().change_index(fctx.ident())? {
+            let span = mc.span();
+            let changes_field = ctx.arg_props().changes_field_ident();
+            let ret_stmt = mc.ret_stmt().clone();
+            let mut bump = quote_spanned! {span=> self.#changes_field.bump(#idx);};
+            if on_success {
+                bump = quote_spanned! {span=> if __fx_ret.is_ok() { #bump }};
+            }
+            mc.set_ret_stmt(quote_spanned! {span=>
+                match #ret_stmt {
+                    __fx_ret => {
+                        #bump
+                        __fx_ret
+                    }
+                }
+            });
+        }
+        Ok(())
+    }
+
+    // Turn a setter into a method returning its self. The original body is put into its own scope to have any
+    // temporaries like lock guards dropped before self is returned.
+    fn setter_returns_self(&self, mc: &mut FXFnConstructor, ret_type: TokenStream, span: Span) {
+        let body = std::mem::take(mc.body_mut());
+        let ret_stmt = mc.ret_stmt().clone();
+        let self_ident = mc.self_ident();
+
+        mc.add_statement(quote_spanned! {span=>
+            {
+                #( #body )*
+                let _ = #ret_stmt;
+            }
+        });
+        mc.set_ret_type(ret_type);
+        mc.set_ret_stmt(quote_spanned! {span=> #self_ident});
+    }
+
+    // With `try_into` the argument is converted before anything else is done so that the field is left intact if
+    // the conversion fails. The method then returns whatever it used to return wrapped into a `Result`.
+    fn maybe_try_into_result(&self, fctx: &FXDeriveFieldCtx, mc: &mut FXFnConstructor, try_into: FXProp<bool>) {
+        if *try_into {
+            let span = try_into.final_span();
+            let ty = fctx.ty();
+            let ret_type = mc.ret_type().clone();
+            let ret_stmt = mc.ret_stmt().clone();
+
+            mc.body_mut().insert(
+                0,
+                quote_spanned! {span=> let value: #ty = ::std::convert::TryInto::try_into(value)?; },
+            );
+            mc.set_ret_type(quote_spanned! {span=>
+                ::std::result::Result<#ret_type, <FXVALINTO as ::std::convert::TryInto<#ty>>::Error>
+            });
+            mc.set_ret_stmt(quote_spanned! {span=> ::std::result::Result::Ok(#ret_stmt)});
+        }
+    }
+
+    // Deferred `try_into` of a builder setter: a conversion error is recorded in the builder under the field name and
+    // is reported by `build()`. A successful conversion discards the error recorded earlier for the same field.
+    fn builder_try_into_deferred(&self, fctx: &FXDeriveFieldCtx, mc: &mut FXFnConstructor, try_into: FXProp<bool>) {
+        let span = try_into.final_span();
+        let ty = fctx.ty();
+        let ident = fctx.ident();
+        let name = fctx.props().base_name().to_string();
+        let errors = deferred_errors_ident(span);
+
+        mc.add_where_bound(quote_spanned! {span=>
+            <FXVALINTO as ::std::convert::TryInto<#ty>>::Error: ::std::fmt::Display
+        });
+        mc.add_statement(quote_spanned! {span=>
+            match ::std::convert::TryInto::<#ty>::try_into(value) {
+                ::std::result::Result::Ok(value) => {
+                    self.#errors.retain(|(field, _)| *field != #name);
+                    self.#ident = ::std::option::Option::Some(value);
+                }
+                ::std::result::Result::Err(err) => {
+                    self.#errors.push((#name, ::std::string::ToString::to_string(&err)));
+                }
+            }
+        });
+    }
+
+    // Apply projection function to the value an accessor gives access to. Whatever the accessor would return –
+    // a reference, a `Ref`, or a lock guard – is kept in a local variable for the time the function is called.
+    fn maybe_map_accessor(
+        &self,
+        fctx: &FXDeriveFieldCtx,
+        method: Option<FXFnConstructor>,
+        mutable: bool,
+    ) -> darling::Result<Option<FXFnConstructor>> {
+        let field_props = fctx.props().field_props();
+        let map = if mutable {
+            field_props.accessor_mut_map()
+        }
+        else {
+            field_props.accessor_map()
+        };
+
+        Ok(if let Some(map) = map {
+            let Some(mut mc) = method
+            else {
+                return Ok(None);
+            };
+            let span = map.final_span();
+            let map_fn = map.func();
+            // The macro cannot know what the function returns unless told so. The opaque type may borrow from `self`.
+            let self_lifetime = mc.self_lifetime().clone().unwrap_or_else(|| quote_spanned! {span=> '_});
+            let ret_type = map.ret_type().map_or_else(
+                || quote_spanned! {span=> impl ::std::marker::Sized + #self_lifetime},
+                |rt| rt.to_token_stream(),
+            );
+            let ret_stmt = mc.ret_stmt().clone();
+            let shortcut = fctx.fallible_shortcut();
+            let value_var = quote_spanned! {span=> __fx_value};
+            let (mutability, ref_mut, as_ref) = if mutable {
+                (quote_spanned! {span=> mut}, quote_spanned! {span=> &mut}, quote_spanned! {span=> as_mut})
+            }
+            else {
+                (quote![], quote_spanned! {span=> &}, quote_spanned! {span=> as_ref})
+            };
+
+            let mapped = if *fctx.optional() {
+                quote_spanned! {span=> (#ref_mut *#value_var).#as_ref().map(#map_fn)}
+            }
+            else {
+                quote_spanned! {span=> #map_fn(#ref_mut *#value_var)}
+            };
+
+            mc.add_statement(quote_spanned! {span=>
+                #[allow(unused_mut)]
+                let #mutability #value_var = { #ret_stmt } #shortcut;
+            });
+            mc.set_ret_type(fctx.fallible_return_type(fctx, self.maybe_optional(fctx, ret_type))?);
+            mc.set_ret_stmt(fctx.fallible_ok_return(&mapped));
+            Some(mc)
+        }
+        else {
+            method
+        })
+    }
+
     fn maybe_add_helper_method(
         &self,
         method: Option<FXFnConstructor>,
@@ -177,6 +514,7 @@ pub(crate) trait FXCodeGenContextual {
                 FXHelperKind::Reader => props.reader_doc(),
                 FXHelperKind::Writer => props.writer_doc(),
                 FXHelperKind::Setter => props.setter_doc(),
+                FXHelperKind::With => props.with_doc(),
                 FXHelperKind::Clearer => props.clearer_doc(),
                 FXHelperKind::Predicate => props.predicate_doc(),
                 _ => None,
@@ -206,11 +544,29 @@ pub(crate) trait FXCodeGenContextual {
             let ctx = self.ctx();
             let impl_ctx = ctx.impl_ctx();
 
-            self.maybe_add_helper_method(self.field_accessor(fctx)?, FXHelperKind::Accessor, fctx)?;
-            self.maybe_add_helper_method(self.field_accessor_mut(fctx)?, FXHelperKind::AccessorMut, fctx)?;
+            self.maybe_add_helper_method(
+                self.maybe_map_accessor(fctx, self.field_accessor(fctx)?, false)?,
+                FXHelperKind::Accessor,
+                fctx,
+            )?;
+            self.maybe_add_helper_method(
+                self.maybe_map_accessor(fctx, self.field_accessor_mut(fctx)?, true)?,
+                FXHelperKind::AccessorMut,
+                fctx,
+            )?;
             self.maybe_add_helper_method(self.field_reader(fctx)?, FXHelperKind::Reader, fctx)?;
             self.maybe_add_helper_method(self.field_writer(fctx)?, FXHelperKind::Writer, fctx)?;
+            ctx.maybe_add_method(self.field_try_reader(fctx, false)?);
+            ctx.maybe_add_method(self.field_try_reader(fctx, true)?);
+            ctx.maybe_add_method(self.field_try_writer(fctx, false)?);
+            ctx.maybe_add_method(self.field_try_writer(fctx, true)?);
+            self.maybe_add_helper_method(self.field_lock(fctx)?, FXHelperKind::Lock, fctx)?;
             self.maybe_add_helper_method(self.field_setter(fctx)?, FXHelperKind::Setter, fctx)?;
+            self.maybe_add_helper_method(self.field_with(fctx)?, FXHelperKind::With, fctx)?;
+            self.maybe_add_helper_method(self.field_fetch_add(fctx)?, FXHelperKind::FetchAdd, fctx)?;
+            self.maybe_add_helper_method(self.field_swap(fctx)?, FXHelperKind::Swap, fctx)?;
+            self.maybe_add_helper_method(self.field_compare_exchange(fctx)?, FXHelperKind::CompareExchange, fctx)?;
+            self.maybe_add_helper_method(self.field_update(fctx)?, FXHelperKind::Update, fctx)?;
             self.maybe_add_helper_method(self.field_clearer(fctx)?, FXHelperKind::Clearer, fctx)?;
             self.maybe_add_helper_method(self.field_predicate(fctx)?, FXHelperKind::Predicate, fctx)?;
             ctx.maybe_add_method(self.field_lazy_builder_wrapper(fctx)?);
@@ -296,6 +652,22 @@ pub(crate) trait FXCodeGenContextual {
                 method: quote_spanned![span=> .as_ref()],
                 ..Default::default()
             },
+            FXAccessorMode::Deref => {
+                if *fctx.optional() {
+                    FXAccessorElements {
+                        type_ref: quote_spanned![span=> &],
+                        method: quote_spanned![span=> .as_deref()],
+                        ..Default::default()
+                    }
+                }
+                else {
+                    FXAccessorElements {
+                        reference: quote_spanned![span=> &],
+                        dereference: quote_spanned![span=> &**],
+                        ..Default::default()
+                    }
+                }
+            }
             FXAccessorMode::None => FXAccessorElements {
                 reference: quote_spanned![span=> &],
                 ..Default::default()
@@ -303,6 +675,17 @@ pub(crate) trait FXCodeGenContextual {
         }
     }
 
+    // The type accessor methods give access to. It is the field type itself unless `deref` accessor mode is used.
+    fn accessor_type(&self, fctx: &FXDeriveFieldCtx, span: Span) -> TokenStream {
+        let ty = fctx.ty();
+        if fctx.accessor_mode().is_deref() {
+            quote_spanned![span=> <#ty as ::std::ops::Deref>::Target]
+        }
+        else {
+            ty.to_token_stream()
+        }
+    }
+
     fn field_simple_lazy_initializer(
         &self,
         fctx: &FXDeriveFieldCtx,
@@ -324,7 +707,7 @@ pub(crate) trait FXCodeGenContextual {
             builder_ident.set_span(span);
             let mut mc = FXFnConstructor::new(builder_ident);
             let ident = fctx.ident();
-            let (val_type, gen_params, into_tok) = self.to_toks(fctx, fctx.builder_into());
+            let (val_type, gen_params, into_tok) = self.to_toks(fctx, fctx.builder_into(), fctx.builder_try_into());
 
             mc.set_span(span)
                 .set_vis(fctx.builder_method_visibility())
@@ -333,9 +716,16 @@ pub(crate) trait FXCodeGenContextual {
                 .add_param(quote_spanned! {span=> value: #val_type})
                 .set_self_borrow(false)
                 .set_ret_type(quote_spanned! {span=> Self})
-                .add_statement(quote_spanned! {span=> self.#ident = ::std::option::Option::Some(value #into_tok);})
                 .set_ret_stmt(quote_spanned! {span=> self});
             mc.add_attribute_toks(fctx.helper_attributes_fn(FXHelperKind::Builder, FXInlining::Always, span))?;
+            let try_into = fctx.builder_try_into();
+            if *try_into && *fctx.builder_try_into_defer() {
+                self.builder_try_into_deferred(fctx, &mut mc, try_into);
+            }
+            else {
+                mc.add_statement(quote_spanned! {span=> self.#ident = ::std::option::Option::Some(value #into_tok);});
+                self.maybe_try_into_result(fctx, &mut mc, try_into);
+            }
 
             let method_optional = fctx.builder_method_optional();
 
@@ -527,9 +917,19 @@ pub(crate) trait FXCodeGenContextual {
         &self,
         fctx: &FXDeriveFieldCtx,
         use_into: FXProp<bool>,
+        use_try_into: FXProp<bool>,
     ) -> (TokenStream, Option<TokenStream>, Option<TokenStream>) {
         let ty = fctx.ty();
-        if *use_into {
+        if *use_try_into {
+            // The value is converted by maybe_try_into_result.
+            let span = use_try_into.final_span();
+            (
+                quote_spanned![span=> FXVALINTO],
+                Some(quote_spanned![span=> FXVALINTO: ::std::convert::TryInto<#ty>]),
+                None,
+            )
+        }
+        else if *use_into {
             let span = use_into.final_span();
             (
                 quote_spanned![span=> FXVALINTO],
@@ -25,6 +25,12 @@ pub(crate) struct FXDeriveMacroCtx {
 
     #[cfg(feature = "serde")]
     shadow_struct: RefCell<Option<FXStructConstructor>>,
+    // Borrowed counterpart of the shadow struct, used to serialize without consuming the user struct.
+    #[cfg(feature = "serde")]
+    shadow_ref_struct: RefCell<Option<FXStructConstructor>>,
+    // Partial struct deserialized by `update_from_deserializer`.
+    #[cfg(feature = "serde")]
+    update_struct:     RefCell<Option<FXStructConstructor>>,
 
     #[cfg(feature = "serde")]
     shadow_var_ident: OnceCell<syn::Ident>,
@@ -32,6 +38,12 @@ pub(crate) struct FXDeriveMacroCtx {
     me_var_ident:     OnceCell<syn::Ident>,
 
     copyable_types: RefCell<Vec<syn::Type>>,
+
+    // Structs holding values of lock groups, in the order of their first member appearance.
+    lock_groups: RefCell<Vec<FXStructConstructor>>,
+
+    // Fields of a `track_changes` struct, in the order of their change tracker indices.
+    tracked_fields: OnceCell<Vec<syn::Ident>>,
 }
 
 impl FXDeriveMacroCtx {
@@ -41,7 +53,13 @@ impl FXDeriveMacroCtx {
             builder_struct:                             OnceCell::new(),
             #[cfg(feature = "serde")]
             shadow_struct:                              RefCell::new(None),
+            #[cfg(feature = "serde")]
+            shadow_ref_struct:                          RefCell::new(None),
+            #[cfg(feature = "serde")]
+            update_struct:                              RefCell::new(None),
             copyable_types:                             RefCell::new(vec![]),
+            lock_groups:                                RefCell::new(vec![]),
+            tracked_fields:                             OnceCell::new(),
             #[cfg(feature = "serde")]
             shadow_var_ident:                           OnceCell::new(),
             #[cfg(feature = "serde")]
@@ -78,6 +96,26 @@ impl FXDeriveMacroCtx {
         Ok(RefMut::map(sstruct, |s| s.as_mut().unwrap()))
     }
 
+    #[cfg(feature = "serde")]
+    pub(crate) fn set_shadow_ref_struct(&self, shadow_ref_struct: FXStructConstructor) {
+        self.shadow_ref_struct.replace(Some(shadow_ref_struct));
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn take_shadow_ref_struct(&self) -> Option<FXStructConstructor> {
+        self.shadow_ref_struct.take()
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn set_update_struct(&self, update_struct: FXStructConstructor) {
+        self.update_struct.replace(Some(update_struct));
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn take_update_struct(&self) -> Option<FXStructConstructor> {
+        self.update_struct.take()
+    }
+
     #[inline(always)]
     pub(crate) fn copyable_types<'a>(&'a self) -> std::cell::Ref<'a, Vec<syn::Type>> {
         self.copyable_types.borrow()
@@ -88,6 +126,43 @@ impl FXDeriveMacroCtx {
         self.copyable_types.borrow_mut().push(field_ctx.ty().clone());
     }
 
+    #[inline(always)]
+    pub(crate) fn take_lock_groups(&self) -> Vec<FXStructConstructor> {
+        self.lock_groups.take()
+    }
+
+    #[inline(always)]
+    pub(crate) fn add_lock_group(&self, group_struct: FXStructConstructor) {
+        self.lock_groups.borrow_mut().push(group_struct);
+    }
+
+    pub(crate) fn lock_group_mut<'a>(&'a self, group_ident: &syn::Ident) -> Option<RefMut<'a, FXStructConstructor>> {
+        RefMut::filter_map(self.lock_groups.borrow_mut(), |groups| {
+            groups.iter_mut().find(|g| g.ident() == group_ident)
+        })
+        .ok()
+    }
+
+    pub(crate) fn tracked_fields(&self) -> darling::Result<&Vec<syn::Ident>> {
+        self.tracked_fields.get_or_try_init(|| {
+            let ctx = self.codegen_ctx()?;
+            Ok(ctx
+                .all_field_ctx()
+                .into_iter()
+                .filter(|fctx| !fctx.extra() && !*fctx.skipped())
+                .map(|fctx| fctx.ident().clone())
+                .collect())
+        })
+    }
+
+    // Index of the field in the change tracker, if the struct tracks changes.
+    pub(crate) fn change_index(&self, field_ident: &syn::Ident) -> darling::Result<Option<usize>> {
+        if !*self.codegen_ctx()?.arg_props().track_changes() {
+            return Ok(None);
+        }
+        Ok(self.tracked_fields()?.iter().position(|ident| ident == field_ident))
+    }
+
     #[cfg(feature = "serde")]
     #[inline]
     // How to reference shadow instance in an associated function
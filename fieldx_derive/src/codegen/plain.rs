@@ -134,6 +134,16 @@ impl<'a> FXCodeGenContextual for FXCodeGenPlain<'a> {
     fn type_tokens<'s>(&'s self, fctx: &'s FXDeriveFieldCtx) -> darling::Result<&'s TokenStream> {
         fctx.ty_wrapped(|| {
             // fxtrace!(fctx.ident_tok().to_string());
+            let atomic = fctx.atomic();
+            if *atomic {
+                return Err(darling::Error::custom("atomic fields require sync or async mode")
+                    .with_span(&atomic.final_span()));
+            }
+            let swap = fctx.swap();
+            if *swap {
+                return Err(darling::Error::custom("swap fields require sync or async mode")
+                    .with_span(&swap.final_span()));
+            }
             let mut ty_tok = fctx.ty().to_token_stream();
             let lazy = fctx.lazy();
             let optional = fctx.optional();
@@ -178,6 +188,11 @@ impl<'a> FXCodeGenContextual for FXCodeGenPlain<'a> {
             let lazy = fctx.lazy();
             let inner_mut = fctx.inner_mut();
 
+            if accessor_mode.is_deref() && *inner_mut {
+                return Err(darling::Error::custom("accessor mode 'deref' cannot be used with inner_mut fields")
+                    .with_span(&accessor_mode.final_span()));
+            }
+
             mc.set_span(span)
                 .set_vis(fctx.accessor_visibility().to_token_stream())
                 .add_attribute_toks(fctx.helper_attributes_fn(FXHelperKind::Accessor, FXInlining::Always, span))?;
@@ -195,7 +210,7 @@ impl<'a> FXCodeGenContextual for FXCodeGenPlain<'a> {
                 let accessor = self.maybe_inner_mut_accessor(fctx, &mut mc);
                 let ret = self.maybe_inner_mut_map(fctx, &mc, accessor, Some(lazy_init));
                 let shortcut = fctx.fallible_shortcut();
-                let ty = fctx.ty();
+                let ty = self.accessor_type(fctx, span);
 
                 let ret_type = if *inner_mut && !(is_copy || is_clone) {
                     self.inner_mut_return_type(
@@ -220,7 +235,7 @@ impl<'a> FXCodeGenContextual for FXCodeGenPlain<'a> {
                 }
             }
             else {
-                let mut ty = fctx.ty().to_token_stream();
+                let mut ty = self.accessor_type(fctx, span);
 
                 ty = self.maybe_optional(fctx, &quote_spanned! {span=> #type_ref #ty});
 
@@ -371,69 +386,80 @@ impl<'a> FXCodeGenContextual for FXCodeGenPlain<'a> {
         Ok(if *setter {
             let span = setter.span();
             let mut mc = FXFnConstructor::new(fctx.setter_ident().clone());
-            let ident = fctx.ident();
-            let ty = fctx.ty();
-            let (val_type, gen_params, into_tok) = self.to_toks(fctx, fctx.setter_into());
-            let mut value_tok = quote_spanned! {span=> value #into_tok};
-            let optional = fctx.optional();
-            let inner_mut = fctx.inner_mut();
-            let lazy = fctx.lazy();
 
             mc.set_span(span)
                 .set_vis(fctx.setter_visibility())
-                .maybe_add_generic(gen_params)
-                .add_param(quote_spanned! {span=> value: #val_type })
                 .add_attribute_toks(fctx.helper_attributes_fn(FXHelperKind::Setter, FXInlining::Always, span))?;
 
-            if *lazy {
-                let lazy_span = lazy.final_span();
-                let accessor = if *inner_mut {
-                    let inner_mut_span = inner_mut.final_span();
-                    let accessor_name = format_ident!("{}_ref", ident, span = inner_mut_span);
-                    mc.add_statement(
-                        quote_spanned! {inner_mut_span=> let mut #accessor_name = self.#ident.borrow_mut();},
-                    );
-                    accessor_name.to_token_stream()
-                }
-                else {
-                    quote_spanned! {span=> self.#ident}
-                };
+            self.field_setter_body(fctx, &mut mc, span)?;
+            self.maybe_track_change(fctx, &mut mc, false)?;
+            self.maybe_chain_setter(fctx, &mut mc);
+            self.maybe_try_into_result(fctx, &mut mc, fctx.setter_try_into());
 
-                mc.set_self_mut(true);
-                mc.set_ret_type(quote_spanned! {lazy_span=> ::std::option::Option<#ty>});
-                mc.add_statement(quote_spanned! {span=>
-                    let old = #accessor.take();
-                    let _ = #accessor.set(#value_tok);
-                });
-                mc.set_ret_stmt(quote_spanned! {span=> old});
+            Some(mc)
+        }
+        else {
+            None
+        })
+    }
+
+    fn field_setter_body(&self, fctx: &FXDeriveFieldCtx, mc: &mut FXFnConstructor, span: Span) -> darling::Result<()> {
+        let ident = fctx.ident();
+        let ty = fctx.ty();
+        let (val_type, gen_params, into_tok) = self.to_toks(fctx, fctx.setter_into(), fctx.setter_try_into());
+        let mut value_tok = quote_spanned! {span=> value #into_tok};
+        let optional = fctx.optional();
+        let inner_mut = fctx.inner_mut();
+        let lazy = fctx.lazy();
+
+        mc.maybe_add_generic(gen_params)
+            .add_param(quote_spanned! {span=> value: #val_type });
+
+        if *lazy {
+            let lazy_span = lazy.final_span();
+            let accessor = if *inner_mut {
+                let inner_mut_span = inner_mut.final_span();
+                let accessor_name = format_ident!("{}_ref", ident, span = inner_mut_span);
+                mc.add_statement(
+                    quote_spanned! {inner_mut_span=> let mut #accessor_name = self.#ident.borrow_mut();},
+                );
+                accessor_name.to_token_stream()
             }
             else {
-                mc.set_ret_type(self.maybe_optional(fctx, ty));
+                quote_spanned! {span=> self.#ident}
+            };
 
-                if *inner_mut || *optional {
-                    if *inner_mut {
-                        if *optional {
-                            value_tok = quote_spanned![optional.final_span()=> Some(#value_tok) ];
-                        }
-                    }
-                    else {
-                        mc.set_self_mut(true);
-                    }
+            mc.set_self_mut(true);
+            mc.set_ret_type(quote_spanned! {lazy_span=> ::std::option::Option<#ty>});
+            mc.add_statement(quote_spanned! {span=>
+                let old = #accessor.take();
+                let _ = #accessor.set(#value_tok);
+            });
+            mc.set_ret_stmt(quote_spanned! {span=> old});
+        }
+        else {
+            mc.set_ret_type(self.maybe_optional(fctx, ty));
 
-                    let span = inner_mut.or(optional).final_span();
-                    mc.set_ret_stmt(quote_spanned! {span=> self.#ident.replace(#value_tok) });
+            if *inner_mut || *optional {
+                if *inner_mut {
+                    if *optional {
+                        value_tok = quote_spanned![optional.final_span()=> Some(#value_tok) ];
+                    }
                 }
                 else {
                     mc.set_self_mut(true);
-                    mc.set_ret_stmt(quote_spanned! {span=> ::std::mem::replace(&mut self.#ident, #value_tok) });
                 }
-            }
 
-            Some(mc)
+                let span = inner_mut.or(optional).final_span();
+                mc.set_ret_stmt(quote_spanned! {span=> self.#ident.replace(#value_tok) });
+            }
+            else {
+                mc.set_self_mut(true);
+                mc.set_ret_stmt(quote_spanned! {span=> ::std::mem::replace(&mut self.#ident, #value_tok) });
+            }
         }
-        else {
-            None
-        })
+
+        Ok(())
     }
 
     fn field_clearer(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>> {
@@ -441,24 +467,14 @@ impl<'a> FXCodeGenContextual for FXCodeGenPlain<'a> {
         Ok(if *clearer {
             let span = clearer.final_span();
             let mut mc = FXFnConstructor::new(fctx.clearer_ident().clone());
-            let ident = fctx.ident();
             let attributes_fn = fctx.helper_attributes_fn(FXHelperKind::Clearer, FXInlining::Always, span);
-            let mut ty_tok = fctx.ty().to_token_stream();
 
             mc.set_span(span)
                 .set_vis(fctx.clearer_visibility())
                 .add_attribute_toks(attributes_fn)?;
 
-            if *fctx.inner_mut() {
-                ty_tok = self.maybe_optional(fctx, ty_tok);
-            }
-            else {
-                mc.set_self_mut(true);
-                ty_tok = quote_spanned! {span=> ::std::option::Option<#ty_tok>};
-            }
-
-            mc.set_ret_type(ty_tok)
-                .set_ret_stmt(quote_spanned! {span=> self.#ident.take() });
+            self.field_clearer_body(fctx, &mut mc, span)?;
+            self.maybe_track_change(fctx, &mut mc, false)?;
 
             Some(mc)
         }
@@ -467,6 +483,24 @@ impl<'a> FXCodeGenContextual for FXCodeGenPlain<'a> {
         })
     }
 
+    fn field_clearer_body(&self, fctx: &FXDeriveFieldCtx, mc: &mut FXFnConstructor, span: Span) -> darling::Result<()> {
+        let ident = fctx.ident();
+        let mut ty_tok = fctx.ty().to_token_stream();
+
+        if *fctx.inner_mut() {
+            ty_tok = self.maybe_optional(fctx, ty_tok);
+        }
+        else {
+            mc.set_self_mut(true);
+            ty_tok = quote_spanned! {span=> ::std::option::Option<#ty_tok>};
+        }
+
+        mc.set_ret_type(ty_tok)
+            .set_ret_stmt(quote_spanned! {span=> self.#ident.take() });
+
+        Ok(())
+    }
+
     fn field_predicate(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>> {
         let lazy = fctx.lazy();
         let optional = fctx.optional();
@@ -574,6 +608,34 @@ impl<'a> FXCodeGenContextual for FXCodeGenPlain<'a> {
         Ok(None)
     }
 
+    fn field_lock(&self, _fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>> {
+        Ok(None)
+    }
+
+    fn field_try_reader(&self, _fctx: &FXDeriveFieldCtx, _timed: bool) -> darling::Result<Option<FXFnConstructor>> {
+        Ok(None)
+    }
+
+    fn field_try_writer(&self, _fctx: &FXDeriveFieldCtx, _timed: bool) -> darling::Result<Option<FXFnConstructor>> {
+        Ok(None)
+    }
+
+    fn field_fetch_add(&self, _fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>> {
+        Ok(None)
+    }
+
+    fn field_swap(&self, _fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>> {
+        Ok(None)
+    }
+
+    fn field_compare_exchange(&self, _fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>> {
+        Ok(None)
+    }
+
+    fn field_update(&self, _fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>> {
+        Ok(None)
+    }
+
     #[cfg(feature = "serde")]
     fn field_from_shadow(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<FXToksMeta> {
         let field_ident = fctx.ident();
@@ -627,4 +689,69 @@ impl<'a> FXCodeGenContextual for FXCodeGenPlain<'a> {
         }
         .into())
     }
+
+    #[cfg(feature = "serde")]
+    fn field_serialize_ref(
+        &self,
+        fctx: &FXDeriveFieldCtx,
+        mc: &mut FXFnConstructor,
+        lifetime: &TokenStream,
+    ) -> darling::Result<(TokenStream, TokenStream)> {
+        let field_ident = fctx.ident();
+        let ty = fctx.ty();
+        let span = fctx.serialize().final_span();
+        let ser_ref = quote_spanned![span=> ::fieldx::serde::FXSerializeRef];
+        let ref_ty = quote_spanned![span=> #ser_ref<#lifetime, #ty>];
+        let inner_mut = fctx.inner_mut();
+
+        Ok(if *fctx.lazy() {
+            if !*fctx.serde_skip_unset() {
+                let lazy_init = self.field_lazy_initializer(fctx, mc)?;
+                let borrow = if *inner_mut {
+                    quote_spanned![span=> .borrow()]
+                }
+                else {
+                    quote![]
+                };
+                mc.add_statement(quote_spanned![span=> let _ = self.#field_ident #borrow #lazy_init;]);
+            }
+            (
+                quote_spanned![span=> ::std::option::Option<#ref_ty>],
+                if *inner_mut {
+                    quote_spanned![span=>
+                        ::fieldx::plain::Ref::filter_map(self.#field_ident.borrow(), |v| v.get()).ok().map(#ser_ref::guard)
+                    ]
+                }
+                else {
+                    quote_spanned![span=> self.#field_ident.get().map(#ser_ref::Ref)]
+                },
+            )
+        }
+        else if *fctx.optional() {
+            (
+                quote_spanned![span=> ::std::option::Option<#ref_ty>],
+                if *inner_mut {
+                    quote_spanned![span=> #ser_ref::option_guard(self.#field_ident.borrow())]
+                }
+                else {
+                    quote_spanned![span=> #ser_ref::option_ref(&self.#field_ident)]
+                },
+            )
+        }
+        else if *inner_mut {
+            (ref_ty, quote_spanned![span=> #ser_ref::guard(self.#field_ident.borrow())])
+        }
+        else {
+            (ref_ty, quote_spanned![span=> #ser_ref::Ref(&self.#field_ident)])
+        })
+    }
+
+    #[cfg(feature = "serde")]
+    fn field_serialize_guard(
+        &self,
+        _fctx: &FXDeriveFieldCtx,
+        _mc: &mut FXFnConstructor,
+    ) -> darling::Result<Option<TokenStream>> {
+        Ok(None)
+    }
 }
@@ -2,6 +2,7 @@ use crate::util::std_default_expr_toks;
 
 use super::derive_ctx::FXDeriveFieldCtx;
 use super::FXCodeGenContextual;
+use super::FXPartialField;
 use fieldx_aux::FXOrig;
 use fieldx_aux::FXProp;
 use fieldx_core::codegen::constructor::FXConstructor;
@@ -41,6 +42,25 @@ fn serde_rename_attr(
     }
 }
 
+// Type and value of a borrowed shadow field referring to the value behind a guard taken in advance.
+fn serde_guard_ref(fctx: &FXDeriveFieldCtx, guard_var: &syn::Ident, lifetime: &TokenStream) -> (TokenStream, TokenStream) {
+    let span = fctx.serialize().final_span();
+    let ty = fctx.ty();
+    let ser_ref = quote_spanned![span=> ::fieldx::serde::FXSerializeRef];
+    if *fctx.serde_optional() {
+        (
+            quote_spanned![span=> ::std::option::Option<#ser_ref<#lifetime, #ty>>],
+            quote_spanned![span=> #ser_ref::option_ref(&*#guard_var)],
+        )
+    }
+    else {
+        (
+            quote_spanned![span=> #ser_ref<#lifetime, #ty>],
+            quote_spanned![span=> #ser_ref::Ref(&*#guard_var)],
+        )
+    }
+}
+
 pub(crate) trait FXCGenSerde: FXCodeGenContextual {
     fn filter_shadow_attributes<'a>(
         &'a self,
@@ -51,7 +71,10 @@ pub(crate) trait FXCGenSerde: FXCodeGenContextual {
                 fctx.field()
                     .attrs()
                     .iter()
-                    .filter(move |a| a.path().is_ident("serde") || forward_attrs.contains(a.path())),
+                    .filter(move |a| {
+                        // Field docs are kept for the schema of the shadow.
+                        a.path().is_ident("serde") || a.path().is_ident("doc") || forward_attrs.contains(a.path())
+                    }),
             )
         }
         else {
@@ -82,7 +105,8 @@ pub(crate) trait FXCGenSerde: FXCodeGenContextual {
         None
     }
 
-    fn serde_field_attribute(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<TokenStream> {
+    // Defaults only matter for deserialization and are omitted for the borrowed shadow struct.
+    fn serde_field_attribute(&self, fctx: &FXDeriveFieldCtx, with_default: bool) -> darling::Result<TokenStream> {
         let ctx = self.ctx();
         Ok(if *ctx.arg_props().serde() {
             let span = fctx.serde().final_span();
@@ -94,7 +118,7 @@ pub(crate) trait FXCGenSerde: FXCodeGenContextual {
 
             let mut default_arg = None;
 
-            if let Some(default_value) = fctx.serde_default_value() {
+            if let Some(default_value) = fctx.serde_default_value().filter(|_| with_default) {
                 // Safe because of has_default()
                 let dv_span = default_value.final_span();
 
@@ -127,10 +151,23 @@ pub(crate) trait FXCGenSerde: FXCodeGenContextual {
                 }
             }
 
+            let skip_none = fctx.serde_skip_none();
+            if *skip_none && with_default && *fctx.deserialize() && default_arg.is_none() {
+                default_arg = Some(quote_spanned![skip_none.final_span()=> default]);
+            }
+
             if let Some(default_arg) = default_arg {
                 serde_attr_args.push(default_arg);
             }
 
+            let skip_unset = fctx.serde_skip_unset();
+            let skip_if_none = if *skip_unset { skip_unset } else { skip_none };
+            if *skip_if_none && *fctx.serialize() {
+                serde_attr_args.push(
+                    quote_spanned![skip_if_none.final_span()=> skip_serializing_if = "::std::option::Option::is_none"],
+                );
+            }
+
             if let Some(rename_arg) =
                 serde_rename_attr(fctx.serde_rename_serialize(), fctx.serde_rename_deserialize(), span)
             {
@@ -173,20 +210,26 @@ pub(crate) trait FXCGenSerde: FXCodeGenContextual {
         value
     }
 
-    fn serde_shadow_field(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<()> {
-        let mut fc = FXFieldConstructor::new(
-            fctx.ident().clone(),
-            self.serde_shadow_field_type(fctx),
-            fctx.serde().final_span(),
-        );
-
-        fc.set_type(self.serde_shadow_field_type(fctx))
-            .add_attributes(self.filter_shadow_attributes(fctx))
-            .add_attribute_toks(self.ctx().ok_or_empty(self.serde_field_attribute(fctx)))?;
+    // Field of the shadow struct or of its borrowed counterpart.
+    fn serde_shadow_field_constructor(
+        &self,
+        fctx: &FXDeriveFieldCtx,
+        ty: TokenStream,
+        with_default: bool,
+    ) -> darling::Result<FXFieldConstructor> {
+        let mut fc = FXFieldConstructor::new(fctx.ident().clone(), ty, fctx.serde().final_span());
+
+        fc.add_attributes(self.filter_shadow_attributes(fctx))
+            .add_attribute_toks(self.ctx().ok_or_empty(self.serde_field_attribute(fctx, with_default)))?;
         if let Some(serde_attrs) = fctx.serde_attributes() {
             fc.add_attributes(serde_attrs.iter());
         }
 
+        Ok(fc)
+    }
+
+    fn serde_shadow_field(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<()> {
+        let fc = self.serde_shadow_field_constructor(fctx, self.serde_shadow_field_type(fctx), true)?;
         self.ctx().impl_ctx().shadow_struct_mut()?.add_field(fc);
 
         Ok(())
@@ -246,11 +289,20 @@ pub(crate) trait FXCGenSerde: FXCodeGenContextual {
 }
 
 pub(crate) trait FXRewriteSerde<'a> {
-    fn serde_derive_traits(&'a self) -> Vec<TokenStream>;
+    fn serde_borrowed(&'a self) -> bool;
+    fn serde_derive_traits(&'a self, serialize: bool, deserialize: bool) -> Vec<TokenStream>;
     fn serde_struct_attribute(&'a self) -> darling::Result<()>;
     fn serde_shadow_struct(&'a self) -> darling::Result<Option<FXStructConstructor>>;
     fn serde_struct_from_shadow(&'a self) -> darling::Result<()>;
     fn serde_struct_into_shadow(&'a self) -> darling::Result<()>;
+    fn serde_struct_serialize(&'a self) -> darling::Result<()>;
+    fn serde_struct_serialize_async(&'a self) -> darling::Result<()>;
+    fn serde_shadow_migrate(&'a self) -> darling::Result<()>;
+    fn serde_struct_update(&'a self) -> darling::Result<()>;
+    #[cfg(feature = "schemars")]
+    fn serde_struct_json_schema(&'a self) -> darling::Result<()>;
+    fn serde_deserialize_type(&'a self) -> TokenStream;
+    fn serde_versioned(&'a self, value: TokenStream) -> TokenStream;
     fn serde_prepare_struct(&'a self) -> darling::Result<()>;
     fn serde_rewrite_struct(&'a self);
     fn serde_shadow_field_default_fn(&'a self) -> darling::Result<Option<TokenStream>>;
@@ -259,25 +311,31 @@ pub(crate) trait FXRewriteSerde<'a> {
 }
 
 impl<'a> FXRewriteSerde<'a> for super::FXRewriter<'a> {
-    fn serde_derive_traits(&self) -> Vec<TokenStream> {
+    // Structs without async fields implement `Serialize` via a borrowed shadow struct. Those with async fields can't do it
+    // without blocking; they keep being serialized with `serde`'s `into` and get `serialize_async()` in addition.
+    fn serde_borrowed(&'a self) -> bool {
+        !self.ctx().all_field_ctx().iter().any(|fctx| *fctx.mode_async())
+    }
+
+    fn serde_derive_traits(&self, serialize: bool, deserialize: bool) -> Vec<TokenStream> {
         let mut traits: Vec<TokenStream> = vec![];
         let ctx = self.ctx();
         let arg_props = ctx.arg_props();
 
         let needs_serialize = arg_props.needs_serialize();
-        if *needs_serialize {
+        if serialize && *needs_serialize {
             traits.push(quote_spanned![needs_serialize.final_span()=> Serialize]);
         }
 
         let needs_deserialize = arg_props.needs_deserialize();
-        if *needs_deserialize {
+        if deserialize && *needs_deserialize {
             traits.push(quote_spanned![needs_deserialize.final_span()=> Deserialize]);
         }
 
         traits
     }
 
-    fn serde_struct_attribute(&self) -> darling::Result<()> {
+    fn serde_struct_attribute(&'a self) -> darling::Result<()> {
         let ctx = self.ctx();
         let arg_props = ctx.arg_props();
         let serde = arg_props.serde();
@@ -285,26 +343,20 @@ impl<'a> FXRewriteSerde<'a> for super::FXRewriter<'a> {
         ctx.user_struct_mut().add_attribute_toks(if *serde {
             let mut serde_args: Vec<TokenStream> = vec![];
 
-            let (_, generics, _) = ctx.input().generics().split_for_impl();
-            let mut shadow_ident_str = syn::LitStr::new(
-                &format!(
-                    "{}{}",
-                    arg_props.serde_shadow_ident().unwrap(),
-                    generics.to_token_stream(),
-                ),
-                serde.final_span(),
-            );
+            let mut shadow_ident_str =
+                syn::LitStr::new(&self.serde_deserialize_type().to_string(), serde.final_span());
             let serde_span = serde.final_span();
 
             let needs_serialize = arg_props.needs_serialize();
-            if *needs_serialize {
+            if *needs_serialize && !self.serde_borrowed() {
                 let span = needs_serialize.final_span();
                 shadow_ident_str.set_span(span);
                 serde_args.push(quote_spanned![span=> into = #shadow_ident_str]);
             }
 
+            // Reference-counted structs are deserialized with `deserialize_rc`.
             let needs_deserialize = arg_props.needs_deserialize();
-            if *needs_deserialize {
+            if *needs_deserialize && !*arg_props.rc() {
                 let span = needs_deserialize.final_span();
                 shadow_ident_str.set_span(span);
                 serde_args.push(quote_spanned![span=> from = #shadow_ident_str]);
@@ -391,7 +443,7 @@ impl<'a> FXRewriteSerde<'a> for super::FXRewriter<'a> {
                 .set_vis(arg_props.serde_visibility())
                 .set_generics(ctx.input().generics().clone())
                 .maybe_add_doc(arg_props.serde_doc())?
-                .add_attribute_toks(crate::util::derive_toks(&self.serde_derive_traits()))?;
+                .add_attribute_toks(crate::util::derive_toks(&self.serde_derive_traits(true, true)))?;
 
             if let Some(attrs) = arg_props.serde_attributes() {
                 shadow_struct.add_attributes(attrs.iter());
@@ -409,6 +461,26 @@ impl<'a> FXRewriteSerde<'a> for super::FXRewriter<'a> {
                 serde_attr_args.push(default_attr_arg);
             }
 
+            #[cfg(feature = "schemars")]
+            if *arg_props.serde_schema() {
+                // The schema describes the struct itself, hence is named after it unless renamed for serde.
+                let schema_name = if arg_props.serde_rename_deserialize().is_none() {
+                    let name = syn::LitStr::new(&ctx.input_ident().to_string(), span);
+                    quote_spanned![span=> , rename = #name]
+                }
+                else {
+                    quote![]
+                };
+                shadow_struct.add_attribute_toks(quote_spanned![span=>
+                    #[derive(::fieldx::schemars::JsonSchema)]
+                    #[schemars(crate = "::fieldx::schemars" #schema_name)]
+                ])?;
+                // Struct docs become the description of the schema.
+                if arg_props.serde_doc().is_none() {
+                    shadow_struct.add_attributes(ctx.input().attrs().iter().filter(|a| a.path().is_ident("doc")));
+                }
+            }
+
             if !serde_attr_args.is_empty() {
                 shadow_struct.add_attribute_toks(quote_spanned![span=> #[serde(#( #serde_attr_args ),*)]])?;
             }
@@ -464,18 +536,32 @@ impl<'a> FXRewriteSerde<'a> for super::FXRewriter<'a> {
                         let field_ident = fctx.ident();
                         let fetch_shadow_field = cgen.field_from_shadow(&fctx)?;
                         let attributes = &fetch_shadow_field.attributes;
-                        fields.push(quote_spanned![deserialize.final_span()=>
-                            #( #attributes )*
-                            #field_ident: #fetch_shadow_field
-                        ]);
+                        fields.push((
+                            fctx.clone(),
+                            quote_spanned![deserialize.final_span()=>
+                                #( #attributes )*
+                                #field_ident: #fetch_shadow_field
+                            ],
+                        ));
                         Ok(())
                     });
                 }
+                else if fctx.lock_group().is_some() {
+                    // Lock group members are not reachable by `..Self::default()`.
+                    if let Some(default_expr) = fctx.default_expr().clone() {
+                        fields.push((fctx.clone(), default_expr.to_token_stream()));
+                    }
+                }
+                else if *arg_props.rc() && arg_props.myself_field_ident() == Some(fctx.ident()) {
+                    // `myself` is set when the struct is created within its container.
+                }
                 else {
                     need_default_init = true;
                 }
             }
 
+            let fields = self.field_inits(fields)?;
+
             // If there are fields that are not deserialized, initialize them with defaults
             let init_from_default = if need_default_init && *arg_props.needs_default() {
                 quote_spanned![span=> .. Self::default()]
@@ -484,10 +570,84 @@ impl<'a> FXRewriteSerde<'a> for super::FXRewriter<'a> {
                 quote![]
             };
 
-            from_method.set_ret_stmt(quote_spanned![span=> Self { #( #fields, )* #init_from_default }]);
-            from_impl.add_method(from_method);
-
-            impl_ctx.shadow_struct_mut()?.add_trait_impl(from_impl);
+            let rc = arg_props.rc();
+            if *rc {
+                // A reference-counted struct must be constructed within its container to have `myself` set. Hence,
+                // instead of `From`, it gets an associated function to be used with `deserialize_with`.
+                let rc_span = rc.final_span();
+                let cgen = self.struct_codegen();
+                let mut mc = FXFnConstructor::new_associated(format_ident!("deserialize_rc", span = rc_span));
+                let deserializer = format_ident!("__FXD", span = rc_span);
+                let rc_type = cgen.maybe_ref_counted(&quote_spanned![rc_span=> Self]);
+                let deserialize_type = self.serde_deserialize_type();
+                let into_data = if arg_props.serde_version().is_some() {
+                    quote_spanned![rc_span=> .into_data()]
+                }
+                else {
+                    quote![]
+                };
+                let struct_init = cgen.maybe_ref_counted_create(
+                    &quote_spanned![rc_span=> Self],
+                    &quote_spanned![rc_span=> #( #fields, )* #init_from_default],
+                    None,
+                    None,
+                );
+
+                mc.set_span(rc_span)
+                    .set_vis(arg_props.new_visibility())
+                    .add_lifetime(quote_spanned![rc_span=> 'de])
+                    .maybe_add_generic(Some(quote_spanned![rc_span=> #deserializer]))
+                    .add_where_bound(quote_spanned![rc_span=> #deserializer: ::fieldx::serde::Deserializer<'de>])
+                    .add_where_bound(quote_spanned![rc_span=> #deserialize_type: ::fieldx::serde::Deserialize<'de>])
+                    .add_param(quote_spanned![rc_span=> deserializer: #deserializer])
+                    .set_ret_type(quote_spanned![rc_span=>
+                        ::std::result::Result<#rc_type, #deserializer::Error>
+                    ])
+                    .add_statement(quote_spanned![rc_span=>
+                        let #shadow_var =
+                            <#deserialize_type as ::fieldx::serde::Deserialize>::deserialize(deserializer)? #into_data;
+                    ])
+                    .set_ret_stmt(quote_spanned![rc_span=> ::std::result::Result::Ok(#struct_init)]);
+                mc.add_attribute_toks(quote_spanned![rc_span=> #[allow(dead_code)]])?
+                    .add_doc(&FXProp::new(
+                        vec![syn::LitStr::new(
+                            "Deserialize an instance of the struct wrapped into its reference-counted container.",
+                            rc_span,
+                        )],
+                        Some(rc_span),
+                    ))?;
+
+                ctx.add_method(mc);
+            }
+            else {
+                from_method.set_ret_stmt(quote_spanned![span=> Self { #( #fields, )* #init_from_default }]);
+                from_impl.add_method(from_method);
+
+                impl_ctx.shadow_struct_mut()?.add_trait_impl(from_impl);
+
+                if let Some(version) = arg_props.serde_version() {
+                    // Versioned data is migrated to the current layout by the time it gets here.
+                    let vspan = version.final_span();
+                    let deserialize_type = self.serde_deserialize_type();
+                    let mut versioned_impl = FXImplConstructor::new(format_ident!("From", span = vspan));
+                    let mut versioned_method = FXFnConstructor::new_associated(format_ident!("from", span = vspan));
+                    versioned_method
+                        .set_span(vspan)
+                        .add_param(quote_spanned! {vspan=> versioned: #deserialize_type})
+                        .set_ret_type(quote_spanned! {vspan=> Self})
+                        .set_ret_stmt(quote_spanned! {vspan=>
+                            <Self as ::std::convert::From<#shadow_ident #generics>>::from(versioned.into_data())
+                        });
+                    versioned_impl
+                        .set_span(vspan)
+                        .set_for_ident(ctx.input_ident().clone())
+                        .set_from_generics(Some(input_generics.clone()))
+                        .set_trait_generics(deserialize_type)
+                        .add_method(versioned_method);
+
+                    impl_ctx.shadow_struct_mut()?.add_trait_impl(versioned_impl);
+                }
+            }
         }
 
         Ok(())
@@ -522,21 +682,42 @@ impl<'a> FXRewriteSerde<'a> for super::FXRewriter<'a> {
                 .set_ret_type(quote_spanned! {span=> Self})
                 .set_self_mut(true);
 
+            let mut unlocked_groups = vec![];
+            let mut group_unlocks = vec![];
             for fctx in ctx.all_field_ctx() {
                 let serialize = fctx.serialize();
                 if *fctx.serde() && *serialize {
                     let field_ident = fctx.ident();
 
+                    if let Some(group) = fctx.lock_group() {
+                        // Group members are moved out of the unlocked group value. It is done after the lazy fields
+                        // are initialized because their builders need the whole struct.
+                        let group_field_ident = arg_props.lock_group_field_ident(group);
+                        if !unlocked_groups.contains(&group_field_ident) {
+                            group_unlocks.push(quote_spanned! {group.span()=>
+                                let #group_field_ident = #me_var.#group_field_ident.into_inner();
+                            });
+                            unlocked_groups.push(group_field_ident);
+                        }
+                    }
+
                     ctx.exec_or_record(|| {
                         let cgen = self.field_codegen(&fctx)?;
                         let fetch_struct_field = cgen.field_from_struct(&fctx)?;
 
+                        // Async builders can't be awaited here.
                         let lazy = fctx.lazy();
-                        if *lazy {
+                        if *lazy && !*fctx.serde_skip_unset() && !*fctx.mode_async() {
                             let lazy_init = cgen.field_lazy_initializer(&fctx, &mut from_method)?;
-                            from_method.add_statement(
-                                quote_spanned![serialize.final_span()=> #me_var.#field_ident #lazy_init; ],
-                            );
+                            // Lazy members of lock groups are initialized by a standalone expression.
+                            let target = if fctx.lock_group().is_some() {
+                                quote![]
+                            }
+                            else {
+                                quote_spanned![serialize.final_span()=> #me_var.#field_ident ]
+                            };
+                            from_method
+                                .add_statement(quote_spanned![serialize.final_span()=> #target #lazy_init; ]);
                         }
 
                         fields.push(quote_spanned![serialize.final_span()=> #field_ident: #fetch_struct_field ]);
@@ -546,6 +727,9 @@ impl<'a> FXRewriteSerde<'a> for super::FXRewriter<'a> {
                 }
             }
 
+            for unlock in group_unlocks {
+                from_method.add_statement(unlock);
+            }
             from_method.set_ret_stmt(quote_spanned! {span=>
                 Self {
                     #( #fields ),*
@@ -558,6 +742,569 @@ impl<'a> FXRewriteSerde<'a> for super::FXRewriter<'a> {
         Ok(())
     }
 
+    // Implement `Serialize` for the user struct via a borrowed shadow struct which refers to the field values instead of
+    // taking them over.
+    fn serde_struct_serialize(&'a self) -> darling::Result<()> {
+        let ctx = self.ctx();
+        let impl_ctx = ctx.impl_ctx();
+        let arg_props = ctx.arg_props();
+        let needs_serialize = arg_props.needs_serialize();
+        let snapshot = arg_props.serde_snapshot();
+
+        // Both the borrowed and the async serialization take the snapshot, hence the checks are done here once.
+        if *snapshot && *arg_props.serde() {
+            if !*ctx.syncish() {
+                return Err(darling::Error::custom("serde(snapshot) requires sync or async mode")
+                    .with_span(&snapshot.final_span()));
+            }
+            if !*needs_serialize {
+                return Err(
+                    darling::Error::custom("serde(snapshot) requires serialization").with_span(&snapshot.final_span())
+                );
+            }
+        }
+
+        if !(*arg_props.serde() && *needs_serialize && self.serde_borrowed()) {
+            return Ok(());
+        }
+
+        let span = needs_serialize.final_span();
+        let struct_ident = ctx.input_ident();
+        let shadow_ident = arg_props.serde_shadow_ident().unwrap();
+        let ref_ident = format_ident!("{}Ref", shadow_ident, span = span);
+        let lifetime = quote_spanned! {span=> 'fx_ser};
+        let input_generics = ctx.input().generics();
+        let generics = input_generics.split_for_impl().1;
+
+        let mut ref_generics = input_generics.clone();
+        ref_generics.params.insert(0, syn::parse2(lifetime.clone())?);
+
+        let mut ref_struct = FXStructConstructor::new(ref_ident.clone());
+        ref_struct
+            .set_span(span)
+            .set_generics(ref_generics)
+            .add_attribute_toks(quote_spanned! {span=> #[derive(Serialize)]})?;
+
+        if let Some(attrs) = arg_props.serde_attributes() {
+            ref_struct.add_attributes(attrs.iter());
+        }
+
+        if let Some(rename_attr) = serde_rename_attr(
+            arg_props.serde_rename_serialize(),
+            arg_props.serde_rename_deserialize(),
+            span,
+        ) {
+            ref_struct.add_attribute_toks(quote_spanned![span=> #[serde(#rename_attr)]])?;
+        }
+
+        let serializer_type = format_ident!("__FXS", span = span);
+        let mut serialize_method = FXFnConstructor::new(format_ident!("serialize", span = span));
+        serialize_method
+            .set_span(span)
+            .maybe_add_generic(Some(quote_spanned! {span=> #serializer_type: ::fieldx::serde::Serializer}))
+            .add_param(quote_spanned! {span=> serializer: #serializer_type})
+            .set_ret_type(quote_spanned! {span=>
+                ::std::result::Result<#serializer_type::Ok, #serializer_type::Error>
+            });
+
+        let mut fields = vec![];
+        let mut locked_groups = vec![];
+        let mut guards = vec![];
+        for fctx in ctx.all_field_ctx() {
+            let serialize = fctx.serialize();
+            if *fctx.serde() && *serialize {
+                if let Some(group) = fctx.lock_group() {
+                    // A group is locked once for all of its members, after the lazy fields are initialized.
+                    let group_field_ident = arg_props.lock_group_field_ident(group);
+                    if !locked_groups.contains(&group_field_ident) {
+                        guards.push(quote_spanned! {group.span()=>
+                            let #group_field_ident = self.#group_field_ident.read();
+                        });
+                        locked_groups.push(group_field_ident);
+                    }
+                }
+
+                ctx.exec_or_record(|| {
+                    let cgen = self.field_codegen(&fctx)?;
+                    let field_ident = fctx.ident();
+                    let fspan = serialize.final_span();
+                    let guard = if *snapshot {
+                        cgen.field_serialize_guard(&fctx, &mut serialize_method)?
+                    }
+                    else {
+                        None
+                    };
+                    let (ty, value) = if let Some(guard) = guard {
+                        // Taken along with the group guards, in the order of field declarations.
+                        let guard_var = format_ident!("__fx_{}", field_ident, span = fspan);
+                        guards.push(quote_spanned! {fspan=> let #guard_var = #guard;});
+                        serde_guard_ref(&fctx, &guard_var, &lifetime)
+                    }
+                    else {
+                        cgen.field_serialize_ref(&fctx, &mut serialize_method, &lifetime)?
+                    };
+                    ref_struct.add_field(cgen.serde_shadow_field_constructor(&fctx, ty, false)?);
+                    fields.push(quote_spanned![fspan=> #field_ident: #value]);
+                    Ok(())
+                });
+            }
+        }
+
+        let phantom_ident = format_ident!("__fx_phantom", span = span);
+        let mut phantom_field = FXFieldConstructor::new(
+            phantom_ident.clone(),
+            quote_spanned! {span=> ::std::marker::PhantomData<&#lifetime #struct_ident #generics>},
+            span,
+        );
+        phantom_field.add_attribute_toks(quote_spanned! {span=> #[serde(skip)]})?;
+        ref_struct.add_field(phantom_field);
+
+        for guard in guards {
+            serialize_method.add_statement(guard);
+        }
+        // The borrowed struct must be dropped before the group guards it refers to, hence not a temporary.
+        let ref_var = format_ident!("__fx_shadow_ref", span = span);
+        let versioned = self.serde_versioned(quote_spanned! {span=> &#ref_var});
+        serialize_method
+            .add_statement(quote_spanned! {span=>
+                let #ref_var = #ref_ident {
+                    #( #fields, )*
+                    #phantom_ident: ::std::marker::PhantomData,
+                };
+            })
+            .set_ret_stmt(quote_spanned! {span=> ::fieldx::serde::Serialize::serialize(#versioned, serializer)});
+
+        // Like a derived implementation, require all type parameters to be serializable.
+        let mut impl_generics = input_generics.clone();
+        let type_params = input_generics.type_params().map(|tp| tp.ident.clone()).collect::<Vec<_>>();
+        if !type_params.is_empty() {
+            let where_clause = impl_generics.make_where_clause();
+            for tp in type_params {
+                where_clause
+                    .predicates
+                    .push(syn::parse2(quote_spanned! {span=> #tp: ::fieldx::serde::Serialize})?);
+            }
+        }
+
+        let mut serialize_impl =
+            FXImplConstructor::new(syn::parse2::<syn::Path>(quote_spanned! {span=> ::fieldx::serde::Serialize})?);
+        serialize_impl
+            .set_span(span)
+            .set_from_generics(Some(impl_generics))
+            .set_for_ident(struct_ident.clone())
+            .add_method(serialize_method);
+
+        ref_struct.add_trait_impl(serialize_impl);
+        impl_ctx.set_shadow_ref_struct(ref_struct);
+
+        Ok(())
+    }
+
+    // Structs with async fields can't be serialized from within a runtime without blocking it. For them, `to_shadow()`
+    // awaits every lock and clones the field values into the shadow struct, and `serialize_async()` serializes it.
+    fn serde_struct_serialize_async(&'a self) -> darling::Result<()> {
+        let ctx = self.ctx();
+        let arg_props = ctx.arg_props();
+        let needs_serialize = arg_props.needs_serialize();
+
+        if !*arg_props.serde() || !*needs_serialize || self.serde_borrowed() {
+            return Ok(());
+        }
+
+        let span = needs_serialize.final_span();
+        let vis = ctx.input().vis();
+        let shadow_ident = arg_props.serde_shadow_ident().unwrap();
+        let input_generics = ctx.input().generics();
+        let generics = input_generics.split_for_impl().1;
+        let type_params = input_generics.type_params().map(|tp| tp.ident.clone()).collect::<Vec<_>>();
+        // `FXSerializeRef` types are not used for the shadow, but `field_serialize_ref` needs a lifetime anyway.
+        let lifetime = quote_spanned! {span=> '_};
+
+        let mut to_shadow_method = FXFnConstructor::new(format_ident!("to_shadow", span = span));
+        to_shadow_method
+            .set_span(span)
+            .set_vis(vis)
+            .set_async(FXProp::new(true, Some(span)))
+            .set_ret_type(quote_spanned! {span=> #shadow_ident #generics})
+            .add_attribute_toks(quote_spanned! {span=> #[allow(dead_code)]})?
+            .add_doc(&FXProp::new(
+                vec![syn::LitStr::new(
+                    "Snapshot of the struct for serialization. Locks are awaited, lazy fields are initialized.",
+                    span,
+                )],
+                Some(span),
+            ))?;
+        for tp in type_params.iter() {
+            to_shadow_method.add_where_bound(quote_spanned! {span=> #tp: ::std::clone::Clone});
+        }
+
+        let snapshot = arg_props.serde_snapshot();
+        let mut fields = vec![];
+        let mut locked_groups = vec![];
+        // With `snapshot` the guards are taken after all lazy fields are initialized and are held until the shadow is
+        // complete.
+        let mut guards = vec![];
+        let mut values = vec![];
+        for fctx in ctx.all_field_ctx() {
+            let serialize = fctx.serialize();
+            if *fctx.serde() && *serialize {
+                ctx.exec_or_record(|| {
+                    let cgen = self.field_codegen(&fctx)?;
+                    let field_ident = fctx.ident();
+                    let fspan = serialize.final_span();
+                    let var = format_ident!("__fx_{}", field_ident, span = fspan);
+
+                    if let Some(group) = fctx.lock_group() {
+                        let group_field_ident = arg_props.lock_group_field_ident(group);
+                        if !locked_groups.contains(&group_field_ident) {
+                            let await_call = fctx.impl_details().await_call(group.span());
+                            let group_lock = quote_spanned! {group.span()=>
+                                let #group_field_ident = self.#group_field_ident.read()#await_call;
+                            };
+                            if *snapshot {
+                                guards.push(group_lock);
+                            }
+                            else {
+                                to_shadow_method.add_statement(group_lock);
+                            }
+                            locked_groups.push(group_field_ident);
+                        }
+                    }
+
+                    let guard = if *snapshot {
+                        cgen.field_serialize_guard(&fctx, &mut to_shadow_method)?
+                    }
+                    else {
+                        None
+                    };
+                    if let Some(guard) = guard {
+                        // Raw guards, unlike `FXSerializeRef`, don't prevent the future from being `Send`.
+                        guards.push(quote_spanned! {fspan=> let #var = #guard;});
+                        fields.push(quote_spanned! {fspan=> #field_ident: ::std::clone::Clone::clone(&*#var)});
+                        return Ok(());
+                    }
+
+                    let (_, value) = cgen.field_serialize_ref(&fctx, &mut to_shadow_method, &lifetime)?;
+                    let value = if *fctx.serde_optional() {
+                        quote_spanned! {fspan=> (#value).map(|v| ::std::clone::Clone::clone(&*v))}
+                    }
+                    else {
+                        quote_spanned! {fspan=> ::std::clone::Clone::clone(&*#value)}
+                    };
+                    // Every value is taken by its own statement to have its guard released before the next await.
+                    let value = quote_spanned! {fspan=> let #var = #value;};
+                    if *snapshot {
+                        values.push(value);
+                    }
+                    else {
+                        to_shadow_method.add_statement(value);
+                    }
+                    fields.push(quote_spanned! {fspan=> #field_ident: #var});
+                    Ok(())
+                });
+            }
+        }
+
+        for stmt in guards.into_iter().chain(values) {
+            to_shadow_method.add_statement(stmt);
+        }
+        to_shadow_method.set_ret_stmt(quote_spanned! {span=> #shadow_ident { #( #fields ),* } });
+
+        let serializer_type = format_ident!("__FXS", span = span);
+        let versioned = self.serde_versioned(quote_spanned! {span=> &self.to_shadow().await});
+        let mut serialize_method = FXFnConstructor::new(format_ident!("serialize_async", span = span));
+        serialize_method
+            .set_span(span)
+            .set_vis(vis)
+            .set_async(FXProp::new(true, Some(span)))
+            .maybe_add_generic(Some(quote_spanned! {span=> #serializer_type: ::fieldx::serde::Serializer}))
+            .add_param(quote_spanned! {span=> serializer: #serializer_type})
+            .set_ret_type(quote_spanned! {span=>
+                ::std::result::Result<#serializer_type::Ok, #serializer_type::Error>
+            })
+            .set_ret_stmt(quote_spanned! {span=> ::fieldx::serde::Serialize::serialize(#versioned, serializer)})
+            .add_attribute_toks(quote_spanned! {span=> #[allow(dead_code)]})?
+            .add_doc(&FXProp::new(
+                vec![syn::LitStr::new(
+                    "Serialize the struct without blocking the async runtime.",
+                    span,
+                )],
+                Some(span),
+            ))?;
+        for tp in type_params.iter() {
+            serialize_method
+                .add_where_bound(quote_spanned! {span=> #tp: ::std::clone::Clone + ::fieldx::serde::Serialize});
+        }
+
+        ctx.add_method(to_shadow_method);
+        ctx.add_method(serialize_method);
+
+        Ok(())
+    }
+
+    // Make the shadow of a versioned struct read the data of older layouts and upgrade it through the chain of the
+    // migration functions.
+    fn serde_shadow_migrate(&'a self) -> darling::Result<()> {
+        let ctx = self.ctx();
+        let arg_props = ctx.arg_props();
+
+        let Some(version) = arg_props.serde_version()
+        else {
+            return Ok(());
+        };
+
+        if !*arg_props.serde() || !*arg_props.needs_deserialize() {
+            return Ok(());
+        }
+
+        let span = version.final_span();
+        let current = *version;
+        let cgen = self.struct_codegen();
+        let shadow_ident = arg_props.serde_shadow_ident().unwrap();
+        let input_generics = ctx.input().generics();
+        let generics = input_generics.split_for_impl().1;
+        let data_type = format_ident!("__FXV", span = span);
+        let migrations = arg_props.serde_migrations();
+
+        let mut arms = vec![quote_spanned! {span=> #current => data.next(), }];
+        for (idx, migration) in migrations.iter().enumerate() {
+            let from = migration.version();
+            let from_span = from.final_span();
+            let from = *from;
+            let mut chain = migrations[idx..]
+                .iter()
+                .map(|m| cgen.fixup_self_type(m.migrate().to_token_stream()));
+            let first = chain.next().unwrap();
+            arms.push(quote_spanned! {from_span=>
+                #from => {
+                    let upgraded = data.migrate(#first)?;
+                    #( let upgraded = #chain(upgraded); )*
+                    ::std::result::Result::Ok(upgraded)
+                }
+            });
+        }
+
+        let mut migrate_method = FXFnConstructor::new_associated(format_ident!("migrate", span = span));
+        migrate_method
+            .set_span(span)
+            .maybe_add_generic(Some(quote_spanned! {span=> #data_type}))
+            .add_where_bound(quote_spanned! {span=> #data_type: ::fieldx::serde::FXVersionedData<'de>})
+            .add_param(quote_spanned! {span=> version: u32})
+            .add_param(quote_spanned! {span=> data: &mut #data_type})
+            .set_ret_type(quote_spanned! {span=> ::std::result::Result<Self, #data_type::Error>})
+            .set_ret_stmt(quote_spanned! {span=>
+                match version {
+                    #( #arms )*
+                    _ => ::std::result::Result::Err(::fieldx::serde::unsupported_version(version)),
+                }
+            });
+
+        let mut impl_generics = input_generics.clone();
+        impl_generics.params.insert(0, syn::parse2(quote_spanned! {span=> 'de})?);
+        impl_generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse2(
+                quote_spanned! {span=> #shadow_ident #generics: ::fieldx::serde::Deserialize<'de>},
+            )?);
+
+        let mut migrate_impl =
+            FXImplConstructor::new(syn::parse2::<syn::Path>(quote_spanned! {span=> ::fieldx::serde::FXMigrate})?);
+        migrate_impl
+            .set_span(span)
+            .set_from_generics(Some(impl_generics))
+            .set_generics(generics.to_token_stream())
+            .set_trait_generics(quote_spanned! {span=> 'de})
+            .set_for_ident(shadow_ident.clone())
+            .add_method(migrate_method);
+
+        ctx.impl_ctx().shadow_struct_mut()?.add_trait_impl(migrate_impl);
+
+        Ok(())
+    }
+
+    // Deserializes into a partial struct where every field is optional and writes the fields present in the input via
+    // the locks of the existing object. Outstanding references to the object remain valid.
+    fn serde_struct_update(&'a self) -> darling::Result<()> {
+        let ctx = self.ctx();
+        let arg_props = ctx.arg_props();
+        let update = arg_props.serde_update();
+
+        if !*update || !*arg_props.serde() {
+            return Ok(());
+        }
+
+        let span = update.final_span();
+
+        if !*ctx.syncish() {
+            return Err(darling::Error::custom("serde(update) requires sync or async mode").with_span(&span));
+        }
+
+        if !*arg_props.needs_deserialize() {
+            return Err(darling::Error::custom("serde(update) requires deserialization").with_span(&span));
+        }
+
+        let input_ident = ctx.input_ident();
+        let update_ident = format_ident!("__{}Update", input_ident, span = span);
+        let update_var = format_ident!("update", span = span);
+        let deserializer = format_ident!("__FXD", span = span);
+        let vis = arg_props
+            .serde_visibility()
+            .cloned()
+            .unwrap_or(syn::Visibility::Inherited);
+        let clear_lazy = *arg_props.serde_update_clear_lazy();
+        let generics = ctx.struct_generic_params();
+
+        let mut partial = self.partial_struct(&update_ident, &vis, &update_var, true, |fctx| {
+            if !*fctx.serde() || !*fctx.deserialize() {
+                None
+            }
+            else if *fctx.optional() || *fctx.lazy() {
+                Some(FXPartialField::Clearable {
+                    clear_missing: clear_lazy && *fctx.lazy(),
+                })
+            }
+            else {
+                Some(FXPartialField::Set)
+            }
+        })?;
+
+        let mut errors = darling::Error::accumulator();
+        for ident in partial.mut_fields.iter() {
+            errors.push(
+                darling::Error::custom(format!(
+                    "Field '{ident}' can't be updated via a shared reference; make it lock-protected or disable its \
+                     deserialization"
+                ))
+                .with_span(ident),
+            );
+        }
+        errors.finish()?;
+
+        partial
+            .struct_ctor
+            .add_attribute_toks(quote_spanned! {span=> #[doc(hidden)]})?;
+
+        let statements = &partial.statements;
+        let mut mc = FXFnConstructor::new(format_ident!("update_from_deserializer", span = span));
+        mc.set_span(span)
+            .set_vis(ctx.input().vis())
+            .set_async(partial.is_async)
+            .add_lifetime(quote_spanned! {span=> 'de})
+            .maybe_add_generic(Some(quote_spanned! {span=> #deserializer}))
+            .add_where_bound(quote_spanned! {span=> #deserializer: ::fieldx::serde::Deserializer<'de>})
+            .add_where_bound(quote_spanned! {span=> #update_ident #generics: ::fieldx::serde::Deserialize<'de>})
+            .add_param(quote_spanned! {span=> deserializer: #deserializer})
+            .set_ret_type(quote_spanned! {span=> ::std::result::Result<(), #deserializer::Error>})
+            .add_statement(quote_spanned! {span=>
+                let #update_var =
+                    <#update_ident #generics as ::fieldx::serde::Deserialize>::deserialize(deserializer)?;
+            })
+            .add_statement(quote_spanned! {span=> #( #statements )* })
+            .set_ret_stmt(quote_spanned! {span=> ::std::result::Result::Ok(())})
+            .add_attribute_toks(quote_spanned! {span=> #[allow(dead_code, clippy::useless_conversion)]})?
+            .add_doc(&FXProp::new(
+                vec![syn::LitStr::new(
+                    "Updates the object in place with the fields present in the deserializer input.",
+                    span,
+                )],
+                Some(span),
+            ))?;
+
+        ctx.add_method(mc);
+        ctx.impl_ctx().set_update_struct(partial.struct_ctor);
+
+        Ok(())
+    }
+
+    // The schema of the struct is the schema of what it is deserialized from: the shadow, or its versioned wrapper.
+    #[cfg(feature = "schemars")]
+    fn serde_struct_json_schema(&'a self) -> darling::Result<()> {
+        let ctx = self.ctx();
+        let arg_props = ctx.arg_props();
+
+        if !*arg_props.serde_schema() {
+            return Ok(());
+        }
+
+        let span = arg_props.serde().final_span();
+        let schema_type = self.serde_deserialize_type();
+        let json_schema = quote_spanned! {span=> ::fieldx::schemars::JsonSchema};
+        let cow_str = quote_spanned! {span=> ::std::borrow::Cow<'static, str>};
+        let generator = format_ident!("generator", span = span);
+
+        let mut impl_generics = ctx.input().generics().clone();
+        impl_generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse2(quote_spanned! {span=> #schema_type: #json_schema})?);
+
+        let mut inline_schema = FXFnConstructor::new_associated(format_ident!("inline_schema", span = span));
+        inline_schema
+            .set_span(span)
+            .set_ret_type(quote_spanned! {span=> bool})
+            .set_ret_stmt(quote_spanned! {span=> <#schema_type as #json_schema>::inline_schema()});
+
+        let mut schema_name = FXFnConstructor::new_associated(format_ident!("schema_name", span = span));
+        schema_name
+            .set_span(span)
+            .set_ret_type(cow_str.clone())
+            .set_ret_stmt(quote_spanned! {span=> <#schema_type as #json_schema>::schema_name()});
+
+        let mut schema_id = FXFnConstructor::new_associated(format_ident!("schema_id", span = span));
+        schema_id
+            .set_span(span)
+            .set_ret_type(cow_str)
+            .set_ret_stmt(quote_spanned! {span=> <#schema_type as #json_schema>::schema_id()});
+
+        let mut json_schema_method = FXFnConstructor::new_associated(format_ident!("json_schema", span = span));
+        json_schema_method
+            .set_span(span)
+            .add_param(quote_spanned! {span=> #generator: &mut ::fieldx::schemars::SchemaGenerator})
+            .set_ret_type(quote_spanned! {span=> ::fieldx::schemars::Schema})
+            .set_ret_stmt(quote_spanned! {span=> <#schema_type as #json_schema>::json_schema(#generator)});
+
+        let mut schema_impl = FXImplConstructor::new(syn::parse2::<syn::Path>(json_schema.clone())?);
+        schema_impl
+            .set_span(span)
+            .set_for_ident(ctx.input_ident().clone())
+            .set_from_generics(Some(impl_generics))
+            .add_method(inline_schema)
+            .add_method(schema_name)
+            .add_method(schema_id)
+            .add_method(json_schema_method);
+
+        ctx.impl_ctx().shadow_struct_mut()?.add_trait_impl(schema_impl);
+
+        Ok(())
+    }
+
+    // The type the user struct is deserialized from.
+    fn serde_deserialize_type(&'a self) -> TokenStream {
+        let ctx = self.ctx();
+        let arg_props = ctx.arg_props();
+        let shadow_ident = arg_props.serde_shadow_ident().unwrap();
+        let generics = ctx.input().generics().split_for_impl().1;
+        if let Some(version) = arg_props.serde_version() {
+            quote_spanned! {version.final_span()=> ::fieldx::serde::FXVersioned<#shadow_ident #generics>}
+        }
+        else {
+            quote_spanned! {shadow_ident.span()=> #shadow_ident #generics}
+        }
+    }
+
+    // Reference to the value to be serialized, tagged with the layout version if the struct is versioned.
+    fn serde_versioned(&'a self, value: TokenStream) -> TokenStream {
+        if let Some(version) = self.ctx().arg_props().serde_version() {
+            let span = version.final_span();
+            let version = *version;
+            quote_spanned! {span=> &::fieldx::serde::FXVersioned::new(#version, #value)}
+        }
+        else {
+            value
+        }
+    }
+
     fn serde_prepare_struct(&'a self) -> darling::Result<()> {
         let ctx = self.ctx();
 
@@ -578,7 +1325,9 @@ impl<'a> FXRewriteSerde<'a> for super::FXRewriter<'a> {
                 }
 
                 ctx.user_struct_mut()
-                    .add_attribute_toks(crate::util::derive_toks(&self.serde_derive_traits()))?;
+                    .add_attribute_toks(crate::util::derive_toks(
+                        &self.serde_derive_traits(!self.serde_borrowed(), !*ctx.arg_props().rc()),
+                    ))?;
                 ctx.ok_or_record(self.serde_struct_attribute());
             }
         }
@@ -623,7 +1372,15 @@ impl<'a> FXRewriteSerde<'a> for super::FXRewriter<'a> {
                 // default_fn.set_ret_stmt(quote_spanned! {default_span=> #serde_default.into()});
                 default_fn.set_ret_stmt(quote_spanned! {default_span=> #serde_default});
                 ctx.add_method(default_fn);
-                let default_str = syn::LitStr::new(&format!("{}::{}", ctx.input_ident(), fn_ident), span);
+                let default_str = syn::LitStr::new(
+                    &format!(
+                        "{}{}::{}",
+                        ctx.input_ident(),
+                        generics.as_turbofish().to_token_stream(),
+                        fn_ident
+                    ),
+                    span,
+                );
 
                 // return
                 Some(quote_spanned![span=> default = #default_str])
@@ -644,13 +1401,27 @@ impl<'a> FXRewriteSerde<'a> for super::FXRewriter<'a> {
             ctx.ok_or_record(self.serde_shadow_default_impl());
             ctx.ok_or_record(self.serde_struct_from_shadow());
             ctx.ok_or_record(self.serde_struct_into_shadow());
+            ctx.ok_or_record(self.serde_struct_serialize());
+            ctx.ok_or_record(self.serde_struct_serialize_async());
+            ctx.ok_or_record(self.serde_shadow_migrate());
+            ctx.ok_or_record(self.serde_struct_update());
+            #[cfg(feature = "schemars")]
+            ctx.ok_or_record(self.serde_struct_json_schema());
         }
     }
 
     fn serde_finalize(&'a self) -> darling::Result<TokenStream> {
         let ctx = self.ctx();
         Ok(if *ctx.arg_props().serde() {
-            ctx.impl_ctx().shadow_struct()?.to_token_stream()
+            let impl_ctx = ctx.impl_ctx();
+            let shadow_struct = impl_ctx.shadow_struct()?.to_token_stream();
+            let shadow_ref_struct = impl_ctx.take_shadow_ref_struct();
+            let update_struct = impl_ctx.take_update_struct();
+            quote! {
+                #shadow_struct
+                #shadow_ref_struct
+                #update_struct
+            }
         }
         else {
             quote![]
@@ -23,6 +23,14 @@ use super::derive_ctx::FXDeriveMacroCtx;
 use super::FXCodeGenContextual;
 use super::FXValueRepr;
 
+// Kinds of atomic operations the memory ordering is chosen for.
+#[derive(Clone, Copy)]
+enum FXAtomicOp {
+    Load,
+    // Read-modify-write operations, the setter included.
+    Update,
+}
+
 pub(crate) struct FXCodeGenSync<'a> {
     codegen: &'a crate::codegen::FXRewriter<'a>,
     ctx:     Rc<FXCodeGenCtx<FXDeriveMacroCtx>>,
@@ -35,7 +43,10 @@ impl<'a> FXCodeGenSync<'a> {
 
     fn read_method_name(&self, fctx: &FXDeriveFieldCtx, mutable: bool, span: Span) -> syn::Ident {
         let sfx = if mutable { "_mut" } else { "" };
-        if *fctx.fallible() {
+        if *fctx.lock_mutex() {
+            format_ident!("lock", span = span)
+        }
+        else if *fctx.fallible() {
             format_ident!("try_read{sfx}", span = span)
         }
         else {
@@ -43,6 +54,105 @@ impl<'a> FXCodeGenSync<'a> {
         }
     }
 
+    // Non-lazy lock-protected fields are written to via either `write()` or, for mutexes, `lock()`.
+    fn write_method_name(&self, fctx: &FXDeriveFieldCtx, span: Span) -> syn::Ident {
+        if *fctx.lock_mutex() {
+            format_ident!("lock", span = span)
+        }
+        else {
+            format_ident!("write", span = span)
+        }
+    }
+
+    // Wraps a guard of a lock group into a guard projected onto the group member.
+    fn lock_group_projection(
+        &self,
+        fctx: &FXDeriveFieldCtx,
+        guard: TokenStream,
+        write: bool,
+        span: Span,
+    ) -> TokenStream {
+        let ident = fctx.ident();
+        if write {
+            quote_spanned! {span=>
+                ::fieldx::lock_guards::FXGroupWriteGuard::new(#guard, |g| &g.#ident, |g| &mut g.#ident)
+            }
+        }
+        else {
+            quote_spanned! {span=> ::fieldx::lock_guards::FXGroupReadGuard::new(#guard, |g| &g.#ident)}
+        }
+    }
+
+    // Expression locking a non-lazy field for reading or writing. Members of a lock group are locked via the group
+    // lock.
+    fn lock_guard_expr(&self, fctx: &FXDeriveFieldCtx, write: bool, span: Span) -> TokenStream {
+        let await_call = fctx.impl_details().await_call(span);
+        if let Some(group) = fctx.lock_group() {
+            let group_field_ident = self.ctx().arg_props().lock_group_field_ident(group);
+            let method = if write {
+                format_ident!("write", span = span)
+            }
+            else {
+                format_ident!("read", span = span)
+            };
+            self.lock_group_projection(
+                fctx,
+                quote_spanned! {span=> self.#group_field_ident.#method()#await_call},
+                write,
+                span,
+            )
+        }
+        else {
+            let ident = fctx.ident();
+            let method = if write {
+                self.write_method_name(fctx, span)
+            }
+            else {
+                self.read_method_name(fctx, false, span)
+            };
+            quote_spanned! {span=> self.#ident.#method()#await_call}
+        }
+    }
+
+    // Type of the guard returned by `lock_guard_expr`.
+    fn lock_guard_type(
+        &self,
+        fctx: &FXDeriveFieldCtx,
+        write: bool,
+        lifetime: Option<&TokenStream>,
+        ty: &TokenStream,
+        span: Span,
+    ) -> darling::Result<TokenStream> {
+        let implementor = fctx.impl_details();
+        let lifetime = lifetime.map(|lt| quote_spanned! {span=> #lt,});
+        Ok(if let Some(group) = fctx.lock_group() {
+            let ctx = self.ctx();
+            let group_struct_ident = ctx.arg_props().lock_group_struct_ident(group);
+            let generic_params = ctx.struct_generic_params();
+            let group_type = quote_spanned! {span=> #group_struct_ident #generic_params};
+            if write {
+                let guard = implementor.rwlock_write_guard(span)?;
+                quote_spanned! {span=> ::fieldx::lock_guards::FXGroupWriteGuard<#guard<#lifetime #group_type>, #ty>}
+            }
+            else {
+                let guard = implementor.rwlock_read_guard(span)?;
+                quote_spanned! {span=> ::fieldx::lock_guards::FXGroupReadGuard<#guard<#lifetime #group_type>, #ty>}
+            }
+        }
+        else {
+            let guard = if *fctx.lock_mutex() {
+                implementor.mutex_guard(span)?
+            }
+            else if write {
+                implementor.rwlock_write_guard(span)?
+            }
+            else {
+                implementor.rwlock_read_guard(span)?
+            };
+            quote_spanned! {span=> #guard<#lifetime #ty>}
+        })
+    }
+
     fn field_reader_method(
         &self,
         fctx: &FXDeriveFieldCtx,
@@ -58,7 +168,6 @@ impl<'a> FXCodeGenSync<'a> {
         let ident = fctx.ident();
         let ty = fctx.ty();
         let implementor = fctx.impl_details();
-        let rwlock_guard = implementor.rwlock_read_guard(span)?;
         let await_call = implementor.await_call(span);
         let read_method = self.read_method_name(fctx, false, span);
         let mut lock_guard_params = Vec::new();
@@ -75,12 +184,22 @@ impl<'a> FXCodeGenSync<'a> {
             .add_attribute_toks(attributes_fn)?;
 
         let lazy = fctx.lazy();
-        let optional = fctx.optional();
 
         // Tokens for set_ret_type and set_ret_stmt are generated using the default span because the components of the
         // return type that relate to specific arguments (lazy, optional) are already bound to their respective spans.
         // However, the surrounding syntax belongs to the method itself.
-        if *lazy {
+        if *lazy && fctx.lock_group().is_some() {
+            let lazy_span = lazy.final_span();
+            let ret_stmt = self.single_lock_lazy_call(fctx, &mut mc, "lazy_read", None, lazy_span)?;
+
+            lock_guard_params.push(ty.to_token_stream());
+            mc.set_ret_type(fctx.fallible_return_type(
+                fctx,
+                quote_spanned! {span=> ::fieldx::sync::MappedRwLockReadGuard< #(#lock_guard_params),* > },
+            )?)
+            .set_ret_stmt(ret_stmt);
+        }
+        else if *lazy {
             self.maybe_ref_counted_self(fctx, &mut mc)?;
             let lazy_span = lazy.final_span();
             let mapped_guard = implementor.rwlock_mapped_read_guard(lazy_span)?;
@@ -96,28 +215,239 @@ impl<'a> FXCodeGenSync<'a> {
             )
             .set_ret_stmt(quote_spanned! {span=> self.#ident.#read_method(#self_rc)#await_call});
         }
-        else if *optional {
-            let opt_span = optional.final_span();
-            let ty = quote_spanned![opt_span=> ::std::option::Option<#ty>];
-            lock_guard_params.push(ty);
-            mc.set_ret_type(quote_spanned! {span=> #rwlock_guard< #(#lock_guard_params),* > })
-                .set_ret_stmt(quote_spanned! {span=> self.#ident.#read_method()#await_call});
+        else {
+            let ty = self.maybe_optional(fctx, ty);
+            mc.set_ret_type(self.lock_guard_type(fctx, false, lock_guard_params.first(), &ty, span)?)
+                .set_ret_stmt(self.lock_guard_expr(fctx, false, span));
+        }
+
+        Ok(mc)
+    }
+
+    // Builds `try_<helper>` or, with `timed`, `try_<helper>_for` method for lock-protected fields. For lazy fields
+    // the reader runs the builder if the field is not initialized yet and the lock is acquired in time.
+    fn field_try_lock_method(
+        &self,
+        fctx: &FXDeriveFieldCtx,
+        write: bool,
+        timed: bool,
+    ) -> darling::Result<FXFnConstructor> {
+        let (helper_ident, helper_vis, helper_kind, span) = if write {
+            (fctx.writer_ident(), fctx.writer_visibility(), FXHelperKind::Writer, fctx.writer().final_span())
         }
         else {
-            lock_guard_params.push(ty.to_token_stream());
-            mc.set_ret_type(quote_spanned! {span=> #rwlock_guard< #(#lock_guard_params),* > })
-                .set_ret_stmt(quote_spanned! {span=> self.#ident.#read_method()#await_call});
+            (fctx.reader_ident(), fctx.reader_visibility(), FXHelperKind::Reader, fctx.reader().final_span())
+        };
+        let method_ident = if timed {
+            format_ident!("try_{}_for", helper_ident, span = span)
+        }
+        else {
+            format_ident!("try_{}", helper_ident, span = span)
+        };
+        let mut mc = FXFnConstructor::new(method_ident);
+        let ident = fctx.ident();
+        let implementor = fctx.impl_details();
+        let lifetime = quote_spanned! {span=> 'fx_try_lock_lifetime};
+        let lock = if let Some(group) = fctx.lock_group() {
+            let group_field_ident = self.ctx().arg_props().lock_group_field_ident(group);
+            quote_spanned! {span=> self.#group_field_ident}
+        }
+        else {
+            quote_spanned! {span=> self.#ident}
+        };
+        let timeout = if timed {
+            let timeout = quote_spanned! {span=> timeout};
+            mc.add_param(quote_spanned! {span=> #timeout: ::std::time::Duration})
+                .set_async(fctx.mode_async());
+            Some(timeout)
+        }
+        else {
+            None
+        };
+
+        mc.set_vis(helper_vis)
+            .set_span(span)
+            .add_attribute_toks(fctx.helper_attributes_fn(helper_kind, FXInlining::Always, span))?
+            // These come implicitly with reader/writer, there is no reason to complain if they're not used.
+            .add_attribute_toks(quote_spanned! {span=> #[allow(dead_code)]})?
+            .set_self_lifetime(lifetime.clone());
+
+        let lazy = fctx.lazy();
+
+        if *lazy && !(write && fctx.lock_group().is_some()) {
+            let lazy_span = lazy.final_span();
+            let await_call = implementor.await_call(span);
+            let timeout = timeout.unwrap_or_else(|| quote_spanned! {span=> ::std::time::Duration::ZERO});
+            let ty = fctx.ty();
+
+            // Lazy initialization of an async field has to be awaited even if the lock is not waited for.
+            mc.set_async(fctx.mode_async());
+
+            if write {
+                let fx_wrlock_guard = implementor.fx_mapped_write_guard(lazy_span)?;
+                let builder_wrapper_type = self.builder_wrapper_type(fctx, false)?;
+
+                mc.set_ret_type(quote_spanned! {span=>
+                    ::std::option::Option<#fx_wrlock_guard<#lifetime, #builder_wrapper_type>>
+                })
+                .set_ret_stmt(quote_spanned! {span=> self.#ident.write_within(#timeout)#await_call});
+            }
+            else if fctx.lock_group().is_some() {
+                let ret_stmt =
+                    self.single_lock_lazy_call(fctx, &mut mc, "lazy_read_within", Some(&timeout), lazy_span)?;
+
+                mc.set_ret_type(fctx.fallible_return_type(
+                    fctx,
+                    quote_spanned! {span=>
+                        ::std::option::Option<::fieldx::sync::MappedRwLockReadGuard<#lifetime, #ty>>
+                    },
+                )?)
+                .set_ret_stmt(ret_stmt);
+            }
+            else {
+                self.maybe_ref_counted_self(fctx, &mut mc)?;
+                let mapped_guard = implementor.rwlock_mapped_read_guard(lazy_span)?;
+                let read_method = if *fctx.fallible() {
+                    format_ident!("try_read_within", span = span)
+                }
+                else {
+                    format_ident!("read_within", span = span)
+                };
+                let self_rc = mc.self_maybe_rc_as_ref().ok_or_else(|| {
+                    darling::Error::custom("Missing information about the `self` identifier, but reader method cannot be an associated function")
+                        .with_span(&span)
+                })?;
+
+                mc.set_ret_type(fctx.fallible_return_type(
+                    fctx,
+                    quote_spanned! {span=> ::std::option::Option<#mapped_guard<#lifetime, #ty>>},
+                )?)
+                .set_ret_stmt(quote_spanned! {span=> self.#ident.#read_method(#self_rc, #timeout)#await_call});
+            }
+        }
+        else if let Some(group) = fctx.lock_group().filter(|_| *lazy) {
+            // The writer of a lazy lock group member doesn't initialize the field.
+            let ctx = self.ctx();
+            let group_struct_ident = ctx.arg_props().lock_group_struct_ident(group);
+            let generic_params = ctx.struct_generic_params();
+            let guard = implementor.rwlock_write_guard(span)?;
+            let try_lock = implementor.rwlock_try_write(&lock, timeout.as_ref(), span)?;
+            let ty = fctx.ty();
+
+            mc.set_ret_type(quote_spanned! {span=>
+                ::std::option::Option<
+                    ::fieldx::lock_guards::FXGroupWriter<#guard<#lifetime, #group_struct_ident #generic_params>, #ty>
+                >
+            })
+            .set_ret_stmt(quote_spanned! {span=>
+                #try_lock.map(|g| ::fieldx::lock_guards::FXGroupWriter::new(g, |g| &mut g.#ident))
+            });
+        }
+        else {
+            let ty = self.maybe_optional(fctx, fctx.ty());
+            let mut try_lock = if write {
+                implementor.rwlock_try_write(&lock, timeout.as_ref(), span)?
+            }
+            else {
+                implementor.rwlock_try_read(&lock, timeout.as_ref(), span)?
+            };
+            let guard = self.lock_guard_type(fctx, write, Some(&lifetime), &ty, span)?;
+
+            if fctx.lock_group().is_some() {
+                let projection = self.lock_group_projection(fctx, quote_spanned! {span=> g}, write, span);
+                try_lock = quote_spanned! {span=> #try_lock.map(|g| #projection)};
+            }
+
+            mc.set_ret_type(quote_spanned! {span=> ::std::option::Option<#guard>})
+                .set_ret_stmt(try_lock);
         }
 
         Ok(mc)
     }
 
+    // Call of a `fieldx::sync::single_lock` function for a lazy field of a single-lock struct. The `try_` variant is
+    // used for fallible fields. The timeout is passed to the `_within` functions.
+    fn single_lock_lazy_call(
+        &self,
+        fctx: &FXDeriveFieldCtx,
+        mc: &mut FXFnConstructor,
+        func: &str,
+        timeout: Option<&TokenStream>,
+        span: Span,
+    ) -> darling::Result<TokenStream> {
+        let ident = fctx.ident();
+        let group = fctx.lock_group().ok_or_else(|| {
+            darling::Error::custom(format!("Internal problem: field '{ident}' is not a lock group member")).with_span(&span)
+        })?;
+        let group_field_ident = self.ctx().arg_props().lock_group_field_ident(group);
+        let func = if *fctx.fallible() {
+            format_ident!("try_{}", func, span = span)
+        }
+        else {
+            format_ident!("{}", func, span = span)
+        };
+        let self_ident = mc.self_ident();
+        self.maybe_ref_counted_self(fctx, mc)?;
+        let self_rc = mc.self_maybe_rc_as_ref().ok_or_else(|| {
+            darling::Error::custom("Missing information about the `self` identifier, but lazy field methods cannot be associated functions")
+                .with_span(&span)
+        })?;
+        let lazy_builder = fctx.impl_details().lazy_builder(fctx);
+        let timeout = timeout.map(|timeout| quote_spanned! {span=> , #timeout});
+        Ok(quote_spanned! {span=>
+            ::fieldx::sync::single_lock::#func(
+                &#self_ident.#group_field_ident,
+                |g| &g.#ident,
+                |g| &mut g.#ident,
+                || #lazy_builder(#self_rc)
+                #timeout
+            )
+        })
+    }
+
+    // Fields of a single-lock struct share the lock of the implicit group, which is incompatible with explicit groups,
+    // mutexes, and async mode.
+    fn single_lock_check(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<()> {
+        let single_lock = self.ctx().arg_props().single_lock();
+        if !*single_lock {
+            return Ok(());
+        }
+
+        let field_props = fctx.props().field_props();
+        if let Some(group) = field_props.lock_group() {
+            return Err(
+                darling::Error::custom("explicit lock groups cannot be used with single_lock").with_span(group),
+            );
+        }
+        if let Some(mutex) = field_props.lock_mutex() {
+            if *mutex {
+                return Err(darling::Error::custom("mutex-protected fields cannot be used with single_lock")
+                    .with_span(&mutex.final_span()));
+            }
+        }
+        if fctx.lock_group().is_some() && *fctx.mode_async() {
+            return Err(darling::Error::custom("single_lock requires lock-protected fields to be sync")
+                .with_span(&fctx.mode_async().final_span()));
+        }
+
+        Ok(())
+    }
+
+    fn lock_type(&self, fctx: &FXDeriveFieldCtx, span: Span) -> darling::Result<TokenStream> {
+        if *fctx.lock_mutex() {
+            fctx.impl_details().mutex(span)
+        }
+        else {
+            fctx.impl_details().rwlock(span)
+        }
+    }
+
     #[inline(always)]
     fn maybe_locked_ty<T: ToTokens>(&self, fctx: &FXDeriveFieldCtx, ty: &T) -> darling::Result<TokenStream> {
         let lock = fctx.lock();
         Ok(if *lock {
             let span = lock.final_span();
-            let rwlock = fctx.impl_details().rwlock(span)?;
+            let rwlock = self.lock_type(fctx, span)?;
             quote_spanned![span=> #rwlock<#ty>]
         }
         else {
@@ -125,6 +455,106 @@ impl<'a> FXCodeGenSync<'a> {
         })
     }
 
+    // Atomic and swap fields live outside of any lock or lazy container.
+    fn lock_free_check(&self, fctx: &FXDeriveFieldCtx, mode: &str) -> darling::Result<()> {
+        let conflict = fctx.lock().or(fctx.lazy()).or(fctx.optional());
+        if *conflict {
+            return Err(
+                darling::Error::custom(format!("{mode} fields cannot be lock-protected, lazy, or optional"))
+                    .with_span(&conflict.final_span()),
+            );
+        }
+        Ok(())
+    }
+
+    fn swap_type(&self, fctx: &FXDeriveFieldCtx) -> TokenStream {
+        let span = fctx.swap().final_span();
+        let ty = fctx.ty();
+        quote_spanned! {span=> ::fieldx::arc_swap::ArcSwap<#ty>}
+    }
+
+    // Returns the primitive type name of an atomic field, i.e. the last segment of its type path.
+    fn atomic_primitive(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<String> {
+        let ty = fctx.ty();
+        let primitive = match ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => type_path
+                .path
+                .segments
+                .last()
+                .filter(|s| s.arguments.is_none())
+                .map(|s| s.ident.to_string()),
+            _ => None,
+        };
+
+        match primitive {
+            Some(p) if matches!(
+                p.as_str(),
+                "bool" | "i8" | "i16" | "i32" | "i64" | "isize" | "u8" | "u16" | "u32" | "u64" | "usize"
+            ) =>
+            {
+                Ok(p)
+            }
+            _ => Err(darling::Error::custom(format!(
+                "type '{}' has no atomic counterpart; only bool, fixed-size integers, isize, and usize are supported",
+                ty.to_token_stream()
+            ))
+            .with_span(ty)),
+        }
+    }
+
+    fn atomic_type(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<TokenStream> {
+        let span = fctx.atomic().final_span();
+        let primitive = self.atomic_primitive(fctx)?;
+        // bool -> AtomicBool, u32 -> AtomicU32, isize -> AtomicIsize, etc.
+        let atomic_ident = format_ident!("Atomic{}{}", primitive[..1].to_uppercase(), &primitive[1..], span = span);
+        Ok(if cfg!(feature = "clonable-lock") {
+            quote_spanned! {span=> ::fieldx::atomic::FXAtomic<::std::sync::atomic::#atomic_ident>}
+        }
+        else {
+            quote_spanned! {span=> ::std::sync::atomic::#atomic_ident}
+        })
+    }
+
+    // Produce the memory ordering for an operation. Loads cannot use release semantics, so the configured ordering is
+    // weakened to the closest valid one for them.
+    fn atomic_ordering(&self, fctx: &FXDeriveFieldCtx, op: FXAtomicOp) -> darling::Result<TokenStream> {
+        let ordering = fctx.atomic_ordering();
+        let name = ordering.to_string();
+        let effective = match (name.as_str(), op) {
+            ("Relaxed" | "Acquire" | "Release" | "AcqRel" | "SeqCst", FXAtomicOp::Update) => name.as_str(),
+            ("Release", FXAtomicOp::Load) => "Relaxed",
+            ("AcqRel", FXAtomicOp::Load) => "Acquire",
+            ("Relaxed" | "Acquire" | "SeqCst", FXAtomicOp::Load) => name.as_str(),
+            _ => {
+                return Err(darling::Error::custom(format!(
+                    "unknown memory ordering '{name}', expected one of Relaxed, Acquire, Release, AcqRel, or SeqCst"
+                ))
+                .with_span(ordering))
+            }
+        };
+        let span = ordering.span();
+        let effective = format_ident!("{}", effective, span = span);
+        Ok(quote_spanned! {span=> ::std::sync::atomic::Ordering::#effective})
+    }
+
+    // Common part of fetch_add_, swap_, and compare_exchange_ helpers.
+    fn atomic_helper_method(
+        &self,
+        fctx: &FXDeriveFieldCtx,
+        helper_ident: &syn::Ident,
+        helper_vis: &syn::Visibility,
+        helper_kind: FXHelperKind,
+    ) -> darling::Result<FXFnConstructor> {
+        let span = fctx.atomic().final_span();
+        let mut mc = FXFnConstructor::new(helper_ident.clone());
+        mc.set_vis(helper_vis)
+            .set_span(span)
+            .add_attribute_toks(fctx.helper_attributes_fn(helper_kind, FXInlining::Always, span))?
+            // These come implicitly with atomic, there is no reason to complain if they're not used.
+            .add_attribute_toks(quote_spanned! {span=> #[allow(dead_code)]})?;
+        Ok(mc)
+    }
+
     // Compose declaration of the type to use for holding the builder method object.
     fn builder_wrapper_type(&self, fctx: &FXDeriveFieldCtx, turbo_fish: bool) -> darling::Result<TokenStream> {
         let ctx = self.ctx();
@@ -190,6 +620,26 @@ impl<'a> FXCodeGenSync<'a> {
         }
     }
 
+    // Initialize a lazy field before it is borrowed for serialization, unless uninitialized ones are to be skipped.
+    #[cfg(feature = "serde")]
+    fn serialize_lazy_init(&self, fctx: &FXDeriveFieldCtx, mc: &mut FXFnConstructor) -> darling::Result<()> {
+        if *fctx.lazy() && !*fctx.serde_skip_unset() {
+            let span = fctx.serialize().final_span();
+            let await_call = fctx.impl_details().await_call(span);
+            let lazy_init = self.field_lazy_initializer(fctx, mc)?;
+            // Lazy members of lock groups are initialized by a standalone expression.
+            let target = if fctx.lock_group().is_some() {
+                quote![]
+            }
+            else {
+                let field_ident = fctx.ident();
+                quote_spanned![span=> self.#field_ident]
+            };
+            mc.add_statement(quote_spanned![span=> let _ = #target #lazy_init #await_call;]);
+        }
+        Ok(())
+    }
+
     fn field_proxy_type(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<TokenStream> {
         Ok(if *fctx.lazy() {
             let implementor = fctx.impl_details();
@@ -221,12 +671,37 @@ impl<'a> FXCodeGenContextual for FXCodeGenSync<'a> {
                 return Ok(ty);
             }
 
+            self.single_lock_check(fctx)?;
+
+            if *fctx.atomic() {
+                self.lock_free_check(fctx, "atomic")?;
+                return self.atomic_type(fctx);
+            }
+
+            if *fctx.swap() {
+                self.lock_free_check(fctx, "swap")?;
+                return Ok(self.swap_type(fctx));
+            }
+
             let lazy = fctx.lazy();
-            if *lazy {
+            if *lazy && fctx.lock_group().is_some() {
+                // The group lock protects the value; it is not set until the field is initialized.
+                Ok(quote_spanned! [lazy.final_span()=> ::std::option::Option<#ty>])
+            }
+            else if *lazy {
+                let mutex = fctx.lock_mutex();
+                if *mutex {
+                    return Err(darling::Error::custom("lazy fields cannot be protected with a mutex")
+                        .with_span(&mutex.final_span()));
+                }
                 let proxy_type = self.field_proxy_type(fctx)?;
                 let span = fctx.ty().span();
                 Ok(quote_spanned! [span=> #proxy_type<#builder_wrapper_type>])
             }
+            else if fctx.lock_group().is_some() {
+                // The group lock protects the value.
+                Ok(self.maybe_optional(fctx, &ty))
+            }
             else {
                 self.maybe_locked_ty(fctx, &self.maybe_optional(fctx, &ty))
             }
@@ -260,6 +735,32 @@ impl<'a> FXCodeGenContextual for FXCodeGenSync<'a> {
                 dereference,
             } = self.accessor_elements(fctx);
 
+            let atomic = fctx.atomic();
+            if *atomic {
+                let mut mc = FXFnConstructor::new(fctx.accessor_ident().clone());
+                let ident = fctx.ident();
+                let ordering = self.atomic_ordering(fctx, FXAtomicOp::Load)?;
+                mc.set_span(span)
+                    .set_vis(fctx.accessor_visibility())
+                    .add_attribute_toks(fctx.helper_attributes_fn(FXHelperKind::Accessor, FXInlining::Always, span))?
+                    .set_ret_type(fctx.ty().to_token_stream())
+                    .set_ret_stmt(quote_spanned! {atomic.final_span()=> self.#ident.load(#ordering)});
+                return Ok(Some(mc));
+            }
+
+            let swap = fctx.swap();
+            if *swap {
+                let mut mc = FXFnConstructor::new(fctx.accessor_ident().clone());
+                let ident = fctx.ident();
+                let ty = fctx.ty();
+                mc.set_span(span)
+                    .set_vis(fctx.accessor_visibility())
+                    .add_attribute_toks(fctx.helper_attributes_fn(FXHelperKind::Accessor, FXInlining::Always, span))?
+                    .set_ret_type(quote_spanned! {swap.final_span()=> ::std::sync::Arc<#ty>})
+                    .set_ret_stmt(quote_spanned! {swap.final_span()=> self.#ident.load_full()});
+                return Ok(Some(mc));
+            }
+
             let mut mc = if *lock {
                 self.field_reader_method(
                     fctx,
@@ -282,7 +783,7 @@ impl<'a> FXCodeGenContextual for FXCodeGenSync<'a> {
             let shortcut = fctx.fallible_shortcut();
             let implementor = fctx.impl_details();
             let await_call = implementor.await_call(span);
-            let ty = fctx.ty();
+            let ty = self.accessor_type(fctx, span);
             let ty = self.maybe_optional(fctx, &quote_spanned! {span=> #type_ref #ty });
             let ty = fctx.fallible_return_type(fctx, &quote_spanned! {span => #reference #ty})?;
 
@@ -311,7 +812,85 @@ impl<'a> FXCodeGenContextual for FXCodeGenSync<'a> {
                 if is_clone || is_copy {
                     mc.set_ret_type(ty.clone());
                     let ret_stmt = mc.ret_stmt();
-                    mc.set_ret_stmt(quote_spanned! {ret_stmt.span()=> #dereference #ret_stmt #method });
+                    if *fctx.fallible() {
+                        // The guard comes wrapped into a Result.
+                        mc.set_ret_stmt(fctx.fallible_ok_return(&quote_spanned! {ret_stmt.span()=>
+                            #dereference (#ret_stmt #shortcut) #method
+                        }));
+                    }
+                    else {
+                        mc.set_ret_stmt(quote_spanned! {ret_stmt.span()=> #dereference #ret_stmt #method });
+                    }
+                }
+                else if accessor_mode.is_deref() {
+                    let lock_span = lock.final_span();
+
+                    if *fctx.lock_mutex() {
+                        return Err(darling::Error::custom(
+                            "accessor mode 'deref' cannot be used with mutex-protected fields",
+                        )
+                        .with_span(&accessor_mode.final_span()));
+                    }
+
+                    if *fctx.optional() && !*lazy {
+                        return Err(darling::Error::custom(
+                            "accessor mode 'deref' cannot be used with optional lock-protected fields",
+                        )
+                        .with_span(&accessor_mode.final_span()));
+                    }
+
+                    let lifetime = mc.self_lifetime().clone().ok_or_else(|| {
+                        darling::Error::custom("Missing lifetime of the reader method for 'deref' accessor mode")
+                            .with_span(&span)
+                    })?;
+                    let field_ty = fctx.ty();
+                    let guard_var = quote_spanned! {lock_span=> __fx_guard};
+                    let (deref_guard, deref_map) = if *lazy && fctx.lock_group().is_some() {
+                        let target = self.accessor_type(fctx, span);
+                        (
+                            quote_spanned! {lock_span=> ::fieldx::sync::MappedRwLockReadGuard<#lifetime, #target>},
+                            quote_spanned! {lock_span=>
+                                ::fieldx::sync::MappedRwLockReadGuard::map(#guard_var, |v| ::std::ops::Deref::deref(v))
+                            },
+                        )
+                    }
+                    else if fctx.lock_group().is_some() {
+                        // A projected group guard cannot be mapped by the lock implementation.
+                        let group_guard = self.lock_guard_type(
+                            fctx,
+                            false,
+                            Some(&lifetime),
+                            &field_ty.to_token_stream(),
+                            lock_span,
+                        )?;
+                        (
+                            quote_spanned! {lock_span=> ::fieldx::lock_guards::FXDerefReadGuard<#group_guard>},
+                            quote_spanned! {lock_span=> ::fieldx::lock_guards::FXDerefReadGuard::new(#guard_var)},
+                        )
+                    }
+                    else {
+                        let read_guard = if *lazy {
+                            implementor.rwlock_mapped_read_guard(lock_span)?
+                        }
+                        else {
+                            implementor.rwlock_read_guard(lock_span)?
+                        };
+                        let target = self.accessor_type(fctx, span);
+                        (
+                            implementor.rwlock_deref_read_guard(
+                                &quote_spanned! {lock_span=> #read_guard<#lifetime, #field_ty>},
+                                &lifetime,
+                                &target,
+                                lock_span,
+                            )?,
+                            implementor.rwlock_deref_read_map(&guard_var, *lazy, lock_span)?,
+                        )
+                    };
+                    let ret_stmt = mc.ret_stmt();
+
+                    mc.add_statement(quote_spanned! {lock_span=> let #guard_var = #ret_stmt #shortcut; })
+                        .set_ret_type(fctx.fallible_return_type(fctx, deref_guard)?)
+                        .set_ret_stmt(fctx.fallible_ok_return(&deref_map));
                 }
             }
             else {
@@ -343,14 +922,41 @@ impl<'a> FXCodeGenContextual for FXCodeGenSync<'a> {
             let lazy = fctx.lazy();
             let implementor = fctx.impl_details();
             let await_call = implementor.await_call(span);
+            let atomic = fctx.atomic();
+            let swap = fctx.swap();
+
+            if *swap {
+                return Err(darling::Error::custom(format!(
+                    "swap fields cannot have mutable accessors, use {}() instead",
+                    fctx.update_ident()
+                ))
+                .with_span(&span));
+            }
 
-            if *lazy {
+            if *atomic {
+                // Exclusive access to the struct doesn't need atomic operations.
+                mc.set_self_mut(true)
+                    .set_ret_type(quote_spanned! {span=> &mut #ty})
+                    .set_ret_stmt(quote_spanned! {atomic.final_span()=> self.#ident.get_mut()});
+            }
+            else if *lazy {
                 self.maybe_ref_counted_self(fctx, &mut mc)?;
                 let lazy_span = lazy.final_span();
 
                 mc.set_async(fctx.mode_async());
 
-                if *lock {
+                if *lock && fctx.lock_group().is_some() {
+                    let lifetime = quote_spanned! {lazy_span=> 'fx_get_mut};
+
+                    mc.set_self_lifetime(lifetime.clone());
+                    mc.set_ret_type(fctx.fallible_return_type(
+                        fctx,
+                        quote_spanned! {span=> ::fieldx::sync::MappedRwLockWriteGuard<#lifetime, #ty> },
+                    )?);
+                    let ret_stmt = self.single_lock_lazy_call(fctx, &mut mc, "lazy_write", None, lazy_span)?;
+                    mc.set_ret_stmt(ret_stmt);
+                }
+                else if *lock {
                     let self_rc = mc.self_maybe_rc_as_ref()
                     .ok_or(
                         darling::Error::custom("Missing information about the `self` identifier, but mutable accessor method cannot be an associated function")
@@ -392,13 +998,12 @@ impl<'a> FXCodeGenContextual for FXCodeGenSync<'a> {
 
                 if *lock {
                     let lock_span = lock.final_span();
-                    let wrguard = implementor.rwlock_write_guard(lock_span)?;
                     let lifetime = quote_spanned! {lock_span=> 'fx_mut_lifetime};
 
                     mc.set_async(fctx.mode_async())
                         .set_self_lifetime(lifetime.clone())
-                        .set_ret_type(quote_spanned! [lock_span=> #wrguard<#lifetime, #ty_toks>])
-                        .set_ret_stmt(quote_spanned! [lock_span=> self.#ident.write() #await_call]);
+                        .set_ret_type(self.lock_guard_type(fctx, true, Some(&lifetime), &ty_toks, lock_span)?)
+                        .set_ret_stmt(self.lock_guard_expr(fctx, true, lock_span));
                 }
                 else {
                     // Bare field
@@ -453,6 +1058,9 @@ impl<'a> FXCodeGenContextual for FXCodeGenSync<'a> {
                 #field_ident: #field_default
             ]
         }
+        else if *lazy && fctx.lock_group().is_some() {
+            quote_spanned! {span=> #field_ident: self.#field_ident.take()#or_default}
+        }
         else if *lazy {
             let lazy_builder = self.wrap_builder(fctx, lazy_builder)?;
             let init = self.new_lazy_field_container(
@@ -487,7 +1095,24 @@ impl<'a> FXCodeGenContextual for FXCodeGenSync<'a> {
         fctx: &FXDeriveFieldCtx,
         mc: &mut FXFnConstructor,
     ) -> darling::Result<TokenStream> {
-        if *fctx.lock() {
+        if let (true, Some(group)) = (*fctx.lock(), fctx.lock_group()) {
+            // Members of a lock group are initialized by a standalone expression.
+            let self_var = mc.self_maybe_rc();
+            let span = fctx.lazy().final_span();
+            let ident = fctx.ident();
+            let group_field_ident = self.ctx().arg_props().lock_group_field_ident(group);
+            let lazy_builder = fctx.impl_details().lazy_builder(fctx);
+            let init = if *fctx.fallible() {
+                quote_spanned! {span=> #lazy_builder(&#self_var)}
+            }
+            else {
+                quote_spanned! {span=> ::std::result::Result::<_, ()>::Ok(#lazy_builder(&#self_var))}
+            };
+            Ok(quote_spanned! {span=>
+                ::fieldx::sync::single_lock::lazy_init(&#self_var.#group_field_ident, |g| &g.#ident, |g| &mut g.#ident, || #init)
+            })
+        }
+        else if *fctx.lock() {
             let self_var = mc.self_maybe_rc();
             let span = fctx.lazy().final_span();
             Ok(quote_spanned! {span=> .lazy_init(&#self_var)})
@@ -514,17 +1139,119 @@ impl<'a> FXCodeGenContextual for FXCodeGenSync<'a> {
         let impl_ctx = self.ctx().impl_ctx();
         let me_var = impl_ctx.me_var_ident()?;
         let mut field_access = quote_spanned! {field_ident.span()=> #me_var.#field_ident };
-        let into_inner = fctx.lock().or(fctx.lazy());
+        let swap = fctx.swap();
+        if *swap {
+            // The value may still be shared with outstanding snapshots.
+            let span = swap.final_span();
+            return Ok(quote_spanned! {span=> ::std::sync::Arc::unwrap_or_clone(#field_access.into_inner()) }.into());
+        }
+        if let Some(group) = fctx.lock_group() {
+            // The group lock is unwrapped into a local variable of the same name.
+            let group_var = self.ctx().arg_props().lock_group_field_ident(group);
+            return Ok(quote_spanned! {field_ident.span()=> #group_var.#field_ident }.into());
+        }
+        let into_inner = fctx.lock().or(fctx.lazy()).or(fctx.atomic());
         if *into_inner {
             field_access = quote_spanned! {into_inner.final_span()=> #field_access.into_inner() };
         }
         Ok(field_access.into())
     }
 
+    #[cfg(feature = "serde")]
+    fn field_serialize_ref(
+        &self,
+        fctx: &FXDeriveFieldCtx,
+        mc: &mut FXFnConstructor,
+        lifetime: &TokenStream,
+    ) -> darling::Result<(TokenStream, TokenStream)> {
+        let field_ident = fctx.ident();
+        let ty = fctx.ty();
+        let span = fctx.serialize().final_span();
+        let ser_ref = quote_spanned![span=> ::fieldx::serde::FXSerializeRef];
+        let ref_ty = quote_spanned![span=> #ser_ref<#lifetime, #ty>];
+        let opt_ref_ty = quote_spanned![span=> ::std::option::Option<#ref_ty>];
+        let lazy = fctx.lazy();
+        let await_call = fctx.impl_details().await_call(span);
+
+        self.serialize_lazy_init(fctx, mc)?;
+
+        if *fctx.atomic() {
+            let ordering = self.atomic_ordering(fctx, FXAtomicOp::Load)?;
+            return Ok((ref_ty, quote_spanned![span=> #ser_ref::Owned(self.#field_ident.load(#ordering))]));
+        }
+
+        if *fctx.swap() {
+            return Ok((ref_ty, quote_spanned![span=> #ser_ref::guard(self.#field_ident.load_full())]));
+        }
+
+        if let Some(group) = fctx.lock_group() {
+            let group_var = self.ctx().arg_props().lock_group_field_ident(group);
+            return Ok(if *fctx.serde_optional() {
+                (opt_ref_ty, quote_spanned![span=> #ser_ref::option_ref(&#group_var.#field_ident)])
+            }
+            else {
+                (ref_ty, quote_spanned![span=> #ser_ref::Ref(&#group_var.#field_ident)])
+            });
+        }
+
+        let lock = fctx.lock();
+        Ok(if *lazy && *lock {
+            (opt_ref_ty, quote_spanned![span=> #ser_ref::option_guard(self.#field_ident.read_raw()#await_call)])
+        }
+        else if *lazy {
+            (opt_ref_ty, quote_spanned![span=> self.#field_ident.get().map(#ser_ref::Ref)])
+        }
+        else if *lock {
+            let guard = self.lock_guard_expr(fctx, false, span);
+            if *fctx.optional() {
+                (opt_ref_ty, quote_spanned![span=> #ser_ref::option_guard(#guard)])
+            }
+            else {
+                (ref_ty, quote_spanned![span=> #ser_ref::guard(#guard)])
+            }
+        }
+        else if *fctx.optional() {
+            (opt_ref_ty, quote_spanned![span=> #ser_ref::option_ref(&self.#field_ident)])
+        }
+        else {
+            (ref_ty, quote_spanned![span=> #ser_ref::Ref(&self.#field_ident)])
+        })
+    }
+
+    #[cfg(feature = "serde")]
+    fn field_serialize_guard(
+        &self,
+        fctx: &FXDeriveFieldCtx,
+        mc: &mut FXFnConstructor,
+    ) -> darling::Result<Option<TokenStream>> {
+        if *fctx.atomic() || *fctx.swap() || !*fctx.lock() || fctx.lock_group().is_some() {
+            return Ok(None);
+        }
+
+        let span = fctx.serialize().final_span();
+        self.serialize_lazy_init(fctx, mc)?;
+
+        Ok(Some(if *fctx.lazy() {
+            let field_ident = fctx.ident();
+            let await_call = fctx.impl_details().await_call(span);
+            quote_spanned![span=> self.#field_ident.read_raw()#await_call]
+        }
+        else {
+            self.lock_guard_expr(fctx, false, span)
+        }))
+    }
+
     fn field_reader(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>> {
         let reader = fctx.reader();
         if *reader {
             let span = reader.final_span();
+            if *fctx.lock_mutex() {
+                return Err(darling::Error::custom(format!(
+                    "reader is not supported for mutex-protected fields, use {}() instead",
+                    fctx.lock_ident()
+                ))
+                .with_span(&span));
+            }
             self.field_reader_method(
                 fctx,
                 fctx.reader_ident(),
@@ -544,6 +1271,13 @@ impl<'a> FXCodeGenContextual for FXCodeGenSync<'a> {
         let writer = fctx.writer();
         Ok(if *writer {
             let span = writer.final_span();
+            if *fctx.lock_mutex() {
+                return Err(darling::Error::custom(format!(
+                    "writer is not supported for mutex-protected fields, use {}() instead",
+                    fctx.lock_ident()
+                ))
+                .with_span(&span));
+            }
             let mut mc = FXFnConstructor::new(fctx.writer_ident().clone());
             let ident = fctx.ident();
             let mut ret_ty = fctx.ty().to_token_stream();
@@ -558,7 +1292,21 @@ impl<'a> FXCodeGenContextual for FXCodeGenSync<'a> {
 
             let lazy = fctx.lazy();
 
-            if *lazy {
+            if let Some(group) = fctx.lock_group().filter(|_| *lazy) {
+                let ctx = self.ctx();
+                let group_field_ident = ctx.arg_props().lock_group_field_ident(group);
+                let group_struct_ident = ctx.arg_props().lock_group_struct_ident(group);
+                let generic_params = ctx.struct_generic_params();
+                let guard = implementor.rwlock_write_guard(span)?;
+
+                mc.set_ret_type(quote_spanned! {span=>
+                    ::fieldx::lock_guards::FXGroupWriter<#guard<#lifetime, #group_struct_ident #generic_params>, #ret_ty>
+                });
+                mc.set_ret_stmt(quote_spanned! {span=>
+                    ::fieldx::lock_guards::FXGroupWriter::new(self.#group_field_ident.write(), |g| &mut g.#ident)
+                });
+            }
+            else if *lazy {
                 let lazy_span = lazy.final_span();
                 let fx_wrlock_guard = implementor.fx_mapped_write_guard(lazy_span)?;
                 let builder_wrapper_type = self.builder_wrapper_type(fctx, false)?;
@@ -567,14 +1315,14 @@ impl<'a> FXCodeGenContextual for FXCodeGenSync<'a> {
                 mc.set_ret_stmt(quote_spanned! {span=> self.#ident.write()#await_call});
             }
             else {
-                let wrlock_guard = implementor.rwlock_write_guard(span)?;
-
                 ret_ty = self.maybe_optional(fctx, ret_ty);
 
-                mc.set_ret_type(quote_spanned! {span=> #wrlock_guard<#lifetime, #ret_ty>});
-                mc.set_ret_stmt(quote_spanned! {span=> self.#ident.write()#await_call});
+                mc.set_ret_type(self.lock_guard_type(fctx, true, Some(&lifetime), &ret_ty, span)?);
+                mc.set_ret_stmt(self.lock_guard_expr(fctx, true, span));
             }
 
+            self.maybe_track_change(fctx, &mut mc, false)?;
+
             Some(mc)
         }
         else {
@@ -582,88 +1330,253 @@ impl<'a> FXCodeGenContextual for FXCodeGenSync<'a> {
         })
     }
 
+    fn field_lock(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>> {
+        let mutex = fctx.lock_mutex();
+        Ok(if *mutex {
+            let span = mutex.final_span();
+            let mut mc = FXFnConstructor::new(fctx.lock_ident().clone());
+            let ident = fctx.ident();
+            let implementor = fctx.impl_details();
+            let mutex_guard = implementor.mutex_guard(span)?;
+            let await_call = implementor.await_call(span);
+            let lifetime = quote_spanned! {span=> 'fx_lock_lifetime};
+            let ty = self.maybe_optional(fctx, fctx.ty());
+
+            mc.set_span(span)
+                .set_vis(fctx.lock_visibility())
+                .add_attribute_toks(fctx.helper_attributes_fn(FXHelperKind::Lock, FXInlining::Always, span))?
+                .set_async(fctx.mode_async())
+                .set_self_lifetime(lifetime.clone())
+                .set_ret_type(quote_spanned! {span=> #mutex_guard<#lifetime, #ty>})
+                .set_ret_stmt(quote_spanned! {span=> self.#ident.lock()#await_call});
+
+            Some(mc)
+        }
+        else {
+            None
+        })
+    }
+
+    fn field_try_reader(&self, fctx: &FXDeriveFieldCtx, timed: bool) -> darling::Result<Option<FXFnConstructor>> {
+        let reader = fctx.reader();
+        // Mutex-protected fields are reported by the reader method itself.
+        if *reader && !*fctx.lock_mutex() {
+            self.field_try_lock_method(fctx, false, timed).map(Some)
+        }
+        else {
+            Ok(None)
+        }
+    }
+
+    fn field_try_writer(&self, fctx: &FXDeriveFieldCtx, timed: bool) -> darling::Result<Option<FXFnConstructor>> {
+        let writer = fctx.writer();
+        if *writer && !*fctx.lock_mutex() {
+            self.field_try_lock_method(fctx, true, timed).map(Some)
+        }
+        else {
+            Ok(None)
+        }
+    }
+
+    fn field_fetch_add(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>> {
+        // There is no fetch_add for AtomicBool.
+        if !*fctx.atomic() || self.atomic_primitive(fctx)? == "bool" {
+            return Ok(None);
+        }
+
+        let span = fctx.atomic().final_span();
+        let ident = fctx.ident();
+        let ty = fctx.ty();
+        let ordering = self.atomic_ordering(fctx, FXAtomicOp::Update)?;
+        let mut mc =
+            self.atomic_helper_method(fctx, fctx.fetch_add_ident(), fctx.fetch_add_visibility(), FXHelperKind::FetchAdd)?;
+
+        mc.add_param(quote_spanned! {span=> value: #ty})
+            .set_ret_type(ty.to_token_stream())
+            .set_ret_stmt(quote_spanned! {span=> self.#ident.fetch_add(value, #ordering)});
+        self.maybe_track_change(fctx, &mut mc, false)?;
+
+        Ok(Some(mc))
+    }
+
+    fn field_swap(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>> {
+        if !*fctx.atomic() {
+            return Ok(None);
+        }
+
+        let span = fctx.atomic().final_span();
+        let ident = fctx.ident();
+        let ty = fctx.ty();
+        let ordering = self.atomic_ordering(fctx, FXAtomicOp::Update)?;
+        let mut mc = self.atomic_helper_method(fctx, fctx.swap_ident(), fctx.swap_visibility(), FXHelperKind::Swap)?;
+
+        mc.add_param(quote_spanned! {span=> value: #ty})
+            .set_ret_type(ty.to_token_stream())
+            .set_ret_stmt(quote_spanned! {span=> self.#ident.swap(value, #ordering)});
+        self.maybe_track_change(fctx, &mut mc, false)?;
+
+        Ok(Some(mc))
+    }
+
+    fn field_compare_exchange(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>> {
+        if !*fctx.atomic() {
+            return Ok(None);
+        }
+
+        let span = fctx.atomic().final_span();
+        let ident = fctx.ident();
+        let ty = fctx.ty();
+        let success = self.atomic_ordering(fctx, FXAtomicOp::Update)?;
+        // Failure ordering applies to a load and thus cannot have release semantics.
+        let failure = self.atomic_ordering(fctx, FXAtomicOp::Load)?;
+        let mut mc = self.atomic_helper_method(
+            fctx,
+            fctx.compare_exchange_ident(),
+            fctx.compare_exchange_visibility(),
+            FXHelperKind::CompareExchange,
+        )?;
+
+        mc.add_param(quote_spanned! {span=> current: #ty})
+            .add_param(quote_spanned! {span=> new: #ty})
+            .set_ret_type(quote_spanned! {span=> ::std::result::Result<#ty, #ty>})
+            .set_ret_stmt(quote_spanned! {span=> self.#ident.compare_exchange(current, new, #success, #failure)});
+        self.maybe_track_change(fctx, &mut mc, true)?;
+
+        Ok(Some(mc))
+    }
+
+    fn field_update(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>> {
+        let swap = fctx.swap();
+        if !*swap {
+            return Ok(None);
+        }
+
+        let span = swap.final_span();
+        let ident = fctx.ident();
+        let ty = fctx.ty();
+        let mut mc = FXFnConstructor::new(fctx.update_ident().clone());
+
+        // `rcu` re-runs the closure if another thread replaces the value in between.
+        mc.set_vis(fctx.update_visibility())
+            .set_span(span)
+            .add_attribute_toks(fctx.helper_attributes_fn(FXHelperKind::Update, FXInlining::Always, span))?
+            .add_attribute_toks(quote_spanned! {span=> #[allow(dead_code)]})?
+            .add_param(quote_spanned! {span=> mut update: impl FnMut(&#ty) -> #ty})
+            .set_ret_type(quote_spanned! {span=> ::std::sync::Arc<#ty>})
+            .set_ret_stmt(quote_spanned! {span=> self.#ident.rcu(|old| ::std::sync::Arc::new(update(old)))});
+        self.maybe_track_change(fctx, &mut mc, false)?;
+
+        Ok(Some(mc))
+    }
+
     fn field_setter(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>> {
         let setter = fctx.setter();
         Ok(if *setter {
             let span = setter.final_span();
-            let implementor = fctx.impl_details();
             let mut mc = FXFnConstructor::new(fctx.setter_ident().clone());
-            let ident = fctx.ident();
-            let ty = fctx.ty();
-            let (val_type, gen_params, into_tok) = self.to_toks(fctx, fctx.setter_into());
-            let await_call = implementor.await_call(span);
-            let value_toks = quote_spanned! {span=> value #into_tok};
-            let lazy = fctx.lazy();
-            let optional = fctx.optional();
-            let lock = fctx.lock();
 
             mc.set_span(span)
                 .set_vis(fctx.setter_visibility())
-                .add_attribute_toks(fctx.helper_attributes_fn(FXHelperKind::Setter, FXInlining::Always, span))?
-                .maybe_add_generic(gen_params)
-                .add_param(quote_spanned! {span=> value: #val_type});
+                .add_attribute_toks(fctx.helper_attributes_fn(FXHelperKind::Setter, FXInlining::Always, span))?;
 
-            if *lazy || *optional || *lock {
-                mc.set_async(fctx.mode_async());
-            }
+            self.field_setter_body(fctx, &mut mc, span)?;
+            self.maybe_track_change(fctx, &mut mc, false)?;
+            self.maybe_chain_setter(fctx, &mut mc);
+            self.maybe_try_into_result(fctx, &mut mc, fctx.setter_try_into());
 
-            if *lazy {
-                let lazy_span = lazy.final_span();
-                if *lock {
-                    mc.set_ret_type(quote_spanned! {lazy_span=> ::std::option::Option<#ty>});
-                    mc.set_ret_stmt(quote_spanned! {lazy_span=> self.#ident.write()#await_call.store(#value_toks)});
-                }
-                else {
-                    // async-lock .set() returns a future.
-                    let set_await = if cfg!(feature = "async-lock") {
-                        await_call
-                    }
-                    else {
-                        quote![]
-                    };
-                    mc.set_self_mut(true);
-                    mc.set_ret_type(quote_spanned! {lazy_span=> ::std::option::Option<#ty>});
-                    mc.add_statement(quote_spanned! {span=>
-                        let old = self.#ident.take();
-                        let _ = self.#ident.set(#value_toks)#set_await;
-                    });
-                    mc.set_ret_stmt(quote_spanned! {span=> old});
-                }
-            }
-            else if *optional {
-                let opt_span = optional.final_span();
-                let (lock_method, opt_await_call) = if *lock {
-                    let lock_span = lock.final_span();
-                    (quote_spanned! {lock_span=> .write()}, await_call)
-                }
-                else {
-                    mc.set_self_mut(true);
+            Some(mc)
+        }
+        else {
+            None
+        })
+    }
 
-                    (quote![], quote![])
-                };
+    fn field_setter_body(&self, fctx: &FXDeriveFieldCtx, mc: &mut FXFnConstructor, span: Span) -> darling::Result<()> {
+        let implementor = fctx.impl_details();
+        let ident = fctx.ident();
+        let ty = fctx.ty();
+        let (val_type, gen_params, into_tok) = self.to_toks(fctx, fctx.setter_into(), fctx.setter_try_into());
+        let await_call = implementor.await_call(span);
+        let value_toks = quote_spanned! {span=> value #into_tok};
+        let lazy = fctx.lazy();
+        let optional = fctx.optional();
+        let lock = fctx.lock();
+
+        mc.maybe_add_generic(gen_params)
+            .add_param(quote_spanned! {span=> value: #val_type});
 
-                mc.set_ret_type(quote_spanned! {opt_span=> ::std::option::Option<#ty>});
-                mc.set_ret_stmt(
-                    quote_spanned! {opt_span=> self.#ident #lock_method #opt_await_call.replace(#value_toks)},
-                );
+        if *lazy || *optional || *lock {
+            mc.set_async(fctx.mode_async());
+        }
+
+        let atomic = fctx.atomic();
+        let swap = fctx.swap();
+        if *atomic {
+            let ordering = self.atomic_ordering(fctx, FXAtomicOp::Update)?;
+            mc.set_ret_type(ty.to_token_stream());
+            mc.set_ret_stmt(quote_spanned! {atomic.final_span()=> self.#ident.swap(#value_toks, #ordering)});
+        }
+        else if *swap {
+            let swap_span = swap.final_span();
+            mc.set_ret_type(quote_spanned! {swap_span=> ::std::sync::Arc<#ty>});
+            mc.set_ret_stmt(quote_spanned! {swap_span=> self.#ident.swap(::std::sync::Arc::new(#value_toks))});
+        }
+        else if *lazy {
+            let lazy_span = lazy.final_span();
+            if *lock && fctx.lock_group().is_some() {
+                let wlock = self.lock_guard_expr(fctx, true, lazy_span);
+                mc.set_ret_type(quote_spanned! {lazy_span=> ::std::option::Option<#ty>});
+                mc.set_ret_stmt(quote_spanned! {lazy_span=> #wlock.replace(#value_toks)});
             }
             else if *lock {
-                let lock_span = lock.final_span();
-                mc.set_ret_type(ty.to_token_stream());
-                mc.add_statement(quote_spanned! {lock_span=> let mut wlock = self.#ident.write()#await_call; });
-                mc.set_ret_stmt(quote_spanned! {lock_span=> ::std::mem::replace(&mut *wlock, #value_toks)});
+                mc.set_ret_type(quote_spanned! {lazy_span=> ::std::option::Option<#ty>});
+                mc.set_ret_stmt(quote_spanned! {lazy_span=> self.#ident.write()#await_call.store(#value_toks)});
             }
             else {
-                mc.set_ret_type(ty.to_token_stream());
+                // async-lock .set() returns a future.
+                let set_await = if cfg!(feature = "async-lock") {
+                    await_call
+                }
+                else {
+                    quote![]
+                };
                 mc.set_self_mut(true);
-                mc.set_ret_stmt(quote_spanned! {span=> ::std::mem::replace(&mut self.#ident, #value_toks)});
+                mc.set_ret_type(quote_spanned! {lazy_span=> ::std::option::Option<#ty>});
+                mc.add_statement(quote_spanned! {span=>
+                    let old = self.#ident.take();
+                    let _ = self.#ident.set(#value_toks)#set_await;
+                });
+                mc.set_ret_stmt(quote_spanned! {span=> old});
+            }
+        }
+        else if *optional {
+            let opt_span = optional.final_span();
+            let target = if *lock {
+                self.lock_guard_expr(fctx, true, lock.final_span())
             }
+            else {
+                mc.set_self_mut(true);
 
-            Some(mc)
+                quote_spanned! {opt_span=> self.#ident}
+            };
+
+            mc.set_ret_type(quote_spanned! {opt_span=> ::std::option::Option<#ty>});
+            mc.set_ret_stmt(quote_spanned! {opt_span=> #target.replace(#value_toks)});
+        }
+        else if *lock {
+            let lock_span = lock.final_span();
+            let wlock = self.lock_guard_expr(fctx, true, lock_span);
+            mc.set_ret_type(ty.to_token_stream());
+            mc.add_statement(quote_spanned! {lock_span=> let mut wlock = #wlock; });
+            mc.set_ret_stmt(quote_spanned! {lock_span=> ::std::mem::replace(&mut *wlock, #value_toks)});
         }
         else {
-            None
-        })
+            mc.set_ret_type(ty.to_token_stream());
+            mc.set_self_mut(true);
+            mc.set_ret_stmt(quote_spanned! {span=> ::std::mem::replace(&mut self.#ident, #value_toks)});
+        }
+
+        Ok(())
     }
 
     fn field_clearer(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>> {
@@ -671,42 +1584,56 @@ impl<'a> FXCodeGenContextual for FXCodeGenSync<'a> {
         Ok(if *clearer {
             let span = clearer.final_span();
             let mut mc = FXFnConstructor::new(fctx.clearer_ident().clone());
-            let ident = fctx.ident();
-            let ty = fctx.ty();
-            let implementor = fctx.impl_details();
-            let await_call = implementor.await_call(span);
-            let lock = fctx.lock();
 
             mc.set_vis(fctx.clearer_visibility())
-                .add_attribute_toks(fctx.helper_attributes_fn(FXHelperKind::Clearer, FXInlining::Always, span))?
-                .set_async(fctx.mode_async())
-                .set_ret_type(quote_spanned! {span=> ::std::option::Option<#ty>});
+                .add_attribute_toks(fctx.helper_attributes_fn(FXHelperKind::Clearer, FXInlining::Always, span))?;
 
-            let lazy = fctx.lazy();
+            self.field_clearer_body(fctx, &mut mc, span)?;
+            self.maybe_track_change(fctx, &mut mc, false)?;
 
-            if *lazy {
-                if *lock {
-                    mc.set_ret_stmt(quote_spanned! {lazy.final_span()=> self.#ident.clear()#await_call});
-                }
-                else {
-                    mc.set_self_mut(true);
-                    mc.set_ret_stmt(quote_spanned! {lazy.final_span()=> self.#ident.take()});
-                }
+            Some(mc)
+        }
+        else {
+            None
+        })
+    }
+
+    fn field_clearer_body(&self, fctx: &FXDeriveFieldCtx, mc: &mut FXFnConstructor, span: Span) -> darling::Result<()> {
+        let ident = fctx.ident();
+        let ty = fctx.ty();
+        let implementor = fctx.impl_details();
+        let await_call = implementor.await_call(span);
+        let lock = fctx.lock();
+
+        mc.set_async(fctx.mode_async())
+            .set_ret_type(quote_spanned! {span=> ::std::option::Option<#ty>});
+
+        let lazy = fctx.lazy();
+
+        if *lazy {
+            if *lock && fctx.lock_group().is_some() {
+                let wlock = self.lock_guard_expr(fctx, true, lazy.final_span());
+                mc.set_ret_stmt(quote_spanned! {lazy.final_span()=> #wlock.take()});
             }
             else if *lock {
-                // If not lazy then it's optional
-                mc.set_ret_stmt(quote_spanned! {span=> self.#ident.write()#await_call.take()});
+                mc.set_ret_stmt(quote_spanned! {lazy.final_span()=> self.#ident.clear()#await_call});
             }
             else {
                 mc.set_self_mut(true);
-                mc.set_ret_stmt(quote_spanned! {span=> self.#ident #await_call.take()});
+                mc.set_ret_stmt(quote_spanned! {lazy.final_span()=> self.#ident.take()});
             }
-
-            Some(mc)
+        }
+        else if *lock {
+            // If not lazy then it's optional
+            let wlock = self.lock_guard_expr(fctx, true, span);
+            mc.set_ret_stmt(quote_spanned! {span=> #wlock.take()});
         }
         else {
-            None
-        })
+            mc.set_self_mut(true);
+            mc.set_ret_stmt(quote_spanned! {span=> self.#ident #await_call.take()});
+        }
+
+        Ok(())
     }
 
     fn field_predicate(&self, fctx: &FXDeriveFieldCtx) -> darling::Result<Option<FXFnConstructor>> {
@@ -723,16 +1650,19 @@ impl<'a> FXCodeGenContextual for FXCodeGenSync<'a> {
             let lazy = fctx.lazy();
             let lock = fctx.lock();
 
-            if *lazy {
+            if *lazy && fctx.lock_group().is_some() {
+                let rlock = self.lock_guard_expr(fctx, false, lazy.final_span());
+                mc.set_ret_stmt(quote_spanned! {lazy.final_span()=> #rlock.is_some()});
+            }
+            else if *lazy {
                 mc.set_ret_stmt(quote_spanned! {lazy.final_span()=> self.#ident.is_set()});
             }
             // If not lazy then it's optional
             else if *lock {
                 let lock_span = lock.final_span();
                 mc.set_async(fctx.mode_async());
-                let await_call = fctx.impl_details().await_call(lock_span);
-                let read_method = self.read_method_name(fctx, false, lock_span);
-                mc.set_ret_stmt(quote_spanned! {lock_span=> self.#ident.#read_method()#await_call.is_some()});
+                let rlock = self.lock_guard_expr(fctx, false, lock_span);
+                mc.set_ret_stmt(quote_spanned! {lock_span=> #rlock.is_some()});
             }
             else {
                 mc.set_ret_stmt(quote_spanned! {span=> self.#ident.is_some()});
@@ -765,7 +1695,11 @@ impl<'a> FXCodeGenContextual for FXCodeGenSync<'a> {
             }
         };
 
-        Ok(if *lazy {
+        Ok(if *lazy && fctx.lock_group().is_some() {
+            // Lazy members of a lock group are stored as options, together with the rest of the group.
+            value_wrapper
+        }
+        else if *lazy {
             let lazy_builder = self.wrap_builder(fctx, fctx.impl_details().lazy_builder(fctx))?;
             let value_toks = value_wrapper.to_token_stream();
 
@@ -782,14 +1716,30 @@ impl<'a> FXCodeGenContextual for FXCodeGenSync<'a> {
             }
 
             let lock = fctx.lock();
-            if *lock {
+            let atomic = fctx.atomic();
+            // Members of a lock group are wrapped as a whole, together with the rest of the group.
+            if *lock && fctx.lock_group().is_none() {
                 let lock_span = lock.final_span();
-                let rwlock = fctx.impl_details().rwlock(lock_span)?;
+                let rwlock = self.lock_type(fctx, lock_span)?;
                 let value_toks = value_wrapper.to_token_stream();
                 value_wrapper
                     .replace(quote_spanned![lock_span=> #rwlock::new(#value_toks) ])
                     .mark_as(FXValueFlag::ContainerWrapped)
             }
+            else if *atomic {
+                let atomic_type = self.atomic_type(fctx)?;
+                let value_toks = value_wrapper.to_token_stream();
+                value_wrapper
+                    .replace(quote_spanned![atomic.final_span()=> <#atomic_type>::new(#value_toks) ])
+                    .mark_as(FXValueFlag::ContainerWrapped)
+            }
+            else if *fctx.swap() {
+                let swap_type = self.swap_type(fctx);
+                let value_toks = value_wrapper.to_token_stream();
+                value_wrapper
+                    .replace(quote_spanned![fctx.swap().final_span()=> <#swap_type>::from_pointee(#value_toks) ])
+                    .mark_as(FXValueFlag::ContainerWrapped)
+            }
             else {
                 value_wrapper
             }
@@ -200,10 +200,54 @@ use syn::DeriveInput;
 ///
 /// ## **`sync`**
 ///
-/// **Type**: <a href="#keyword_arg">keyword</a>
+/// **Type**: <a href="#keyword_arg">keyword</a> or <a href="#list_arg">function</a>
 ///
 /// Declare a struct as thread-safe by default.
 ///
+/// <a id="single_lock"></a>
+/// With the **`single_lock`** sub-argument, `sync(single_lock)`, fields are lock-protected by default and all of them
+/// share a single read-write lock instead of having one lock each. This is an implicit [lock group](#lock) named
+/// `state`: the values are stored in a `<Struct>StateGroup` struct and the struct gets `read_state()` and
+/// `write_state()` methods. Field helpers work as usual, with [`reader`/`writer`](#reader_writer_helpers) guards
+/// projected onto their fields.
+///
+/// Lazy fields are stored in the group as `Option`s. The builder runs without holding the lock, so it can read other
+/// fields and initialize other lazy fields of the struct; the value is then stored under an upgradable lock. Builders
+/// of the same struct instance don't run concurrently. Don't trigger lazy initialization while holding a read guard
+/// of the struct as the lock cannot be upgraded until it is released. The writer of a lazy field is an
+/// `FXGroupWriter` with the same `store()` and `clear()` methods as [`FXWriter`](#reader_writer_helpers).
+///
+/// Atomic, swap, and skipped fields, as well as fields with explicit `lock(off)`, stay out of the shared lock. Explicit
+/// lock groups and mutexes cannot be used in this mode; `single_lock` is not supported by the `sync-std` backend either.
+///
+/// ```
+/// # use fieldx::fxstruct;
+/// #[fxstruct(sync(single_lock), get(copy))]
+/// struct Stats {
+///     #[fieldx(set)]
+///     requests: u64,
+///     #[fieldx(set)]
+///     errors:   u64,
+///     #[fieldx(lazy)]
+///     ratio:    f64,
+/// }
+///
+/// impl Stats {
+///     fn build_ratio(&self) -> f64 {
+///         self.errors() as f64 / self.requests().max(1) as f64
+///     }
+/// }
+/// # fn main() {
+/// let stats = Stats::new();
+/// {
+///     let mut state = stats.write_state();
+///     state.requests = 10;
+///     state.errors = 1;
+/// }
+/// assert_eq!(stats.ratio(), 0.1);
+/// # }
+/// ```
+///
 /// ## **`r#async`***
 ///
 /// **Type**: <a href="#keyword_arg">keyword</a>
@@ -288,6 +332,16 @@ use syn::DeriveInput;
 /// - **`into`** – force all builder setter methods to attempt automatic type conversion using `.into()` method
 ///
 ///   With `into`, the example above wouldn't need `String::from`, and the call could look like: `.description("some description")`
+/// - **`try_into`**, **`try_into(defer)`** – like `into`, but uses the [`TryInto`] trait. Takes precedence over
+///   `into`. By default builder setter methods return `Result<Self, E>` where `E` is the conversion error type. The
+///   conversion error is reported immediately, leaving it up to the caller to either bail out with `?` or handle it
+///   otherwise.
+///
+///   With `defer` setter methods return `Self` and conversion errors, which must implement
+///   [`Display`](std::fmt::Display), are kept by the builder. The `build()` method then fails with
+///   `FieldXError::FieldConversion` tagged with the name of the first field that failed. A successful conversion for
+///   the same field discards its earlier error. At field level `try_into(defer(off))` makes the field report its
+///   conversion errors immediately.
 /// - **`opt_in`** - struct-level only argument; with it only fields with explicit `builder` can be set by builder.
 /// - **`init`** - struct-level only argument; specifies identifier of the method to call to finish object initialization.
 /// - **`post_build`** - struct-level only; makes builder's `build()` method to call `post_build()` method. If given an
@@ -301,6 +355,34 @@ use syn::DeriveInput;
 ///     this allows for `mut self` and direct access to the fields without use of inner mutability
 /// - **`error(ErrorType)`** - struct-level only; changes the error type returned by the `build()` method. Together with
 ///   the `post_build` argument makes the post-build method fallible with the same error type.
+/// - **`from_map`** - adds `from_str_map(&HashMap<String, String>)` associated function and
+///   `try_set_by_name(&mut self, name: &str, value: &str)` method to the builder. Values are parsed with
+///   [`FromStr`](std::str::FromStr) into field types; names are those of the fields, or what is given with their
+///   `rename` argument. Only fields that have builder setter methods can be set this way. Unknown names are reported
+///   with `FieldXError::UnknownField`, parse failures – with `FieldXError::FieldParse`. Fields of types not
+///   implementing `FromStr` must opt out with field-level `builder(from_map(off))`.
+/// - **`check(rule, "message")`** - struct-level only; can be used multiple times. Declares a validation rule for the
+///   object constructed by the `build()` method. The `rule` is either a method name, or an expression where `self`
+///   refers to the new object; both must result in a `bool`. All checks are evaluated after the object is constructed
+///   but before `post_build` is called. If any of them fails `build()` returns `FieldXError::Validation` with messages
+///   of all failed rules, and `post_build` is not called. With a custom error variant, as in
+///   `error(MyError, MyError::Invalid)`, the variant receives the text of the validation error instead.
+///
+///   ```
+///   # use fieldx::fxstruct;
+///   #[fxstruct(builder(check(self.min <= self.max, "min must not exceed max"), check(is_named, "no name")))]
+///   struct Range {
+///       min: i32,
+///       max: i32,
+///       name: String,
+///   }
+///   # impl Range {
+///   #     fn is_named(&self) -> bool { !self.name.is_empty() }
+///   # }
+///   # fn main() {
+///   assert!(Range::builder().min(2).max(1).name("r".to_string()).build().is_err());
+///   # }
+///   ```
 /// - **`doc`** - [documentation](#doc_subarg) for the builder struct
 /// - **`method_doc`** - [documentation](#doc_subarg) for the `build` method of the builder
 ///
@@ -352,6 +434,154 @@ use syn::DeriveInput;
 /// - **`doc(...)`** - [documentation](#doc_subarg) for the reference counting method.
 /// - **`off`** temporarily disables reference counting for the struct.
 ///
+/// <a id="transaction"></a>
+/// ## **`transaction`**
+///
+/// **Type**: <a href="#helper_arg">helper</a>
+///
+/// Generates a `transaction` method that locks every non-lazy lock-protected field for writing and passes them all
+/// to a callback at once. This allows updating several fields consistently: other threads can't observe a state where
+/// only some of the fields are changed. Locks are always acquired in the order the fields are declared in and are
+/// released together when the callback returns.
+///
+/// The callback receives a mutable reference to a `<StructName>Transaction` object which provides the following
+/// methods for each of the locked fields, named after the field's own helpers:
+///
+/// - an accessor, returning a reference to the field value;
+/// - a mutable accessor;
+/// - a setter, returning the previous value.
+///
+/// For [`optional`](#optional) fields the value type is wrapped into an `Option`.
+///
+/// With [`r#async`](#async) the method is asynchronous, but the callback itself is not.
+///
+/// ```
+/// # use fieldx::fxstruct;
+/// #[fxstruct(sync, get(copy), get_mut, set, transaction)]
+/// struct Account {
+///     #[fieldx(lock, default(100))]
+///     balance:  i64,
+///     #[fieldx(lock)]
+///     reserved: i64,
+/// }
+///
+/// # fn main() {
+/// let account = Account::new();
+/// account.transaction(|tx| {
+///     *tx.balance_mut() -= 10;
+///     let reserved = *tx.reserved();
+///     tx.set_reserved(reserved + 10);
+/// });
+/// assert_eq!(account.balance(), 90);
+/// assert_eq!(account.reserved(), 10);
+/// # }
+/// ```
+///
+/// Sub-arguments:
+///
+/// - a literal string defines the name of the method. The default is `transaction`.
+/// - **`vis(...)`** - [visibility](#visibility) of both the method and the transaction struct. Defaults to the
+///   visibility of the struct itself.
+/// - **`doc(...)`** - [documentation](#doc_subarg) for the method.
+/// - **`off`** disables the method generation.
+///
+/// The struct must be in [`sync`](#sync) or [`async`](#async) mode and have at least one non-lazy lock-protected field.
+///
+/// <a id="track_changes"></a>
+/// ## **`track_changes`**
+///
+/// **Type**: <a href="#keyword_arg">keyword</a>
+///
+/// Keeps a version counter for every field that isn't [skipped](#skip). Setters, writers, and clearers of a field bump
+/// its counter; so do setters of the [transaction](#transaction) object, `fetch_add_`, `swap_`, and successful
+/// `compare_exchange_` helpers of `atomic` fields, and `update_` helpers of `swap` fields. The counter is bumped after
+/// the field is modified, while its lock is still held; a setter which fails to convert its argument doesn't bump it.
+/// Other ways of modifying the field, like mutable accessors, are not tracked, and lazy initialization isn't considered
+/// a change either. The following methods are generated:
+///
+/// - `is_<field>_dirty()` tells if the field has changed since the last `mark_clean()` call;
+/// - `version_<field>()` returns the current value of the field counter;
+/// - `dirty_fields()` returns the names of changed fields, in the order of their declaration;
+/// - `mark_clean()` considers all fields unchanged.
+///
+/// Newly constructed, built, and deserialized objects start clean with all counters set to zero.
+///
+/// In [`sync`](#sync) and [`async`](#async) modes the counters are atomic and all the methods take `&self`. In plain
+/// mode the counters are plain integers in `Cell`s, which makes the struct `!Sync`.
+///
+/// ```ignore
+/// #[fxstruct(track_changes, get, set)]
+/// struct Document {
+///     title: String,
+///     body:  String,
+/// }
+///
+/// let mut doc = Document::new();
+/// doc.set_title("Report".to_string());
+/// assert_eq!(doc.dirty_fields(), vec!["title"]);
+/// doc.mark_clean();
+/// assert!(!doc.is_title_dirty());
+/// assert_eq!(doc.version_title(), 1);
+/// ```
+///
+/// The tracking state is not a part of the struct value: it doesn't affect derived `PartialEq` or `Hash`.
+///
+/// <a id="patch"></a>
+/// ## **`patch`**
+///
+/// **Type**: <a href="#helper_arg">helper</a>
+///
+/// Generates a `<StructName>Patch` struct and an `apply_patch` method for partial updates. The patch struct has the
+/// same fields as the user struct, except for [skipped](#skip) ones, each wrapped into an `Option`. Fields with a
+/// [clearer](#clearer) are wrapped twice, making it possible to tell "leave as is" (`None`) from "clear"
+/// (`Some(None)`). The patch struct implements `Default` with all fields set to `None`.
+///
+/// `apply_patch` updates every field which is set in the patch the same way the field's setter and clearer would do,
+/// whether these helpers are generated or not: locks are acquired for each field separately, inner mutability is
+/// respected, and [change tracking](#track_changes) counters are bumped. The method takes `&self` if all fields can be
+/// updated via a shared reference, and `&mut self` otherwise. With [`r#async`](#async) the method is asynchronous.
+///
+/// If the struct has [builder checks](#builder_struct), they are evaluated against the patched state and
+/// `apply_patch` returns `Result<(), FieldXError>`. The method takes the write locks of the lock-protected fields in
+/// their declaration order, writes the new values, and then downgrades the locks to upgradable ones so that the checks
+/// can read the fields. If any check fails the old values are restored and `FieldXError::Validation` lists the failed
+/// rules; change tracking counters are only bumped when all checks pass. Other threads can read the patched values
+/// while the checks run. Upgradable locks are only available with the `parking_lot` and `async-lock` backends;
+/// mutex-protected fields can't be patched with checks.
+///
+/// With [`serde`](#serde_struct) enabled the patch struct also derives `Deserialize`, using the deserialization names
+/// of the fields. Fields that are not deserialized are skipped. For clearable fields a `null` clears the field.
+///
+/// ```
+/// # use fieldx::fxstruct;
+/// # use std::sync::Arc;
+/// #[fxstruct(sync, get(copy), patch)]
+/// struct Limits {
+///     #[fieldx(lock, default(5))]
+///     soft:  u32,
+///     #[fieldx(lock, optional, clearer, default(20))]
+///     burst: u32,
+/// }
+///
+/// # fn main() {
+/// let limits = Arc::new(Limits::new());
+/// limits.apply_patch(LimitsPatch {
+///     soft: Some(10),
+///     burst: Some(None),
+/// });
+/// assert_eq!(limits.soft(), 10);
+/// assert_eq!(limits.burst(), None);
+/// # }
+/// ```
+///
+/// Sub-arguments:
+///
+/// - a literal string defines the name of the method. The default is `apply_patch`.
+/// - **`vis(...)`** - [visibility](#visibility) of the method, the patch struct, and its fields. Defaults to the
+///   visibility of the struct itself.
+/// - **`doc(...)`** - [documentation](#doc_subarg) for the method.
+/// - **`off`** disables the patch generation.
+///
 /// ## **`new`**
 ///
 /// **Type**: <a href="#keyword_arg">helper</a>
@@ -392,13 +622,60 @@ use syn::DeriveInput;
 /// - **`copy`** – returns a copy of the field value (the field type must implement [`Copy`]).
 /// - **`as_ref`** – only applicable if the field value is optional; it forces the accessor to have an `Option<&T>`
 ///   return type instead of `&Option<T>`.
+/// - **`deref`** – returns a reference to the dereferenced value, `&<T as Deref>::Target`, like `&str` for a `String`,
+///   or `&[T]` for a `Vec<T>`. Optional fields get `Option<&<T as Deref>::Target>`. For lock-protected fields of `sync`
+///   structs a [`MappedRwLockReadGuard`](https://docs.rs/parking_lot/latest/parking_lot/type.MappedRwLockReadGuard.html)
+///   is returned; in `async` mode it is `fieldx::async::FXDerefReadGuard`. Can't be used with `inner_mut` and with
+///   optional lock-protected fields.
+/// - **`map = path::to::fn`**, **`map(path::to::fn, R)`** – field-level only; the accessor returns a projection of the
+///   field value produced by calling `fn(&T) -> R`. Since the macro can't see the function signature, without `R` the
+///   accessor returns an opaque `impl Sized` which may borrow from the object. `R` can be the concrete type of the
+///   function result or an `impl Trait` type with bounds the callers need. Optional fields get `Option<R>`. For
+///   `inner_mut` and lock-protected fields the value is mapped while the guard is held, hence `R` cannot borrow from
+///   the field.
+///
+/// ```
+/// # use fieldx::fxstruct;
+/// fn first_word(s: &String) -> &str {
+///     s.split_whitespace().next().unwrap_or("")
+/// }
+///
+/// #[fxstruct(get)]
+/// struct Foo {
+///     #[fieldx(get(map(first_word, &str)), default("Hello, world!".to_string()))]
+///     greeting: String,
+/// }
+/// # fn main() {
+/// let obj = Foo::new();
+/// assert_eq!(obj.greeting(), "Hello,");
+/// # }
+/// ```
+///
+/// ```
+/// # use fieldx::fxstruct;
+/// #[fxstruct(get(deref))]
+/// struct Foo {
+///     #[fieldx(default("foo".to_string()))]
+///     name:  String,
+///     #[fieldx(default(vec![1, 2]))]
+///     items: Vec<u32>,
+/// }
+/// # fn main() {
+/// let obj = Foo::new();
+/// let name: &str = obj.name();
+/// let items: &[u32] = obj.items();
+/// assert_eq!(name, "foo");
+/// assert_eq!(items, &[1, 2]);
+/// # }
+/// ```
 ///
 /// ## **`get_mut`**
 ///
 /// **Type**: <a href="#helper_arg">helper</a>
 ///
-/// Request for a mutable accessor. Since neither of additional options of `get` are applicable here[^no_copy_for_mut]
-/// only basic [helper sub-arguments](#sub_args) are accepted.
+/// Request for a mutable accessor. Since most of additional options of `get` are not applicable here[^no_copy_for_mut]
+/// only basic [helper sub-arguments](#sub_args) are accepted. The only exception is field-level `map = path::to::fn`
+/// which works the same way as for `get`, except that the function receives `&mut T`.
 ///
 /// Mutable accessors have the same name, as immutable ones, but with `_mut` suffix, unless given explicit name by the
 /// user:
@@ -425,9 +702,14 @@ use syn::DeriveInput;
 /// Request for setter methods. If a literal string sub-argument is supplied it is used as setter method prefix instead
 /// of the default `set_`.
 ///
-/// Takes an additional sub-argument:
+/// Takes additional sub-arguments:
 ///
 /// - **`into`**: use the [`Into`] trait to automatically convert a value into the field type
+/// - **`try_into`**: use the [`TryInto`] trait for conversion; setters return `Result<R, E>` where `R` is what they'd
+///   return otherwise and `E` is the conversion error type. The field is left untouched if the conversion fails. Takes
+///   precedence over `into`.
+/// - **`chain`**: make setters return a reference to the object instead of the old field value; the reference is
+///   mutable unless the setter takes `&self`, as it happens with lock-protected fields of `sync` structs
 ///
 /// ```
 /// # use fieldx::fxstruct;
@@ -442,6 +724,57 @@ use syn::DeriveInput;
 /// # }
 /// ```
 ///
+/// ```
+/// # use fieldx::fxstruct;
+/// #[fxstruct(set(chain), get(copy))]
+/// struct Foo {
+///     x: i32,
+///     y: i32,
+/// }
+/// # fn main() {
+/// let mut obj = Foo::new();
+/// obj.set_x(1).set_y(2);
+/// assert_eq!((obj.x(), obj.y()), (1, 2));
+/// # }
+/// ```
+///
+/// ```
+/// # use fieldx::fxstruct;
+/// #[fxstruct(set(try_into), get(copy))]
+/// struct Foo {
+///     port: u16,
+/// }
+/// # fn main() {
+/// let mut obj = Foo::new();
+/// assert!(obj.set_port(8080u64).is_ok());
+/// assert!(obj.set_port(100_000u64).is_err());
+/// assert_eq!(obj.port(), 8080);
+/// # }
+/// ```
+///
+/// ## **`with`**
+///
+/// **Type**: <a href="#helper_arg">helper</a>
+///
+/// Request for builder-like `with_<field>` methods that take the object by value, set the field, and return the
+/// object back. Apart from the way the object is passed these follow the rules of setters, including the `into` and
+/// `try_into` sub-arguments of `set`. Async structs get async `with_` methods where setters are async too.
+///
+/// ```
+/// # use fieldx::fxstruct;
+/// #[fxstruct(with, set(into), get)]
+/// struct Foo {
+///     name: String,
+///     #[fieldx(optional)]
+///     comment: String,
+/// }
+/// # fn main() {
+/// let obj = Foo::new().with_name("foo").with_comment("bar");
+/// assert_eq!(obj.name(), "foo");
+/// assert_eq!(obj.comment(), &Some("bar".to_string()));
+/// # }
+/// ```
+///
 /// <a id="reader_writer_helpers"></a>
 /// ## **`reader`**, **`writer`**
 ///
@@ -472,15 +805,76 @@ use syn::DeriveInput;
 /// # }
 /// ```
 ///
+/// Each of the helpers is accompanied by two more methods that never block indefinitely. They are named after the
+/// helper: `try_read_<field>()` acquires the lock only if it is available right away, and
+/// `try_read_<field>_for(timeout)` waits for it no longer than the given [`Duration`](std::time::Duration). The same
+/// goes for `try_write_<field>()` and `try_write_<field>_for(timeout)`. All of them return an `Option` of the lock
+/// guard.
+///
+/// For lazy fields the reader variants run the builder if the field is not initialized yet and the lock has been
+/// acquired; the time the builder takes is not counted toward the timeout. Fallible fields get a `Result` of the
+/// `Option`. The writer variants return the same writer as the `writer` helper and never call the builder.
+///
+/// In async mode the non-timed variants are regular, non-async methods, except for those of lazy fields. The timed
+/// ones use a sleep hook, installed with `fieldx::r#async::set_sleep_hook()`, to remain
+/// independent of a particular async runtime. Without a hook a shared helper thread is used to count down the
+/// timeouts.
+///
+/// ```
+/// # use fieldx::fxstruct;
+/// use std::time::Duration;
+///
+/// #[fxstruct(sync, reader, writer)]
+/// struct Foo {
+///     description: String,
+/// }
+/// # fn main() {
+/// let obj = Foo::new();
+/// let wguard = obj.write_description();
+/// assert!(obj.try_read_description().is_none());
+/// assert!(obj.try_read_description_for(Duration::from_millis(10)).is_none());
+/// drop(wguard);
+/// assert!(obj.try_write_description().is_some());
+/// # }
+/// ```
+///
 /// See [the section about differences between `get`/`get_mut` and `reader`/`writer`](#accessor_vs_reader_writer)
 ///
 /// ## **`lock`**
 ///
-/// **Type**: <a href="#flag_arg">flag</a>
+/// **Type**: <a href="#list_arg">function</a>
 ///
 /// Forces lock-wrapping of all fields by default. Can be explicitly disabled with `lock(off)`. Identical to the
 /// `reader`/`writer` arguments but without installing any methods.
 ///
+/// Fields are normally wrapped into a read-write lock. With the **`mutex`** sub-argument, i.e. `lock(mutex)`, a mutex
+/// is used instead. It is a better choice for write-heavy fields and for types that are [`Send`] but not [`Sync`].
+/// Mutex-protected fields get an additional `lock_<field>()` helper method that returns the mutex guard. Accessors and
+/// mutable accessors also return the mutex guard unless `copy` or `clone` accessor mode is used. Such fields cannot be
+/// lazy, can't be used with `reader` and `writer` helpers, and with `get(deref)`. A field can opt out of struct-level
+/// mutex with `lock(mutex(off))`.
+///
+/// ```
+/// # use fieldx::fxstruct;
+/// use std::cell::Cell;
+///
+/// #[fxstruct(sync, get(copy))]
+/// struct Foo {
+///     #[fieldx(lock(mutex), default(1))]
+///     counter: u32,
+///     // Cell is not Sync, but the struct still is.
+///     #[fieldx(lock(mutex), get(off), default(Cell::new(0)))]
+///     hits:    Cell<u32>,
+/// }
+/// # fn main() {
+/// let obj = Foo::new();
+/// *obj.lock_counter() += 1;
+/// obj.lock_hits().set(2);
+/// assert_eq!(obj.counter(), 2);
+/// assert_eq!(obj.lock_hits().get(), 2);
+/// # }
+/// ```
+///
 /// ## **`clearer`** and **`predicate`**
 ///
 /// **Type**: <a href="#helper_arg">helper</a>
@@ -523,8 +917,31 @@ use syn::DeriveInput;
 ///
 /// Support for de/serialization will be discussed in more details in a section below. What is important to know at this
 /// point is that due to use of container types direct serialization of a struct is hardly possible. Therefore `fieldx`
-/// utilizes `serde`'s `into` and `from` by creating a special shadow struct. The shadow, by default, is named after the
-/// original by prepending the name with double underscore and appending *Shadow* suffix: `__FooShadow`.
+/// utilizes `serde`'s `from` by creating a special shadow struct. The shadow, by default, is named after the original
+/// by prepending the name with double underscore and appending *Shadow* suffix: `__FooShadow`.
+///
+/// Plain and sync structs are serialized via a borrowed counterpart of the shadow, `__FooShadowRef`, whose fields refer
+/// to the values of the original struct, holding read locks where necessary. Lazy fields are initialized first. This
+/// way serialization doesn't require the struct to implement `Clone`.
+///
+/// Structs with async fields can't take their locks without blocking the runtime. They still implement `Serialize` via
+/// `serde`'s `into`, which means they must be `Clone` and that cloning blocks on the locks: with `async-tokio` it
+/// panics if done from within the runtime. Lazy fields are not initialized by it either. With `async-lock`, lazy fields
+/// without a lock can't be cloned, hence such structs can't be serialized. Prefer the two async methods
+/// these structs get instead: `to_shadow()`, which awaits the locks, initializes lazy fields, and returns the shadow
+/// struct with cloned field values; and `serialize_async(serializer)`, which serializes the result of `to_shadow()`.
+///
+/// Reference-counted (`rc`) structs must be constructed inside their container for `myself` to work. Therefore they
+/// don't implement `Deserialize` but get an associated function `deserialize_rc()` which takes a deserializer and
+/// returns the struct already wrapped into `Rc` or `Arc`. It can be used with field attribute
+/// `#[serde(deserialize_with = "Foo::deserialize_rc")]` of a parent struct.
+///
+/// With `serde(schema)` and the `schemars` feature of the `fieldx` crate, the shadow struct derives
+/// [`JsonSchema`](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html) and the struct implements it by
+/// delegating to the shadow, or to the `FXVersioned` wrapper of it for versioned structs. The schema is named after
+/// the struct, or after its `rename`, reflects the serialization-level field names and defaults, and takes its
+/// descriptions from the doc comments of the struct and its fields. All serialized field types must implement
+/// `JsonSchema` then.
 ///
 /// The following sub-arguments are supported:
 ///
@@ -541,6 +958,44 @@ use syn::DeriveInput;
 ///   [`rename`](https://serde.rs/container-attrs.html#rename). Can also be used with a single string literal which
 ///   would then set both `serialize` and `deserialize` at once.
 /// - **`shadow_name(...)`** - its string literal argument specifies a different name for the shadow struct
+/// - **`lazy(force)`**, **`lazy(skip_unset)`** - how lazy fields are serialized. With `force`, the default, they're
+///   initialized first. With `skip_unset` uninitialized lazy fields are left out of the output and are deserialized
+///   back as uninitialized when absent; builders are not called.
+/// - **`version = N`** - layout version of the serialized struct. The struct is then serialized as
+///   `{"version": N, "data": {...}}` with the shadow struct being the data
+/// - **`migrate_from(K = path, ...)`** - functions upgrading data of older layout versions. A function for version `K`
+///   takes a `Deserialize` type of that layout and returns the layout of the next newer version listed, or the shadow
+///   struct for the newest one. Data of version `K` is passed through all the functions from `K` and up before it is
+///   converted into the struct. `Self` in the paths refers to the struct. Use `shadow_name` to give the shadow a
+///   stable name the functions can refer to.
+/// - **`update`**, **`update(clear_lazy)`** - generates `update_from_deserializer(&self, deserializer)` for
+///   [`sync`](#sync) and [`async`](#async) structs. It deserializes the input and writes the fields present in it
+///   through the locks of the existing object, keeping all outstanding `Arc` and `Weak` references to it valid. A
+///   `null` clears an optional or lazy field. Lazy fields missing in the input are left intact, unless `clear_lazy` is
+///   used, in which case they're cleared and rebuilt on next access. Deserialized fields must be updatable via `&self`,
+///   i.e. be lock-protected. Nothing is written if the input fails to deserialize. Versioned structs are not supported.
+/// - **`schema`** - implements `JsonSchema` for the struct; requires the `schemars` feature
+/// - **`skip_none`** - unset optional fields, i.e. those with `optional`, `clearer`, or `predicate`, are left out of the
+///   output instead of being serialized as `null`, and are deserialized as unset when absent from the input
+/// - **`snapshot`** - makes serialization of [`sync`](#sync) and [`async`](#async) structs consistent. Lazy fields are
+///   initialized first, then read locks of all serialized fields, or of their [lock groups](#lock), are taken in the
+///   order of field declarations and held until the shadow struct is complete. A [`single_lock`](#single_lock) struct
+///   is locked only once, with its struct-level lock. The order is the same as [`transaction`](#transaction) uses, so
+///   the two cannot deadlock each other. Writers to any of the fields are blocked for the duration of serialization, or
+///   of `to_shadow()` for async structs.
+///
+/// ```ignore
+/// #[fxstruct(serde(version = 3, migrate_from(1 = Self::from_v1, 2 = Self::from_v2), shadow_name("ConfigShadow")))]
+/// struct Config {
+///     host: String,
+///     port: u16,
+/// }
+///
+/// impl Config {
+///     fn from_v1(old: ConfigV1) -> ConfigV2 { ... }
+///     fn from_v2(old: ConfigV2) -> ConfigShadow { ... }
+/// }
+/// ```
 ///
 /// #### _Notes about `default`_
 ///
@@ -669,6 +1124,118 @@ use syn::DeriveInput;
 ///
 /// Enables field interior mutability.
 ///
+/// ## **`atomic`**
+///
+/// **Type**: <a href="#list_arg">function</a>
+///
+/// Field level only. Stores a [`bool`], fixed-size integer, [`isize`], or [`usize`] field in its
+/// [`std::sync::atomic`] counterpart instead of a lock. Implies sync mode, unless the struct is async. The accessor
+/// loads the value and the setter swaps it, both take `&self`. A mutable accessor uses the atomic's `get_mut()`.
+///
+/// The field also gets `swap_<field>(value)` and `compare_exchange_<field>(current, new)` helper methods, and, unless
+/// it is a `bool`, `fetch_add_<field>(value)`. Same as their [`std::sync::atomic`] namesakes, they return the previous
+/// value.
+///
+/// The **`ordering`** sub-argument takes the name of an [`Ordering`](std::sync::atomic::Ordering) variant to use for
+/// all operations; `SeqCst` is the default. Since loads cannot have release semantics, they use `Relaxed` for `Release`
+/// and `Acquire` for `AcqRel`. The same applies to the failure ordering of `compare_exchange_<field>`.
+///
+/// Atomic fields cannot be lock-protected, lazy, or optional. Builder and serialization deal with the plain
+/// primitive type.
+///
+/// Standard atomics don't implement [`Clone`]. With the `clonable-lock` feature, which is implied by `serde`, atomic
+/// fields are stored in a `fieldx::atomic::FXAtomic` container instead. It dereferences to the atomic and clones it by
+/// loading the current value, so `#[derive(Clone)]` works for the struct.
+///
+/// ```
+/// # use fieldx::fxstruct;
+/// #[fxstruct(sync, get, set)]
+/// struct Stats {
+///     #[fieldx(atomic)]
+///     hits:  u64,
+///     #[fieldx(atomic(ordering(AcqRel)))]
+///     ready: bool,
+/// }
+/// # fn main() {
+/// let stats = Stats::new();
+/// stats.fetch_add_hits(2);
+/// assert_eq!(stats.set_hits(10), 2);
+/// assert_eq!(stats.compare_exchange_ready(false, true), Ok(false));
+/// assert!(stats.ready());
+/// # }
+/// ```
+///
+/// ## **`swap`**
+///
+/// **Type**: <a href="#keyword_arg">keyword</a>
+///
+/// Field level only, requires the `arc-swap` feature. Stores the field as an atomically swappable `Arc<T>` provided by
+/// the [`arc-swap`](https://crates.io/crates/arc-swap) crate. It suits values which are read often but replaced
+/// rarely since readers never block each other nor the writers. Implies sync mode, unless the struct is async.
+///
+/// The accessor returns a cheap `Arc<T>` snapshot of the current value. The setter publishes a new value and returns
+/// the previous snapshot. The `update_<field>(|old| new)` helper computes the new value from the current one and
+/// publishes it with compare-and-swap, re-running the closure if another thread has replaced the value in the meantime.
+///
+/// Swap fields cannot be lock-protected, lazy, or optional, and they don't support mutable accessors. Builder and
+/// serialization deal with the plain `T`. Serialization clones `T` if the value is still shared with any snapshot.
+///
+/// ```
+/// # use fieldx::fxstruct;
+/// #[fxstruct(sync, get, set)]
+/// struct Router {
+///     #[fieldx(swap, default(vec!["/".to_string()]))]
+///     routes: Vec<String>,
+/// }
+/// # fn main() {
+/// let router = Router::new();
+/// let snapshot = router.routes();
+/// router.update_routes(|routes| {
+///     let mut routes = routes.clone();
+///     routes.push("/api".to_string());
+///     routes
+/// });
+/// assert_eq!(snapshot.len(), 1);
+/// assert_eq!(router.routes().len(), 2);
+/// # }
+/// ```
+///
+/// ## **`lock`**
+///
+/// **Type**: <a href="#list_arg">function</a>
+///
+/// Same as the struct-level argument. Additionally, the field level only **`group`** sub-argument, i.e.
+/// `lock(group = "net")` or `lock(group("net"))`, makes the field a member of a lock group. Members of a group are
+/// stored together in a generated `<Struct><Group>Group` struct, e.g. `ServerNetGroup`, which is protected by a single
+/// read-write lock. The struct gets `read_<group>()` and `write_<group>()` methods returning guards over the whole
+/// group; the group struct fields have the same names and visibility as the original fields. Field helpers keep working
+/// as usual, with guards returned by them projected onto the field they belong to.
+///
+/// Group members cannot be lazy or mutex-protected. See also [`sync(single_lock)`](#single_lock) which puts all fields
+/// of a struct into a single group.
+///
+/// ```
+/// # use fieldx::fxstruct;
+/// #[fxstruct(sync, get(copy))]
+/// struct Server {
+///     #[fieldx(lock(group = "net"), get(clone), default("localhost".to_string()))]
+///     host: String,
+///     #[fieldx(lock(group = "net"), set, default(80))]
+///     port: u16,
+/// }
+/// # fn main() {
+/// let server = Server::new();
+/// {
+///     let mut net = server.write_net();
+///     net.host = "example.com".to_string();
+///     net.port = 443;
+/// }
+/// assert_eq!(server.host(), "example.com");
+/// assert_eq!(server.set_port(8443), 443);
+/// assert_eq!(server.read_net().port, 8443);
+/// # }
+/// ```
+///
 /// ## **`rename`**
 ///
 /// **Type**: <a href="#list_arg">function</a>
@@ -678,7 +1245,7 @@ use syn::DeriveInput;
 ///
 /// [^unless_in_serde]: Unless a different alternative name is specified for serialization with `serde` argument.
 ///
-/// ## **`get`**, **`get_mut`**, **`set`**, **`reader`**, **`writer`**, **`clearer`**, **`predicate`**, **`optional`**
+/// ## **`get`**, **`get_mut`**, **`set`**, **`with`**, **`reader`**, **`writer`**, **`clearer`**, **`predicate`**, **`optional`**
 ///
 /// **Type**: <a href="#helper_arg">helper</a>
 ///
@@ -687,6 +1254,7 @@ use syn::DeriveInput;
 /// - [`get`](#get)
 /// - [`get_mut`](#get_mut)
 /// - [`set`](#set)
+/// - [`with`](#with)
 /// - [`reader` and `writer`](#reader-writer)
 /// - [`clearer`](#clearer)
 /// - [`predicate`](#predicate)
@@ -713,6 +1281,8 @@ use syn::DeriveInput;
 /// - `default` is responsible for field default value; contrary to the struct-level, it doesn't use [`Into`] trait
 /// - `attributes` will be applied to the field itself
 /// - `serialize`/`deserialize` control field marshalling
+/// - `lazy(force)` and `lazy(skip_unset)` override the struct-level mode for this lazy field
+/// - `skip_none` and `skip_none(off)` override the struct-level `skip_none` for this optional field
 ///
 /// ## **`into`**
 ///
@@ -733,6 +1303,8 @@ use syn::DeriveInput;
 /// Field level only argument:
 ///
 /// - **`required`** – this field must always get a value from the builder even if otherwise it'd be optional
+/// - **`from_map(off)`** – excludes the field from `from_str_map` and `try_set_by_name` methods generated by the
+///   struct-level `from_map`
 #[proc_macro_attribute]
 pub fn fxstruct(args: proc_macro::TokenStream, input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let attr_args = match ast::NestedMeta::parse_meta_list(args.into()) {
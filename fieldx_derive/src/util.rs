@@ -102,3 +102,9 @@ pub(crate) fn std_default_expr_toks(span: Span) -> FXToksMeta {
         FXValueFlag::StdDefault,
     )
 }
+
+// Builder field keeping conversion errors of setters with deferred `try_into`.
+#[inline]
+pub(crate) fn deferred_errors_ident(span: Span) -> syn::Ident {
+    syn::Ident::new("__fx_deferred_errors", span)
+}